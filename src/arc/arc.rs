@@ -0,0 +1,271 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// The list an element of an [`ArcCache`] belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum List {
+    /// Resident elements accessed once.
+    T1,
+    /// Resident elements accessed at least twice.
+    T2,
+    /// Keys recently evicted from `T1`.
+    B1,
+    /// Keys recently evicted from `T2`.
+    B2,
+}
+
+/// In-memory container with an Adaptive Replacement Cache (ARC) policy.
+///
+/// [`ArcCache`] implements the policy described by N. Megiddo and
+/// D. S. Modha in "ARC: A Self-Tuning, Low Overhead Replacement Cache".
+/// Resident elements are split between two lists ordered from least to
+/// most recently used: `T1` holds elements that were inserted but not
+/// accessed since, and `T2` holds elements that were accessed at least once
+/// after their insertion. The keys of elements evicted from these lists are
+/// remembered, without their value, in two ghost lists `B1` and `B2`.
+///
+/// When evicting elements, the least recently used element of `T1` is
+/// chosen if `T1` is larger than a target size, otherwise the least
+/// recently used element of `T2` is chosen. Inserting a key found in a
+/// ghost list means that the matching resident list was too small and the
+/// target size is adapted online accordingly: hits in `B1` grow the target
+/// size of `T1` and favor recency while hits in `B2` shrink it and favor
+/// frequency. As a result, one-time scans of many keys only go through
+/// `T1` and do not flush the frequently used elements of `T2`.
+///
+/// The capacity of an [`ArcCache`] is the number of elements it can hold.
+/// Ghost lists hold up to the same number of keys. This container cannot
+/// contain duplicate keys.
+///
+/// Elements within the container can be accessed with the
+/// [`Get`](trait.Get.html) and [`GetMut`](trait.GetMut.html) traits. An
+/// access moves the element to the most recently used end of `T2`.
+/// [`ArcCache`] can be shared between threads by wrapping it into a
+/// [`Sequential`](struct.Sequential.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{ArcCache, BuildingBlock, Get};
+///
+/// let mut c = ArcCache::new(2);
+/// c.push(vec![("first", 1), ("second", 2)]);
+///
+/// // Accessing "first" protects it from one-time insertions.
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+/// let out = c.push(vec![("third", 3)]);
+/// assert_eq!(out, vec![("second", 2)]);
+/// let out = c.push(vec![("fourth", 4)]);
+/// assert_eq!(out, vec![("third", 3)]);
+/// assert!(c.contains(&"first"));
+/// ```
+///
+/// [`ArcCache`] can also be built from a
+/// [builder pattern](builder/struct.Builder.html#method.arc) and a
+/// [configuration](config/struct.ArcCacheConfig.html).
+pub struct ArcCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    // Maximum number of resident elements.
+    pub(super) capacity: usize,
+    // Target size of `T1`.
+    pub(super) target: usize,
+    // Counter ordering elements inside lists.
+    pub(super) tick: u64,
+    // Lists of keys ordered from least to most recently used.
+    pub(super) t1: BTreeMap<u64, K>,
+    pub(super) t2: BTreeMap<u64, K>,
+    pub(super) b1: BTreeMap<u64, K>,
+    pub(super) b2: BTreeMap<u64, K>,
+    // Resident elements with their list and position in the list.
+    pub(super) resident: HashMap<K, (List, u64, V)>,
+    // Ghost keys with their list and position in the list.
+    pub(super) ghosts: HashMap<K, (List, u64)>,
+}
+
+impl<K, V> ArcCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Create a new [`ArcCache`] container holding up to `capacity`
+    /// elements.
+    pub fn new(capacity: usize) -> Self {
+        ArcCache {
+            capacity,
+            target: 0,
+            tick: 0,
+            t1: BTreeMap::new(),
+            t2: BTreeMap::new(),
+            b1: BTreeMap::new(),
+            b2: BTreeMap::new(),
+            resident: HashMap::new(),
+            ghosts: HashMap::new(),
+        }
+    }
+
+    /// Get the current target size of the list of elements accessed only
+    /// once.
+    ///
+    /// The target size is adapted online and is always less or equal to
+    /// the container capacity.
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    fn list(&mut self, list: List) -> &mut BTreeMap<u64, K> {
+        match list {
+            List::T1 => &mut self.t1,
+            List::T2 => &mut self.t2,
+            List::B1 => &mut self.b1,
+            List::B2 => &mut self.b2,
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Put `key` at the most recently used end of `list`.
+    fn link(&mut self, list: List, key: K) -> u64 {
+        let tick = self.next_tick();
+        self.list(list).insert(tick, key);
+        tick
+    }
+
+    /// Forget the least recently used key of a ghost `list`.
+    fn drop_ghost(&mut self, list: List) {
+        if let Some((_, key)) = self.list(list).pop_first() {
+            self.ghosts.remove(&key);
+        }
+    }
+
+    /// Move `key` to the most recently used end of `T2` if it is
+    /// resident.
+    ///
+    /// Returns whether the key is resident.
+    pub(super) fn touch(&mut self, key: &K) -> bool {
+        let (list, tick) = match self.resident.get(key) {
+            None => return false,
+            Some((list, tick, _)) => (*list, *tick),
+        };
+        let key = self.list(list).remove(&tick).unwrap();
+        let tick = self.link(List::T2, key.clone());
+        let e = self.resident.get_mut(&key).unwrap();
+        e.0 = List::T2;
+        e.1 = tick;
+        true
+    }
+
+    /// Evict a resident element and remember its key in a ghost list.
+    ///
+    /// The least recently used element of `T1` is evicted if `T1` exceeds
+    /// its target size, otherwise the least recently used element of `T2`
+    /// is evicted. `in_b2` tells whether the eviction is triggered by the
+    /// insertion of a key found in `B2`.
+    pub(super) fn replace(&mut self, in_b2: bool) -> Option<(K, V)> {
+        let t1_len = self.t1.len();
+        let (from, to) = if t1_len > 0
+            && (t1_len > self.target
+                || (in_b2 && t1_len == self.target)
+                || self.t2.is_empty())
+        {
+            (List::T1, List::B1)
+        } else {
+            (List::T2, List::B2)
+        };
+
+        let (_, key) = self.list(from).pop_first()?;
+        let (_, _, value) = self.resident.remove(&key).unwrap();
+        let tick = self.link(to, key.clone());
+        self.ghosts.insert(key.clone(), (to, tick));
+        Some((key, value))
+    }
+
+    /// Insert a key that is not resident and return the evicted elements.
+    pub(super) fn insert(&mut self, key: K, value: V) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        if self.capacity == 0 {
+            out.push((key, value));
+            return out;
+        }
+        let is_full = self.resident.len() >= self.capacity;
+
+        let list = match self.ghosts.remove(&key) {
+            // Ghost hit: adapt the target size of `T1` and insert in `T2`.
+            Some((ghost, tick)) => {
+                self.list(ghost).remove(&tick);
+                let (b1, b2) =
+                    (self.b1.len().max(1), self.b2.len().max(1));
+                if ghost == List::B1 {
+                    let delta = (b2 / b1).max(1);
+                    self.target = (self.target + delta).min(self.capacity);
+                } else {
+                    let delta = (b1 / b2).max(1);
+                    self.target = self.target.saturating_sub(delta);
+                }
+                if is_full {
+                    out.extend(self.replace(ghost == List::B2));
+                }
+                List::T2
+            }
+            // Miss: keep the directory within bounds and insert in `T1`.
+            None => {
+                if self.t1.len() + self.b1.len() >= self.capacity {
+                    if self.t1.len() < self.capacity {
+                        self.drop_ghost(List::B1);
+                        if is_full {
+                            out.extend(self.replace(false));
+                        }
+                    } else {
+                        let (_, k) = self.t1.pop_first().unwrap();
+                        let (_, _, v) = self.resident.remove(&k).unwrap();
+                        out.push((k, v));
+                    }
+                } else {
+                    let total = self.resident.len() + self.ghosts.len();
+                    if total >= 2 * self.capacity {
+                        self.drop_ghost(List::B2);
+                    }
+                    if is_full {
+                        out.extend(self.replace(false));
+                    }
+                }
+                List::T1
+            }
+        };
+
+        let tick = self.link(list, key.clone());
+        self.resident.insert(key, (list, tick, value));
+        out
+    }
+
+    /// Forget ghost keys until the directory fits the container capacity.
+    pub(super) fn trim_ghosts(&mut self) {
+        while self.t1.len() + self.b1.len() > self.capacity
+            && !self.b1.is_empty()
+        {
+            self.drop_ghost(List::B1);
+        }
+        while self.resident.len() + self.ghosts.len() > 2 * self.capacity
+            && !self.ghosts.is_empty()
+        {
+            if self.b2.is_empty() {
+                self.drop_ghost(List::B1);
+            } else {
+                self.drop_ghost(List::B2);
+            }
+        }
+    }
+}
+
+impl<'a, K, V> From<ArcCache<K, V>> for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Clone + Eq + Hash,
+    V: 'a,
+{
+    fn from(container: ArcCache<K, V>) -> Self {
+        crate::DynBuildingBlock::new_resizable_get(container, false, true)
+    }
+}
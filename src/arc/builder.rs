@@ -0,0 +1,50 @@
+use crate::builder::Build;
+use crate::ArcCache;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// `ArcCache` builder.
+///
+/// This builder can be consumed later to spawn an
+/// [`ArcCache`](../../struct.ArcCache.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::Build;
+/// use byoc::builder::ArcCacheBuilder;
+///
+/// let mut container = ArcCacheBuilder::new(2).build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct ArcCacheBuilder<K: Clone + Eq + Hash, V> {
+    pub(super) capacity: usize,
+    unused: PhantomData<(K, V)>,
+}
+
+impl<K: Clone + Eq + Hash, V> ArcCacheBuilder<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        ArcCacheBuilder {
+            capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Clone for ArcCacheBuilder<K, V> {
+    fn clone(&self) -> Self {
+        ArcCacheBuilder {
+            capacity: self.capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Build<ArcCache<K, V>>
+    for ArcCacheBuilder<K, V>
+{
+    fn build(self) -> ArcCache<K, V> {
+        ArcCache::new(self.capacity)
+    }
+}
@@ -0,0 +1,170 @@
+use super::arc::List;
+use super::ArcCache;
+use crate::BuildingBlock;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+impl<K, V> BuildingBlock<K, V> for ArcCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Get the maximum number of elements fitting in the container.
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of resident elements in the container.
+    ///
+    /// Ghost keys are not counted.
+    fn size(&self) -> usize {
+        self.resident.len()
+    }
+
+    /// Returns whether `key` is resident in the container.
+    ///
+    /// Ghost keys are not contained in the container.
+    fn contains(&self, key: &K) -> bool {
+        self.resident.contains_key(key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        matches!(self.resident.get(key), Some((_, _, v)) if predicate(v))
+    }
+
+    type FlushIterator = std::vec::IntoIter<(K, V)>;
+
+    /// Empty the container and return its resident elements.
+    ///
+    /// Ghost keys and the adapted target size are reset as well.
+    fn flush(&mut self) -> Self::FlushIterator {
+        let mut elements = HashMap::new();
+        std::mem::swap(&mut elements, &mut self.resident);
+        self.t1.clear();
+        self.t2.clear();
+        self.b1.clear();
+        self.b2.clear();
+        self.ghosts.clear();
+        self.target = 0;
+
+        elements
+            .into_iter()
+            .map(|(k, (_, _, v))| (k, v))
+            .collect::<Vec<(K, V)>>()
+            .into_iter()
+    }
+
+    /// Take a resident element out of the container.
+    ///
+    /// The key of the element is not remembered in a ghost list since it
+    /// is not evicted.
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        let (key, (list, tick, value)) =
+            self.resident.remove_entry(key)?;
+        match list {
+            List::T1 => self.t1.remove(&tick),
+            _ => self.t2.remove(&tick),
+        };
+        Some((key, value))
+    }
+
+    /// Evict up to `size` elements from the container.
+    ///
+    /// Victims are chosen one after the other with the ARC replacement
+    /// policy and their keys are remembered in the ghost lists.
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        let mut out = Vec::with_capacity(size.min(self.resident.len()));
+        while out.len() < size {
+            match self.replace(false) {
+                None => break,
+                Some(e) => out.push(e),
+            }
+        }
+        out
+    }
+
+    /// Insert key/value pairs in the container.
+    ///
+    /// Elements are inserted one after the other. Elements whose key is
+    /// already resident replace the resident value, which is returned, and
+    /// count as an access to the key. Other elements are inserted
+    /// following the ARC policy and the elements evicted to make room for
+    /// them are returned. If the container capacity is `0`, all `elements`
+    /// are returned.
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for (key, value) in elements {
+            if self.touch(&key) {
+                let e = self.resident.get_mut(&key).unwrap();
+                let value = std::mem::replace(&mut e.2, value);
+                out.push((key, value));
+            } else {
+                out.append(&mut self.insert(key, value));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArcCache;
+    use crate::tests::{test_building_block, test_concurrent};
+    use crate::{BuildingBlock, Get, Sequential};
+
+    #[test]
+    fn building_block() {
+        test_building_block(ArcCache::new(0), true);
+        test_building_block(ArcCache::new(10), true);
+        test_building_block(ArcCache::new(100), true);
+    }
+
+    #[test]
+    fn concurrent() {
+        test_concurrent(Sequential::new(ArcCache::new(0)), 64);
+        test_concurrent(Sequential::new(ArcCache::new(100)), 64);
+    }
+
+    #[test]
+    fn scan_resistance() {
+        let mut c = ArcCache::new(4);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+
+        // A scan of keys accessed once does not evict frequent keys.
+        for i in 2u16..100u16 {
+            c.push(vec![(i, i as u32)]);
+        }
+        assert!(c.contains(&0u16));
+        assert!(c.contains(&1u16));
+        assert_eq!(c.size(), 4);
+    }
+
+    #[test]
+    fn adaptation() {
+        let mut c = ArcCache::new(2);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+        assert_eq!(c.push(vec![(2u16, 2u32)]), vec![(0u16, 0u32)]);
+        assert_eq!(c.target(), 0);
+
+        // Inserting an evicted key accessed once grows the target size of
+        // the list of elements accessed once.
+        assert_eq!(c.push(vec![(0u16, 0u32)]), vec![(1u16, 1u32)]);
+        assert_eq!(c.target(), 1);
+        assert!(c.contains(&0u16));
+
+        // Inserting an evicted key accessed twice shrinks it back.
+        assert_eq!(c.push(vec![(1u16, 1u32)]).len(), 1);
+        assert_eq!(c.target(), 0);
+
+        // Evicted elements are remembered as ghosts, not contained.
+        assert_eq!(c.pop(2).len(), 2);
+        assert_eq!(c.size(), 0);
+        assert!(!c.contains(&0u16));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::builder::ArcCacheBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericKey, GenericValue, IntoConfig,
+};
+use crate::objsafe::DynBuildingBlock;
+use crate::ArcCache;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// Configuration format for [`ArcCache`](../struct.ArcCache.html)
+/// containers.
+///
+/// This configuration format is composed of two key/value fields that
+/// must be present:      
+/// - `id = "ArcCacheConfig"` and
+/// - `capacity = <int>`
+///
+/// The `id` field must be exactly "ArcCacheConfig" while the capacity
+/// will set the maximum number of key/value pairs that the container can
+/// hold.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id = 'ArcCacheConfig'
+/// capacity = 10
+/// ");
+/// let container: DynBuildingBlock<u64, u64> =
+///            ConfigBuilder::from_string(config_str.as_str())
+///            .unwrap()
+///            .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct ArcCacheConfig {
+    #[allow(dead_code)]
+    id: String,
+    capacity: usize,
+}
+
+impl ConfigInstance for ArcCacheConfig {
+    fn id() -> &'static str {
+        "ArcCacheConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        toml::from_str(&toml).map_err(|e| {
+            ConfigError::ConfigFormatError(format!(
+                "Invalid ArcCacheConfig: {}\n{:?}",
+                toml, e
+            ))
+        })
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        DynBuildingBlock::new_resizable_get(
+            ArcCache::new(self.capacity),
+            false,
+            true,
+        )
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> IntoConfig<ArcCacheConfig>
+    for ArcCacheBuilder<K, V>
+{
+    fn as_config(&self) -> ArcCacheConfig {
+        ArcCacheConfig {
+            id: String::from(ArcCacheConfig::id()),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArcCacheConfig;
+    use crate::builder::ArcCacheBuilder;
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_arc_config() {
+        let capacity = 1008;
+        let config_str =
+            format!("id='ArcCacheConfig'\ncapacity={}", capacity);
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = ArcCacheConfig::from_toml(&value).unwrap();
+        assert_eq!(config.capacity, capacity);
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_invalid_arc_config() {
+        let config_str = "id='ArcCacheConfig'\ncapacity='ten'".to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            ArcCacheConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder = ArcCacheBuilder::<(), ()>::new(2);
+        test_config_builder(builder);
+    }
+}
@@ -0,0 +1,94 @@
+use super::ArcCache;
+use crate::utils::get::LifeTimeGuard;
+use crate::{Get, GetMut};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+
+/// Read-only cell representing a reference to a value inside an
+/// [`ArcCache`](struct.ArcCache.html) container.
+pub struct ArcCacheCell<V> {
+    t: *const V,
+}
+
+impl<V> Deref for ArcCacheCell<V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // The cell is wrapped in a `LifeTimeGuard` borrowing the container
+        // mutably. Therefore, the container cannot be modified while this
+        // cell is alive.
+        unsafe { self.t.as_ref().unwrap() }
+    }
+}
+
+/// Read-write cell holding a reference to a value inside an
+/// [`ArcCache`](struct.ArcCache.html) container.
+pub struct ArcCacheMutCell<V> {
+    t: *mut V,
+}
+
+impl<V> Deref for ArcCacheMutCell<V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // See `ArcCacheCell` Deref trait method.
+        unsafe { self.t.as_ref().unwrap() }
+    }
+}
+
+impl<V> DerefMut for ArcCacheMutCell<V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // See `ArcCacheCell` Deref trait method.
+        unsafe { self.t.as_mut().unwrap() }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Get<K, V> for ArcCache<K, V> {
+    type Target = ArcCacheCell<V>;
+
+    /// Accessed elements are moved to the most recently used end of the
+    /// list of frequently used elements.
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        if !self.touch(key) {
+            return None;
+        }
+        self.resident
+            .get(key)
+            .map(|(_, _, v)| LifeTimeGuard::new(ArcCacheCell { t: v }))
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> GetMut<K, V> for ArcCache<K, V> {
+    type Target = ArcCacheMutCell<V>;
+
+    /// Accessed elements are moved to the most recently used end of the
+    /// list of frequently used elements.
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        if !self.touch(key) {
+            return None;
+        }
+        self.resident
+            .get_mut(key)
+            .map(|(_, _, v)| LifeTimeGuard::new(ArcCacheMutCell { t: v }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArcCache;
+    use crate::tests::{test_get, test_get_mut};
+
+    #[test]
+    fn get() {
+        test_get(ArcCache::new(0));
+        test_get(ArcCache::new(10));
+        test_get(ArcCache::new(100));
+        test_get_mut(ArcCache::new(0));
+        test_get_mut(ArcCache::new(10));
+        test_get_mut(ArcCache::new(100));
+    }
+}
@@ -0,0 +1,9 @@
+#[allow(clippy::module_inception)]
+mod arc;
+pub use arc::ArcCache;
+mod building_block;
+mod get;
+mod resizable;
+pub(crate) mod builder;
+#[cfg(feature = "config")]
+pub(crate) mod config;
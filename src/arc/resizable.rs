@@ -0,0 +1,36 @@
+use super::ArcCache;
+use crate::{BuildingBlock, Resizable};
+use std::hash::Hash;
+
+impl<K: Clone + Eq + Hash, V> Resizable<K, V> for ArcCache<K, V> {
+    /// Change the [`ArcCache`] capacity.
+    ///
+    /// If the resident elements do not fit in the new capacity, victims are
+    /// evicted with the [`pop()`](trait.BuildingBlock.html#method.pop)
+    /// method. Ghost keys are forgotten until they fit the new capacity
+    /// as well.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity;
+        self.target = self.target.min(new_capacity);
+        let out = if self.size() > new_capacity {
+            self.pop(self.size() - new_capacity)
+        } else {
+            Vec::new()
+        };
+        self.trim_ghosts();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArcCache;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        test_resizable(ArcCache::new(0));
+        test_resizable(ArcCache::new(10));
+        test_resizable(ArcCache::new(100));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::random::Rng;
 use std::vec::Vec;
 
 /// In-memory container implementation as a fixed size array of key/value pairs.
@@ -18,6 +19,12 @@ use std::vec::Vec;
 /// elements that can fit into the container, i.e the greatest elements within
 /// the container are popped before inserting the new elements.
 ///
+/// Sorting the whole container on eviction can be replaced with a cheaper
+/// approximation using the method
+/// [`with_sampling()`](struct.Array.html#method.with_sampling). In this
+/// mode, each victim is the greatest element out of a few elements picked
+/// at random in the container.
+///
 /// See [`BuildingBlock` implementation](struct.Array.html#impl-BuildingBlock)
 /// for more detail on how does the container operates.
 ///
@@ -64,6 +71,7 @@ pub struct Array<T> {
     pub(super) total_size: usize,
     pub(super) values: Vec<T>,
     pub(super) element_size: fn(&T) -> usize,
+    pub(super) sampling: Option<(usize, Rng)>,
 }
 
 impl<T> Array<T> {
@@ -82,6 +90,7 @@ impl<T> Array<T> {
             capacity: size,
             values: Vec::new(),
             element_size: |_| 1,
+            sampling: None,
         }
     }
 
@@ -104,6 +113,24 @@ impl<T> Array<T> {
         self.element_size = element_size;
         self
     }
+
+    /// Evict sampled elements instead of the greatest elements.
+    ///
+    /// When elements need to be evicted, `samples` elements are picked at
+    /// random in the container and the greatest of them is evicted. This
+    /// is repeated until enough room is freed. For instance, an [`Array`]
+    /// of [`Lru`](utils/decorator/struct.Lru.html) decorated values
+    /// implements a sampled Lru eviction policy.
+    ///
+    /// The random generator is seeded with `seed` such that evictions are
+    /// reproducible. `samples` must be strictly greater than 0.
+    pub fn with_sampling(mut self, samples: usize, seed: u64) -> Self {
+        if samples == 0 {
+            panic!("Array sampling size cannot be 0.");
+        }
+        self.sampling = Some((samples, Rng::new(seed)));
+        self
+    }
 }
 
 impl<T: Clone> Clone for Array<T> {
@@ -113,6 +140,7 @@ impl<T: Clone> Clone for Array<T> {
             total_size: self.total_size,
             values: self.values.clone(),
             element_size: self.element_size,
+            sampling: self.sampling.clone(),
         }
     }
 }
@@ -121,6 +149,6 @@ impl<'a, K: 'a + Ord, V: 'a + Ord> From<Array<(K, V)>>
     for crate::DynBuildingBlock<'a, K, V>
 {
     fn from(array: Array<(K, V)>) -> Self {
-        crate::DynBuildingBlock::new(array, false)
+        crate::DynBuildingBlock::new_resizable_get(array, false, true)
     }
 }
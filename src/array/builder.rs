@@ -18,6 +18,7 @@ use std::marker::PhantomData;
 /// ```
 pub struct ArrayBuilder<T> {
     pub(super) capacity: usize,
+    pub(super) sampling: Option<(usize, u64)>,
     unused: PhantomData<T>,
 }
 
@@ -27,15 +28,31 @@ impl<T> ArrayBuilder<T> {
     pub fn new(capacity: usize) -> Self {
         ArrayBuilder {
             capacity,
+            sampling: None,
             unused: PhantomData,
         }
     }
+
+    /// The [Array](../../struct.Array.html) container spawned by this
+    /// builder will evict the greatest element out of `samples` random
+    /// elements.
+    ///
+    /// See [`Array::with_sampling()`](../../struct.Array.html#method.with_sampling).
+    /// `samples` must be strictly greater than 0.
+    pub fn with_sampling(mut self, samples: usize, seed: u64) -> Self {
+        if samples == 0 {
+            panic!("Array sampling size cannot be 0.");
+        }
+        self.sampling = Some((samples, seed));
+        self
+    }
 }
 
 impl<T> Clone for ArrayBuilder<T> {
     fn clone(&self) -> Self {
         ArrayBuilder {
             capacity: self.capacity,
+            sampling: self.sampling,
             unused: PhantomData,
         }
     }
@@ -43,6 +60,11 @@ impl<T> Clone for ArrayBuilder<T> {
 
 impl<T> Build<Array<T>> for ArrayBuilder<T> {
     fn build(self) -> Array<T> {
-        Array::new(self.capacity)
+        match self.sampling {
+            None => Array::new(self.capacity),
+            Some((samples, seed)) => {
+                Array::new(self.capacity).with_sampling(samples, seed)
+            }
+        }
     }
 }
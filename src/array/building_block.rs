@@ -31,6 +31,14 @@ where
         self.values.iter().any(|(k, _)| k == key)
     }
 
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.values.iter().any(|(k, v)| k == key && predicate(v))
+    }
+
     /// Get the size currently occupied by elements in this [`Array`].
     ///
     /// This is the sum of this [`Array`] elements size, as defined by the
@@ -52,6 +60,11 @@ where
     /// sum of evicted elements' size meets the `size` threshold. Elements
     /// size is computed with the function `element_size()` set by the method
     /// [`with_element_size()`](struct.Array.html#method.with_element_size).
+    ///
+    /// If the [`Array`] was built
+    /// [`with_sampling()`](struct.Array.html#method.with_sampling),
+    /// the greatest element out of a random sample is evicted instead,
+    /// until the `size` threshold is met.
     fn pop(&mut self, size: usize) -> Vec<(K, V)> {
         // If the vector is empty there is nothing to return.
         if self.values.is_empty() {
@@ -67,6 +80,27 @@ where
 
         // Otherwise, we need to evict the greatest elements.
 
+        // Sampled eviction.
+        if let Some((samples, rng)) = &mut self.sampling {
+            let mut out = Vec::new();
+            let mut freed = 0;
+            while freed < size {
+                let n = self.values.len();
+                let mut victim = rng.below(n);
+                for _ in 1..*samples {
+                    let i = rng.below(n);
+                    if self.values[i].1 > self.values[victim].1 {
+                        victim = i;
+                    }
+                }
+                let element = self.values.swap_remove(victim);
+                freed += (self.element_size)(&element);
+                out.push(element);
+            }
+            self.total_size -= freed;
+            return out;
+        }
+
         // Sort values.
         self.values.sort_unstable_by(|(_, v1), (_, v2)| v1.cmp(v2));
 
@@ -204,4 +238,32 @@ mod tests {
             true,
         );
     }
+
+    #[test]
+    fn building_block_sampling() {
+        test_building_block(Array::new(0).with_sampling(5, 0), true);
+        test_building_block(Array::new(10).with_sampling(5, 0), true);
+        test_building_block(Array::new(100).with_sampling(5, 0), true);
+    }
+
+    #[test]
+    fn sampled_eviction() {
+        use crate::BuildingBlock;
+        let elements: Vec<(u16, u32)> =
+            (0..10).map(|i| (i, i as u32)).collect();
+
+        // Same seeds evict the same elements.
+        let mut a = Array::new(10).with_sampling(3, 42);
+        let mut b = Array::new(10).with_sampling(3, 42);
+        a.push(elements.clone());
+        b.push(elements.clone());
+        assert_eq!(a.pop(4), b.pop(4));
+        assert_eq!(a.size(), 6);
+
+        // Sampling as many elements as the container size evicts the
+        // greatest element with high probability.
+        let mut c = Array::new(10).with_sampling(100, 0);
+        c.push(elements);
+        assert_eq!(c.pop(1), vec![(9u16, 9u32)]);
+    }
 }
@@ -16,6 +16,12 @@ use serde::{Deserialize, Serialize};
 /// The `id` field must be exactly "ArrayConfig" while the capacity
 /// will set the maximum number of key/value pairs that the array can
 /// hold.
+///
+/// Optionally, `sampling = <int>` and `seed = <int>` fields set the
+/// array to evict the greatest element out of `sampling` random elements.
+/// `sampling` must be strictly greater than 0.
+/// See [`Array::with_sampling()`](../struct.Array.html#method.with_sampling).
+/// The `seed` defaults to `0`.
 /// ```
 /// use byoc::{BuildingBlock, DynBuildingBlock};
 /// use byoc::config::{ConfigInstance, ConfigBuilder};
@@ -34,6 +40,21 @@ pub struct ArrayConfig {
     #[allow(dead_code)]
     id: String,
     capacity: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sampling: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+impl ArrayConfig {
+    /// Build the [`Array`] described by this configuration.
+    pub(crate) fn array<T>(&self) -> Array<T> {
+        match self.sampling {
+            None => Array::new(self.capacity),
+            Some(samples) => Array::new(self.capacity)
+                .with_sampling(samples, self.seed.unwrap_or(0)),
+        }
+    }
 }
 
 impl ConfigInstance for ArrayConfig {
@@ -43,18 +64,32 @@ impl ConfigInstance for ArrayConfig {
 
     fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
         let toml = toml::to_string(&value).unwrap();
-        toml::from_str(&toml).map_err(|e| {
+        let cfg: ArrayConfig = toml::from_str(&toml).map_err(|e| {
             ConfigError::ConfigFormatError(format!(
                 "Invalid ArrayConfig: {}\n{:?}",
                 toml, e
             ))
-        })
+        })?;
+        if cfg.sampling == Some(0) {
+            return Err(ConfigError::ConfigFormatError(String::from(
+                "Invalid ArrayConfig sampling: must be greater than 0.",
+            )));
+        }
+        Ok(cfg)
     }
 
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        DynBuildingBlock::new(Array::new(self.capacity), false)
+        DynBuildingBlock::new_resizable_get(self.array(), false, true)
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
     }
 }
 
@@ -63,6 +98,8 @@ impl<T> IntoConfig<ArrayConfig> for ArrayBuilder<T> {
         ArrayConfig {
             id: String::from(ArrayConfig::id()),
             capacity: self.capacity,
+            sampling: self.sampling.map(|(samples, _)| samples),
+            seed: self.sampling.map(|(_, seed)| seed),
         }
     }
 }
@@ -72,7 +109,7 @@ mod tests {
     use super::ArrayConfig;
     use crate::builder::ArrayBuilder;
     use crate::config::tests::test_config_builder;
-    use crate::config::ConfigInstance;
+    use crate::config::{ConfigError, ConfigInstance};
     use crate::objsafe::DynBuildingBlock;
     use crate::BuildingBlock;
 
@@ -89,9 +126,32 @@ mod tests {
         assert_eq!(array.capacity(), capacity);
     }
 
+    #[test]
+    fn test_sampling_array_config() {
+        let config_str = "id='ArrayConfig'\ncapacity=10\nsampling=5";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = ArrayConfig::from_toml(&value).unwrap();
+        assert_eq!(config.sampling, Some(5));
+        assert_eq!(config.seed, None);
+        let array: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(array.capacity(), 10);
+    }
+
+    #[test]
+    fn test_invalid_sampling_array_config() {
+        let config_str = "id='ArrayConfig'\ncapacity=10\nsampling=0";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        assert!(matches!(
+            ArrayConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
     #[test]
     fn test_builder_as_config() {
         let builder = ArrayBuilder::<()>::new(10);
         test_config_builder(builder);
+        let builder = ArrayBuilder::<()>::new(10).with_sampling(5, 42);
+        test_config_builder(builder);
     }
 }
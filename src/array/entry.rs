@@ -0,0 +1,40 @@
+use super::{Array, ArrayMutCell};
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, GetEntry, GetMut};
+
+impl<K, V> GetEntry<K, V> for Array<(K, V)>
+where
+    K: Ord,
+    V: Ord,
+{
+    type Target = ArrayMutCell<V>;
+
+    /// Look up the key once. Vacant entries are inserted with the
+    /// [`Array`] [`push()`](trait.BuildingBlock.html#method.push) method.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        match self.get_mut(&key).map(LifeTimeGuard::unwrap) {
+            Some(value) => Entry::Occupied(OccupiedEntry::new(
+                key,
+                LifeTimeGuard::new(value),
+            )),
+            None => Entry::Vacant(VacantEntry::new(
+                key,
+                Box::new(move |k, v| self.push(vec![(k, v)])),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Array;
+    use crate::tests::test_entry;
+
+    #[test]
+    fn entry() {
+        test_entry(Array::new(0));
+        test_entry(Array::new(10));
+        test_entry(Array::new(100));
+    }
+}
@@ -0,0 +1,26 @@
+use super::Array;
+use crate::Iter;
+
+impl<K: Clone, V: Clone> Iter<K, V> for Array<(K, V)> {
+    type Iterator<'a>
+        = std::iter::Cloned<std::slice::Iter<'a, (K, V)>>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.values.iter().cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Array;
+    use crate::tests::test_iter;
+
+    #[test]
+    fn iter() {
+        test_iter(Array::new(0));
+        test_iter(Array::new(10));
+        test_iter(Array::new(100));
+    }
+}
@@ -2,7 +2,12 @@
 mod array;
 pub use array::Array;
 mod building_block;
+mod entry;
 mod get;
+mod iter;
+mod range;
+mod resizable;
+mod try_building_block;
 pub use get::{ArrayCell, ArrayMutCell};
 pub(crate) mod builder;
 #[cfg(feature = "config")]
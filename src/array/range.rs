@@ -0,0 +1,67 @@
+use super::Array;
+use crate::{RangeGet, RangeTake};
+use std::ops::RangeBounds;
+
+impl<K: Clone + Ord, V> RangeGet<K, V> for Array<(K, V)> {
+    /// Check whether the container contains at least one key within
+    /// `range`.
+    ///
+    /// This method scans the [`Array`] elements until a key within
+    /// `range` is found.
+    fn contains_range<R: RangeBounds<K>>(&self, range: R) -> bool {
+        self.values.iter().any(|(k, _)| range.contains(k))
+    }
+
+    /// Get the keys of the container within `range` sorted in ascending
+    /// order.
+    ///
+    /// This method scans all the [`Array`] elements and sorts the matching
+    /// keys.
+    fn keys_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<K> {
+        let mut keys: Vec<K> = self
+            .values
+            .iter()
+            .filter_map(|(k, _)| {
+                if range.contains(k) {
+                    Some(k.clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        keys.sort();
+        keys
+    }
+}
+
+impl<K: Ord, V> RangeTake<K, V> for Array<(K, V)> {
+    /// Take all the key/value pairs with a key within `range` out of the
+    /// container.
+    ///
+    /// This method scans all the [`Array`] elements in reverse order and
+    /// removes the elements with a matching key.
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)> {
+        let mut ret = Vec::new();
+        for i in (0..self.values.len()).rev() {
+            if range.contains(&self.values[i].0) {
+                self.total_size -=
+                    (self.element_size)(self.values.get(i).unwrap());
+                ret.push(self.values.swap_remove(i));
+            }
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Array;
+    use crate::tests::test_range;
+
+    #[test]
+    fn range() {
+        test_range(Array::new(0));
+        test_range(Array::new(10));
+        test_range(Array::new(100));
+    }
+}
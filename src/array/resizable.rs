@@ -0,0 +1,30 @@
+use super::Array;
+use crate::{BuildingBlock, Resizable};
+
+impl<K: Ord, V: Ord> Resizable<K, V> for Array<(K, V)> {
+    /// Change the [`Array`] capacity.
+    ///
+    /// If the elements of the container do not fit in the new capacity,
+    /// the elements with the greatest values are popped out until they do.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity;
+        if self.total_size > new_capacity {
+            self.pop(self.total_size - new_capacity)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Array;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        test_resizable(Array::new(0));
+        test_resizable(Array::new(10));
+        test_resizable(Array::new(100));
+    }
+}
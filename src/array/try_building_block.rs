@@ -0,0 +1,17 @@
+use super::Array;
+use crate::TryBuildingBlock;
+
+impl<K: Ord, V: Ord> TryBuildingBlock<K, V> for Array<(K, V)> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Array;
+    use crate::tests::test_try_building_block;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(Array::new(0), true);
+        test_try_building_block(Array::new(10), true);
+        test_try_building_block(Array::new(100), true);
+    }
+}
@@ -1,4 +1,7 @@
-use std::hash::{Hash, Hasher};
+use super::{BucketExecution, BucketSelector, Parallel, Serial};
+use std::sync::{
+    Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
 
 /// Associative `BuildingBlock` wrapper with multiple sets/buckets.
 ///
@@ -9,11 +12,16 @@ use std::hash::{Hash, Hasher};
 /// to balance the access to multiple containers while offering some
 /// amount of parallelism when using it concurrently.
 ///
-/// Keys inserted in this container must be hashable and their hash value
-/// is used to associate them with a specific bucket. Association between
-/// keys and buckets cannot be changed once this container is instantiated.
-/// Below is how keys and buckets is associated. This can be used to provide
-/// a custom `Hasher` that will tune how keys are grouped into buckets.
+/// Keys inserted in this container are associated with a specific bucket
+/// by a [`BucketSelector`](utils/associative/trait.BucketSelector.html).
+/// Association between keys and buckets cannot be changed once this
+/// container is instantiated.
+///
+/// The selector can simply be a `Hasher`. In that case, keys must be
+/// hashable and their hash value is used to associate them with a specific
+/// bucket. Below is how keys and buckets is associated. This can be used to
+/// provide a custom `Hasher` that will tune how keys are grouped into
+/// buckets.
 ///
 /// ```
 /// use std::collections::hash_map::DefaultHasher;
@@ -32,13 +40,33 @@ use std::hash::{Hash, Hasher};
 /// let key_bucket_index = key_hash % n_buckets;
 /// ```
 ///
+/// Other selectors are available in the
+/// [`utils::associative`](utils/associative/index.html) module, for
+/// instance to keep ranges of keys in the same bucket:
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::{Array, Associative};
+/// use byoc::utils::associative::KeyRange;
+///
+/// let mut c = Associative::new(vec![Array::new(2); 2],
+///                              KeyRange::new(vec![10]));
+/// assert!(c.push(vec![(1, 1), (2, 2)]).is_empty());
+/// // Keys 1 and 2 go in the first bucket, which is full.
+/// assert_eq!(c.push(vec![(3, 3)]).len(), 1);
+/// ```
+///
 /// Since a key can only go in one bucket, the container may refuse
 /// insertions before it is actually full if the target bucket is full.
+/// See the overflow mode below to mitigate this issue.
 ///
 /// [`Get`](trait.Get.html) and [`Concurrent`](trait.Concurrent.html)
 /// traits are inherited from the type of container used to build this
 /// associative container. If the buckets bear any of these traits,
-/// then so does [`Associative`] container.
+/// then so does [`Associative`] container. `Concurrent` clones of this
+/// container share its list of buckets behind a read/write lock. Methods
+/// reading the container lock the list for reading while methods
+/// modifying the container lock it for writing.
 ///
 /// ## Examples
 ///
@@ -65,44 +93,234 @@ use std::hash::{Hash, Hasher};
 /// }
 ///```
 ///
+/// ## Parallel Execution
+///
+/// By default, methods working on several buckets at once, i.e
+/// [`take_multiple()`](trait.BuildingBlock.html#tymethod.take_multiple),
+/// [`pop()`](trait.BuildingBlock.html#tymethod.pop),
+/// [`push()`](trait.BuildingBlock.html#tymethod.push) and
+/// [`flush()`](trait.BuildingBlock.html#tymethod.flush), process buckets
+/// one after the other. The execution strategy is the third type parameter
+/// of this container. With the
+/// [`into_parallel()`](struct.Associative.html#method.into_parallel)
+/// method, buckets work is instead dispatched on several threads.
+/// See [`Parallel`](utils/associative/struct.Parallel.html) for details.
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::{Array, Associative};
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// let mut c = Associative::new(vec![Array::new(2); 4],
+///                              DefaultHasher::new())
+///     .into_parallel(2);
+/// assert!(c.push(vec![(0, 4), (1, 12)]).is_empty());
+/// assert_eq!(c.flush().count(), 2);
+/// ```
+///
+/// ## Overflow
+///
+/// When a bucket receiving many keys evicts elements while other buckets
+/// still have room, the container may be turned into an overflow mode with
+/// the [`with_overflow()`](struct.Associative.html#method.with_overflow)
+/// method. In this mode, each key has a second candidate bucket: the bucket
+/// chosen by the selector among the other buckets. On
+/// [`push()`](trait.BuildingBlock.html#tymethod.push), the elements evicted
+/// out of their first bucket are pushed in their second bucket, and only
+/// the elements evicted from there are returned. Lookups check both
+/// candidate buckets. With
+/// [`KeyRange`](utils/associative/struct.KeyRange.html),
+/// [`JumpHash`](utils/associative/struct.JumpHash.html) and
+/// [`Rendezvous`](utils/associative/struct.Rendezvous.html) selectors,
+/// the second bucket of most keys is the bucket next to their first
+/// bucket.
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::{Array, Associative};
+/// use byoc::utils::associative::KeyRange;
+///
+/// let mut c = Associative::new(vec![Array::new(2); 2],
+///                              KeyRange::new(vec![10]))
+///     .with_overflow();
+/// assert!(c.push(vec![(1, 1), (2, 2)]).is_empty());
+/// // The first bucket is full, one element moves to the second bucket.
+/// assert!(c.push(vec![(3, 3)]).is_empty());
+/// assert!(c.contains(&1) && c.contains(&2) && c.contains(&3));
+/// ```
+///
 /// [`Associative`] can also be built from a
 /// [builder pattern](builder/builders/struct.AssociativeBuilder.html) and a
 /// [configuration](config/struct.AssociativeConfig.html).
-pub struct Associative<C, H: Hasher + Clone> {
-    pub(super) containers: Vec<C>,
-    pub(super) hasher: H,
+pub struct Associative<C, S, E = Serial> {
+    // Shared with `Concurrent` clones such that they all see the same
+    // buckets when buckets are added or removed.
+    pub(super) containers: Arc<Buckets<C>>,
+    pub(super) selector: S,
+    pub(super) execution: E,
+    pub(super) overflow: bool,
+}
+
+/// The sets of an [`Associative`] container, shared with its
+/// [`Concurrent`](trait.Concurrent.html) clones.
+pub(super) struct Buckets<C> {
+    pub(super) list: RwLock<Vec<C>>,
+    // `Concurrent::clone()` of the sets, set when the container is
+    // cloned for the first time.
+    pub(super) clone_bucket: OnceLock<fn(&C) -> C>,
 }
 
-impl<C, H: Hasher + Clone> Associative<C, H> {
+impl<C> Buckets<C> {
+    pub(super) fn new(list: Vec<C>) -> Self {
+        Buckets {
+            list: RwLock::new(list),
+            clone_bucket: OnceLock::new(),
+        }
+    }
+}
+
+impl<C, S> Associative<C, S> {
     /// Construct a new associative container.
     ///
     /// This function builds an associative container using other
-    /// containers as sets.
-    pub fn new(sets: Vec<C>, key_hasher: H) -> Self {
+    /// containers as sets. Keys are associated with sets by the
+    /// `selector`, which is usually a
+    /// [`BucketSelector`](utils/associative/trait.BucketSelector.html)
+    /// or a `Hasher`. Sets are processed one after the other.
+    pub fn new(sets: Vec<C>, selector: S) -> Self {
         Associative {
-            containers: sets,
-            hasher: key_hasher,
+            containers: Arc::new(Buckets::new(sets)),
+            selector,
+            execution: Serial,
+            overflow: false,
         }
     }
+}
+
+impl<C, S, E> Associative<C, S, E> {
+    /// Dispatch the work on sets of this container on up to `threads`
+    /// threads.
+    ///
+    /// Sets, keys and values must be [`Send`] for the resulting container
+    /// to implement [`BuildingBlock`](trait.BuildingBlock.html).
+    /// See [`Parallel`](utils/associative/struct.Parallel.html) for
+    /// details.
+    ///
+    /// Panics if `threads` is `0`.
+    pub fn into_parallel(
+        self,
+        threads: usize,
+    ) -> Associative<C, S, Parallel> {
+        self.with_execution(Parallel::new(threads))
+    }
+
+    pub(super) fn with_execution<F>(
+        self,
+        execution: F,
+    ) -> Associative<C, S, F> {
+        Associative {
+            containers: self.containers,
+            selector: self.selector,
+            execution,
+            overflow: self.overflow,
+        }
+    }
+
+    /// Offer the elements evicted out of a full set to a second set before
+    /// returning them.
+    ///
+    /// The second set of a key is the set chosen by the selector among
+    /// the other sets. Lookups check both sets of a key. Elements evicted
+    /// out of their second set are not offered to another set.
+    /// This mode has no effect on containers with a single set.
+    pub fn with_overflow(mut self) -> Self {
+        self.overflow = true;
+        self
+    }
+
+    /// Whether elements evicted out of a full set are offered to a second
+    /// set.
+    pub fn overflow(&self) -> bool {
+        self.overflow
+    }
 
-    pub(super) fn set<K: Hash>(&self, key: K) -> usize {
-        let n_sets = self.containers.len();
-        let mut hasher = self.hasher.clone();
-        key.hash(&mut hasher);
-        let i = hasher.finish();
-        (i % (n_sets as u64)) as usize
+    /// The selector associating keys with sets.
+    pub fn selector(&self) -> &S {
+        &self.selector
+    }
+
+    /// Lock the list of sets for reading.
+    pub(super) fn buckets(&self) -> RwLockReadGuard<'_, Vec<C>> {
+        self.containers.list.read().unwrap()
+    }
+
+    /// Lock the list of sets for writing.
+    ///
+    /// This excludes all the [`Concurrent`](trait.Concurrent.html) clones
+    /// of this container and is only meant to add or remove sets.
+    pub(super) fn buckets_mut(&self) -> RwLockWriteGuard<'_, Vec<C>> {
+        self.containers.list.write().unwrap()
+    }
+
+    /// Run `f` on mutable sets.
+    ///
+    /// Until this container is cloned, the list of sets is locked for
+    /// writing, which is free of contention, and `f` runs on the sets
+    /// themselves. Once cloned, the list of sets is only locked for
+    /// reading such that clones do not wait for each other, and `f` runs
+    /// on [`Concurrent`](trait.Concurrent.html) clones of the sets.
+    pub(super) fn with_buckets<R>(
+        &self,
+        f: impl FnOnce(&mut [C]) -> R,
+    ) -> R {
+        match self.containers.clone_bucket.get() {
+            None => f(&mut self.buckets_mut()),
+            Some(clone) => {
+                let mut buckets: Vec<C> =
+                    self.buckets().iter().map(clone).collect();
+                f(&mut buckets)
+            }
+        }
+    }
+
+    /// The set of a key among `n` sets.
+    pub(super) fn set<K>(&self, key: &K, n: usize) -> usize
+    where
+        S: BucketSelector<K>,
+    {
+        self.selector.select(key, n)
+    }
+
+    /// The second set of a key among `n` sets in overflow mode, i.e the
+    /// set chosen by the selector when the first set of the key is left
+    /// out.
+    pub(super) fn overflow_set<K>(
+        &self,
+        key: &K,
+        n: usize,
+    ) -> Option<usize>
+    where
+        S: BucketSelector<K>,
+    {
+        if !self.overflow || n < 2 {
+            return None;
+        }
+        let i = self.set(key, n);
+        let j = self.selector.select(key, n - 1);
+        Some(if j < i { j } else { j + 1 })
     }
 }
 
-impl<'a, K, V, C, H> From<Associative<C, H>>
+impl<'a, K, V, C, S, E> From<Associative<C, S, E>>
     for crate::DynBuildingBlock<'a, K, V>
 where
-    K: 'a + Clone + Hash,
+    K: 'a + Clone,
     V: 'a + Ord,
     C: 'a + crate::BuildingBlock<K, V> + crate::Concurrent,
-    H: 'a + Hasher + Clone,
+    S: 'a + BucketSelector<K>,
+    E: 'a + BucketExecution<K, V, C> + Clone + Send + Sync,
 {
-    fn from(associative: Associative<C, H>) -> Self {
+    fn from(associative: Associative<C, S, E>) -> Self {
         crate::DynBuildingBlock::new(associative, true)
     }
 }
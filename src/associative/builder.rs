@@ -1,8 +1,10 @@
-use crate::associative::ExclusiveHasher;
+use super::associative::Buckets;
+use crate::associative::{ExclusiveHasher, Parallel, Serial};
 use crate::builder::Build;
 use crate::Associative;
 use std::hash::Hasher;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// `Associative` container builder.
 ///
@@ -44,26 +46,38 @@ use std::marker::PhantomData;
 ///     .add_layer(2)
 ///     .build();
 /// container.push(vec![(1, 2)]);
+///
+/// // Buckets can also be processed in parallel:
+/// let mut container = ArrayBuilder::new(2)
+///     .into_associative(DefaultHasher::new(), 4)
+///     .into_parallel(2)
+///     .build();
+/// container.push(vec![(1, 2), (3, 4)]);
 /// ```
-pub struct AssociativeBuilder<C, H, B>
+pub struct AssociativeBuilder<C, H, B, E = Serial>
 where
     H: Hasher,
 {
     pub(super) builder: B,
     pub(super) num_sets: usize,
+    pub(super) execution: E,
+    pub(super) overflow: bool,
     set_hasher: ExclusiveHasher<H>,
     unused: PhantomData<C>,
 }
 
-impl<C, H, B> Clone for AssociativeBuilder<C, H, B>
+impl<C, H, B, E> Clone for AssociativeBuilder<C, H, B, E>
 where
     H: Hasher + Clone,
     B: Clone,
+    E: Clone,
 {
     fn clone(&self) -> Self {
         AssociativeBuilder {
             builder: self.builder.clone(),
             num_sets: self.num_sets,
+            execution: self.execution.clone(),
+            overflow: self.overflow,
             set_hasher: self.set_hasher.clone(),
             unused: PhantomData,
         }
@@ -78,15 +92,22 @@ where
         AssociativeBuilder {
             builder,
             num_sets,
+            execution: Serial,
+            overflow: false,
             set_hasher: ExclusiveHasher::new(key_hasher, num_sets),
             unused: PhantomData,
         }
     }
+}
 
+impl<C, H, B, E> AssociativeBuilder<C, H, B, E>
+where
+    H: Hasher + Clone,
+{
     pub fn add_layer(
         self,
         num_keys: usize,
-    ) -> AssociativeBuilder<Associative<C, ExclusiveHasher<H>>, H, Self>
+    ) -> AssociativeBuilder<Associative<C, ExclusiveHasher<H>, E>, H, Self>
     {
         let hasher = self.set_hasher.next(num_keys).expect(
             "Too many and/or too large associative layers stacked.",
@@ -94,23 +115,60 @@ where
         AssociativeBuilder {
             builder: self,
             num_sets: num_keys,
+            execution: Serial,
+            overflow: false,
             set_hasher: hasher,
             unused: PhantomData,
         }
     }
+
+    /// Process the buckets of the built container on up to `threads`
+    /// threads.
+    ///
+    /// See [`Parallel`](../../utils/associative/struct.Parallel.html) for
+    /// details.
+    pub fn into_parallel(
+        self,
+        threads: usize,
+    ) -> AssociativeBuilder<C, H, B, Parallel> {
+        AssociativeBuilder {
+            builder: self.builder,
+            num_sets: self.num_sets,
+            execution: Parallel::new(threads),
+            overflow: self.overflow,
+            set_hasher: self.set_hasher,
+            unused: PhantomData,
+        }
+    }
+
+    /// Offer the elements evicted out of a full set of the built
+    /// container to a second set before returning them.
+    ///
+    /// See
+    /// [`Associative::with_overflow()`](../../struct.Associative.html#method.with_overflow)
+    /// for details.
+    pub fn with_overflow(mut self) -> Self {
+        self.overflow = true;
+        self
+    }
 }
 
-impl<C, H, B> Build<Associative<C, ExclusiveHasher<H>>>
-    for AssociativeBuilder<C, H, B>
+impl<C, H, B, E> Build<Associative<C, ExclusiveHasher<H>, E>>
+    for AssociativeBuilder<C, H, B, E>
 where
     B: Build<C> + Clone,
     H: Hasher + Clone,
 {
-    fn build(self) -> Associative<C, ExclusiveHasher<H>> {
-        let sets = (0..self.num_sets)
+    fn build(self) -> Associative<C, ExclusiveHasher<H>, E> {
+        let containers = (0..self.num_sets)
             .map(|_| self.builder.clone().build())
             .collect();
-        Associative::new(sets, self.set_hasher)
+        Associative {
+            containers: Arc::new(Buckets::new(containers)),
+            selector: self.set_hasher,
+            execution: self.execution,
+            overflow: self.overflow,
+        }
     }
 }
 
@@ -1,13 +1,193 @@
-use super::Associative;
+use super::{Associative, BucketExecution, BucketSelector};
 use crate::BuildingBlock;
-use std::hash::{Hash, Hasher};
 
-impl<K, V, C, H> BuildingBlock<K, V> for Associative<C, H>
+/// Compute how much to pop from each bucket of an [`Associative`]
+/// container to free `size` while balancing the size of its buckets.
+///
+/// `bucket_sizes` is the size of each bucket of the container. The returned
+/// vector contains pairs of bucket index and size to pop from the bucket.
+/// If everything in the container must be popped, `None` is returned.
+pub(super) fn pop_plan(
+    bucket_sizes: Vec<usize>,
+    size: usize,
+) -> Option<Vec<(usize, usize)>> {
+    // Collect all buckets sizes and indexes.
+    // In this variable we keep the buckets we intend to pop from and
+    // their size.
+    let mut popped_buckets: Vec<(usize, usize)> =
+        bucket_sizes.into_iter().enumerate().collect();
+
+    // The total size of popped buckets.
+    let mut popped_buckets_size =
+        popped_buckets.iter().map(|(_, s)| s).sum();
+
+    // Easy path, we don't need to go further if there is nothing to return.
+    if size == 0 || popped_buckets_size == 0 {
+        return Some(Vec::new());
+    }
+
+    // Easy path, we don't need to go further we need to return everything.
+    if size >= popped_buckets_size {
+        return None;
+    }
+
+    // Generic pop(size) scenario:
+    //
+    //  1   3   2   0  -- sorted buckets
+    //             +-+
+    //         +-+ | |
+    //     +-+ | | | |
+    // ------------------ average bucket size
+    // ------------------ target average bucket size after pop.
+    //     | | | | | |
+    // +-+ | | | | | |
+    // | | | | | | | |
+    // We cannot pop from bucket `1` without increasing imbalance.
+    // Instead we will need to pop below the
+    // target average bucket size after pop` in other buckets.
+    // Consequently, we need to recompute our goal of:
+    // `target average bucket size after pop` without the buckets below
+    // that value. We can process iteratively removing the smallest
+    // bucket with a size below the target value at every step.
+
+    // First, sort ! in reverse order to have the small buckets last.
+    popped_buckets.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
+
+    // Then loop through the buckets starting from the smallest (tail) and:
+    let target_average_bucket_size = loop {
+        // Compute the goal for buckets size:
+        let target_average_bucket_size =
+            (popped_buckets_size - size) / (popped_buckets.len() + 1);
+
+        // Look if the smaller bucket size is greater than the goal.
+        let (bucket_size, bucket_index) = popped_buckets
+            .pop()
+            .expect("Associative container pop() error.");
+
+        // If it is greater, then we can stop and go to the next step.
+        if bucket_size >= target_average_bucket_size {
+            popped_buckets.push((bucket_size, bucket_index));
+            break target_average_bucket_size;
+        }
+
+        // Else, we loop on what's left of the buckets.
+        // The new `popped_buckets_size` of remaining bucket is the current
+        // one minus the discarded bucket.
+        popped_buckets_size -= bucket_size;
+
+        // Since bucket_size < target_average_bucket_size,
+        // there must be more than requested pop size in other buckets.
+        // If this is not true, the next iteration of the loop will panic
+        // on computing `popped_buckets_size - size`.
+        assert!(
+            popped_buckets_size > size,
+            "Associative container pop() error."
+        );
+    };
+
+    // Now all the buckets in `popped_buckets` vector have a size that is
+    // greater than the `target_average_bucket_size`. We just have to pop()
+    // the difference between their size and the target size from them.
+    //
+    //  3   2   0  -- remaining sorted buckets.
+    //         +-+     +
+    //     +-+ | |     |
+    // +-+ | | | |     | size to pop      +
+    // | | | | | |     | in last bucket.  | size to pop
+    // | | | | | |     |                  | in first bucket.
+    // | | | | | |     +                  +
+    // -------------- average bucket size after pop
+    // | | | | | |
+    Some(
+        popped_buckets
+            .into_iter()
+            .rev()
+            .map(|(bucket_size, bucket_index)| {
+                (bucket_index, bucket_size - target_average_bucket_size)
+            })
+            .collect(),
+    )
+}
+
+impl<C, S, E> Associative<C, S, E> {
+    /// Push elements in the locked `buckets` of this container.
+    ///
+    /// See [`push()`](trait.BuildingBlock.html#tymethod.push).
+    pub(super) fn push_buckets<K, V>(
+        &self,
+        buckets: &mut [C],
+        elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)>
+    where
+        K: Clone,
+        C: BuildingBlock<K, V>,
+        S: BucketSelector<K>,
+        E: BucketExecution<K, V, C>,
+    {
+        let n = elements.len();
+        let n_sets = buckets.len();
+        let overflow = self.overflow && n_sets > 1;
+
+        // Take pushed keys out of their second bucket.
+        let mut out = if overflow {
+            let mut set_keys: Vec<Vec<K>> =
+                (0..n_sets).map(|_| Vec::new()).collect();
+            for (k, _) in elements.iter() {
+                set_keys[self.overflow_set(k, n_sets).unwrap()]
+                    .push(k.clone());
+            }
+            self.execution.take_multiple(buckets, &mut set_keys)
+        } else {
+            Vec::new()
+        };
+
+        let mut set_elements: Vec<Vec<(K, V)>> =
+            Vec::with_capacity(n_sets);
+        for _ in 0..n_sets {
+            set_elements.push(Vec::with_capacity(n));
+        }
+        for e in elements.into_iter() {
+            set_elements[self.set(&e.0, n_sets)].push(e);
+        }
+
+        let victims = self.execution.push(buckets, set_elements);
+        if !overflow {
+            return victims.into_iter().flatten().collect();
+        }
+
+        // Offer the victims evicted out of their first bucket to their
+        // second bucket.
+        let mut set_elements: Vec<Vec<(K, V)>> =
+            (0..n_sets).map(|_| Vec::new()).collect();
+        for (i, bucket_victims) in victims.into_iter().enumerate() {
+            for e in bucket_victims.into_iter() {
+                if self.set(&e.0, n_sets) == i
+                    && !buckets[i].contains(&e.0)
+                {
+                    set_elements[self.overflow_set(&e.0, n_sets).unwrap()]
+                        .push(e);
+                } else {
+                    out.push(e);
+                }
+            }
+        }
+        out.extend(
+            self.execution
+                .push(buckets, set_elements)
+                .into_iter()
+                .flatten(),
+        );
+        out
+    }
+}
+
+impl<K, V, C, S, E> BuildingBlock<K, V> for Associative<C, S, E>
 where
-    K: Clone + Hash,
+    K: Clone,
     V: Ord,
     C: BuildingBlock<K, V>,
-    H: Hasher + Clone,
+    S: BucketSelector<K>,
+    E: BucketExecution<K, V, C>,
 {
     /// Get the maximum storage size of this [`BuildingBlock`].
     ///
@@ -18,28 +198,47 @@ where
     /// `size` close to its `capacity` if their respective buckets/sets are
     /// full.
     fn capacity(&self) -> usize {
-        self.containers.iter().map(|c| c.capacity()).sum()
+        self.buckets().iter().map(|c| c.capacity()).sum()
     }
 
-    type FlushIterator =
-        std::iter::Flatten<std::vec::IntoIter<C::FlushIterator>>;
+    type FlushIterator = E::FlushIterator;
+
+    /// Flush all the buckets of this container.
+    ///
+    /// Buckets are flushed according to this container execution strategy.
+    /// Elements come out in buckets order.
     fn flush(&mut self) -> Self::FlushIterator {
-        self.containers
-            .iter_mut()
-            .map(|c| c.flush())
-            .collect::<Vec<C::FlushIterator>>()
-            .into_iter()
-            .flatten()
+        self.with_buckets(|buckets| self.execution.flush(buckets))
     }
 
     /// Check if container contains a matching key.
     ///
     /// The key is first hashed to find out which bucket may contain the key.
     /// Then the method returns whether the matching container actually contains
-    /// the key.
+    /// the key. In overflow mode, the second bucket of the key is also
+    /// checked.
     fn contains(&self, key: &K) -> bool {
-        let i = self.set(key.clone());
-        self.containers[i].contains(key)
+        let buckets = self.buckets();
+        let i = self.set(key, buckets.len());
+        buckets[i].contains(key)
+            || match self.overflow_set(key, buckets.len()) {
+                Some(j) => buckets[j].contains(key),
+                None => false,
+            }
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        let buckets = self.buckets();
+        let i = self.set(key, buckets.len());
+        buckets[i].contains_if(key, predicate)
+            || match self.overflow_set(key, buckets.len()) {
+                Some(j) => buckets[j].contains_if(key, predicate),
+                None => false,
+            }
     }
 
     /// Get the size currently occupied by elements in this [`BuildingBlock`].
@@ -51,7 +250,7 @@ where
     /// `size` close to its `capacity` if their respective buckets/sets are
     /// full.
     fn size(&self) -> usize {
-        self.containers.iter().map(|c| c.size()).sum()
+        self.buckets().iter().map(|c| c.size()).sum()
     }
 
     /// Take the matching key/value pair out of the container.
@@ -59,47 +258,71 @@ where
     /// The key is first hashed to find out which bucket may contain the key.
     /// Then the method returns the result of
     /// [`take()`](trait.BuildingBlock.html#method.take) method on the
-    /// matching container.
+    /// matching container. In overflow mode, the key is taken out of its
+    /// second bucket if it is not found in the first one.
     fn take(&mut self, key: &K) -> Option<(K, V)> {
-        let i = self.set(key.clone());
-        self.containers[i].take(key)
+        self.with_buckets(|buckets| {
+            let i = self.set(key, buckets.len());
+            match buckets[i].take(key) {
+                None => match self.overflow_set(key, buckets.len()) {
+                    Some(j) => buckets[j].take(key),
+                    None => None,
+                },
+                found => found,
+            }
+        })
     }
 
     /// Take multiple keys out of a container at once.
     ///
     /// This method will first hash all the keys and sort them by index of their
     /// matching bucket.
-    /// Then, for each bucket in sequential order, the result of
+    /// Then, for each bucket, the result of
     /// [`take_multiple()`](trait.BuildingBlock.html#method.take_multiple)
-    /// with the matching bucket keys is returned.
+    /// with the matching bucket keys is returned. Buckets are processed
+    /// according to this container execution strategy. In overflow mode,
+    /// the keys that were not found are then looked up in their second
+    /// bucket the same way.
     fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
-        let mut ret = Vec::with_capacity(keys.len());
+        self.with_buckets(|buckets| {
+            let n_sets = buckets.len();
 
-        // Rearrange keys per set.
-        let mut set_keys: Vec<Vec<K>> =
-            Vec::with_capacity(self.containers.len());
-        for _ in 0..self.containers.len() {
-            set_keys.push(Vec::with_capacity(keys.len()));
-        }
-        for k in keys.drain(0..keys.len()) {
-            set_keys[self.set(k.clone())].push(k);
-        }
+            // Rearrange keys per set.
+            let mut set_keys: Vec<Vec<K>> = Vec::with_capacity(n_sets);
+            for _ in 0..n_sets {
+                set_keys.push(Vec::with_capacity(keys.len()));
+            }
+            for k in keys.drain(0..keys.len()) {
+                set_keys[self.set(&k, n_sets)].push(k);
+            }
 
-        // Take from each bucket.
-        for (c, keys) in
-            self.containers.iter_mut().zip(set_keys.iter_mut())
-        {
-            if !keys.is_empty() {
-                ret.append(&mut c.take_multiple(keys));
+            // Take from each bucket.
+            let mut ret =
+                self.execution.take_multiple(buckets, &mut set_keys);
+
+            // Take the keys not found from their second bucket.
+            if self.overflow && n_sets > 1 {
+                let mut overflow_keys: Vec<Vec<K>> =
+                    (0..n_sets).map(|_| Vec::new()).collect();
+                for k in set_keys.iter_mut().flat_map(|sk| sk.drain(..)) {
+                    overflow_keys[self.overflow_set(&k, n_sets).unwrap()]
+                        .push(k);
+                }
+                ret.append(
+                    &mut self
+                        .execution
+                        .take_multiple(buckets, &mut overflow_keys),
+                );
+                set_keys = overflow_keys;
             }
-        }
 
-        // Put the remaining keys back in the input keys.
-        for mut sk in set_keys.into_iter() {
-            keys.append(&mut sk);
-        }
+            // Put the remaining keys back in the input keys.
+            for mut sk in set_keys.into_iter() {
+                keys.append(&mut sk);
+            }
 
-        ret
+            ret
+        })
     }
 
     /// Free up to `size` space from the container.
@@ -121,107 +344,19 @@ where
     /// attempt to pop the requested size elements. However, the method can't
     /// guarantee to achieve optimal bucket balancing.
     fn pop(&mut self, size: usize) -> Vec<(K, V)> {
-        let mut victims = Vec::<(K, V)>::new();
-
-        // Collect all buckets sizes and indexes.
-        // In this variable we keep the buckets we intend to pop from and
-        // their size.
-        let mut popped_buckets: Vec<(usize, usize)> = self
-            .containers
-            .iter()
-            .map(|c| c.size())
-            .enumerate()
-            .collect();
-
-        // The total size of popped buckets.
-        let mut popped_buckets_size =
-            popped_buckets.iter().map(|(_, s)| s).sum();
-
-        // Easy path, we don't need to go further if there is nothing to return.
-        if size == 0 || popped_buckets_size == 0 {
-            return victims;
-        }
-
-        // Easy path, we don't need to go further we need to return everything.
-        if size >= popped_buckets_size {
-            return self.flush().collect();
-        }
-
-        // Generic pop(size) scenario:
-        //
-        //  1   3   2   0  -- sorted buckets
-        //             +-+
-        //         +-+ | |
-        //     +-+ | | | |
-        // ------------------ average bucket size
-        // ------------------ target average bucket size after pop.
-        //     | | | | | |
-        // +-+ | | | | | |
-        // | | | | | | | |
-        // We cannot pop from bucket `1` without increasing imbalance.
-        // Instead we will need to pop below the
-        // target average bucket size after pop` in other buckets.
-        // Consequently, we need to recompute our goal of:
-        // `target average bucket size after pop` without the buckets below
-        // that value. We can process iteratively removing the smallest
-        // bucket with a size below the target value at every step.
-
-        // First, sort ! in reverse order to have the small buckets last.
-        popped_buckets.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
-
-        // Then loop through the buckets starting from the smallest (tail) and:
-        let target_average_bucket_size = loop {
-            // Compute the goal for buckets size:
-            let target_average_bucket_size =
-                (popped_buckets_size - size) / (popped_buckets.len() + 1);
-
-            // Look if the smaller bucket size is greater than the goal.
-            let (bucket_size, bucket_index) = popped_buckets
-                .pop()
-                .expect("Associative container pop() error.");
-
-            // If it is greater, then we can stop and go to the next step.
-            if bucket_size >= target_average_bucket_size {
-                popped_buckets.push((bucket_size, bucket_index));
-                break target_average_bucket_size;
+        self.with_buckets(|buckets| {
+            let sizes = buckets.iter().map(|c| c.size()).collect();
+            match pop_plan(sizes, size) {
+                None => self.execution.flush(buckets).collect(),
+                Some(plan) => {
+                    let mut sizes = vec![0; buckets.len()];
+                    for (bucket_index, pop_size) in plan.into_iter() {
+                        sizes[bucket_index] = pop_size;
+                    }
+                    self.execution.pop(buckets, sizes)
+                }
             }
-
-            // Else, we loop on what's left of the buckets.
-            // The new `popped_buckets_size` of remaining bucket is the current
-            // one minus the discarded bucket.
-            popped_buckets_size -= bucket_size;
-
-            // Since bucket_size < target_average_bucket_size,
-            // there must be more than requested pop size in other buckets.
-            // If this is not true, the next iteration of the loop will panic
-            // on computing `popped_buckets_size - size`.
-            assert!(
-                popped_buckets_size > size,
-                "Associative container pop() error."
-            );
-        };
-
-        // Now all the buckets in `popped_buckets` vector have a size that is
-        // greater than the `target_average_bucket_size`. We just have to pop()
-        // the difference between their size and the target size from them.
-        //
-        //  3   2   0  -- remaining sorted buckets.
-        //         +-+     +
-        //     +-+ | |     |
-        // +-+ | | | |     | size to pop      +
-        // | | | | | |     | in last bucket.  | size to pop
-        // | | | | | |     |                  | in first bucket.
-        // | | | | | |     +                  +
-        // -------------- average bucket size after pop
-        // | | | | | |
-        for (bucket_size, bucket_index) in popped_buckets.into_iter().rev()
-        {
-            let bucket = &mut self.containers[bucket_index];
-            let pop_size = bucket_size - target_average_bucket_size;
-            victims.append(&mut bucket.pop(pop_size));
-        }
-
-        victims
+        })
     }
 
     /// Insert key/value pairs in the container.
@@ -231,27 +366,21 @@ where
     /// the bucket [`push()`](trait.BuildingBlock.html#method.push) invoked
     /// with the associated keys and values to insert. It is up to this
     /// container to choose what will be inserted and what may be evicted.
+    /// Buckets are processed according to this container execution
+    /// strategy.
     ///
     /// Note that this container may refuse new elements before being having
     /// its `size` close to its `capacity` if for instance most key are assigned
     /// to a full bucket while other buckets still have room.
+    ///
+    /// In overflow mode, the pushed keys are first taken out of their
+    /// second bucket and returned, such that a key lives in a single bucket.
+    /// Then, the elements evicted out of their first bucket, i.e the
+    /// returned elements whose key is not in the bucket anymore, are pushed
+    /// in their second bucket the same way. Elements evicted out of their
+    /// second bucket and values replaced in the first bucket are returned.
     fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
-        let n = elements.len();
-        let n_sets = self.containers.len();
-        let mut set_elements: Vec<Vec<(K, V)>> =
-            Vec::with_capacity(n_sets);
-        for _ in 0..n_sets {
-            set_elements.push(Vec::with_capacity(n));
-        }
-        for e in elements.into_iter() {
-            set_elements[self.set(e.0.clone())].push(e);
-        }
-
-        let mut out = Vec::with_capacity(n);
-        for (i, v) in set_elements.into_iter().enumerate() {
-            out.append(&mut (self.containers[i].push(v)));
-        }
-        out
+        self.with_buckets(|buckets| self.push_buckets(buckets, elements))
     }
 }
 
@@ -259,7 +388,8 @@ where
 mod tests {
     use super::Associative;
     use crate::tests::test_building_block;
-    use crate::Array;
+    use crate::utils::associative::KeyRange;
+    use crate::{Array, BTree, BuildingBlock, Sequential};
     use std::collections::hash_map::DefaultHasher;
 
     #[test]
@@ -272,4 +402,105 @@ mod tests {
             true,
         );
     }
+
+    #[test]
+    fn parallel_building_block() {
+        test_building_block(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            )
+            .into_parallel(4),
+            true,
+        );
+        test_building_block(
+            Associative::new(
+                (0..10).map(|_| Sequential::new(Array::new(5))).collect(),
+                DefaultHasher::new(),
+            )
+            .into_parallel(16),
+            true,
+        );
+    }
+
+    #[test]
+    fn overflow_building_block() {
+        test_building_block(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            )
+            .with_overflow(),
+            true,
+        );
+        test_building_block(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            )
+            .with_overflow()
+            .into_parallel(4),
+            true,
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        // All keys select the first bucket and overflow in the second one.
+        let mut c = Associative::new(
+            vec![Array::new(5); 4],
+            KeyRange::new(vec![100u16, 200, 300]),
+        )
+        .with_overflow();
+        assert!(c
+            .push((0..10u16).map(|i| (i, i as u32)).collect())
+            .is_empty());
+        assert_eq!(c.buckets()[0].size(), 5);
+        assert_eq!(c.buckets()[1].size(), 5);
+        assert!((0..10u16).all(|k| c.contains(&k)));
+
+        // Both candidate buckets are full.
+        assert_eq!(c.push(vec![(10, 10)]).len(), 1);
+        assert_eq!(c.size(), 10);
+        assert_eq!((0..11u16).filter(|k| c.contains(k)).count(), 10);
+
+        // Keys are found in both candidate buckets.
+        let k = (0..11u16).find(|k| c.buckets()[1].contains(k)).unwrap();
+        assert_eq!(c.take(&k), Some((k, k as u32)));
+        let mut keys: Vec<u16> = (0..11).collect();
+        assert_eq!(c.take_multiple(&mut keys).len(), 9);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(c.size(), 0);
+    }
+
+    #[test]
+    fn overflow_update() {
+        let mut c = Associative::new(
+            (0..4).map(|_| BTree::new(5)).collect(),
+            KeyRange::new(vec![100u16, 200, 300]),
+        )
+        .with_overflow();
+        assert!(c
+            .push((0..3u16).map(|i| (i, i as u32)).collect())
+            .is_empty());
+
+        // Updating a key returns the replaced value.
+        assert_eq!(c.push(vec![(0, 1000)]), vec![(0, 0)]);
+        assert_eq!(c.size(), 3);
+        assert_eq!(c.take(&0), Some((0, 1000)));
+        assert!(!c.contains(&0));
+        assert_eq!(c.take(&0), None);
+
+        // Updating a key living in its second bucket moves it back to its
+        // first bucket.
+        assert!(c
+            .push((3..10u16).map(|i| (i, i as u32)).collect())
+            .is_empty());
+        assert_eq!(c.buckets()[1].size(), 4);
+        let k = (0..10u16).find(|k| c.buckets()[1].contains(k)).unwrap();
+        let out = c.push(vec![(k, 1000)]);
+        assert!(out.contains(&(k, k as u32)));
+        assert_eq!(c.take(&k), Some((k, 1000)));
+        assert!(!c.contains(&k));
+    }
 }
@@ -1,20 +1,19 @@
 use super::Associative;
 use crate::Concurrent;
-use std::hash::Hasher;
 
-unsafe impl<C: Send, H: Hasher + Clone> Send for Associative<C, H> {}
-
-unsafe impl<C: Sync, H: Hasher + Clone> Sync for Associative<C, H> {}
-
-impl<C: Concurrent, H: Hasher + Clone> Concurrent for Associative<C, H> {
+impl<C, S, E> Concurrent for Associative<C, S, E>
+where
+    C: Concurrent,
+    S: Clone + Send + Sync,
+    E: Clone + Send + Sync,
+{
     fn clone(&self) -> Self {
+        self.containers.clone_bucket.get_or_init(|| C::clone);
         Associative {
-            containers: self
-                .containers
-                .iter()
-                .map(|c| Concurrent::clone(c))
-                .collect(),
-            hasher: self.hasher.clone(),
+            containers: self.containers.clone(),
+            selector: self.selector.clone(),
+            execution: self.execution.clone(),
+            overflow: self.overflow,
         }
     }
 }
@@ -36,4 +35,16 @@ mod tests {
             64,
         );
     }
+
+    #[test]
+    fn parallel_concurrent() {
+        test_concurrent(
+            Associative::new(
+                vec![Sequential::new(Array::new(30)); 30],
+                DefaultHasher::new(),
+            )
+            .into_parallel(4),
+            64,
+        );
+    }
 }
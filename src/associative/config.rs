@@ -1,3 +1,6 @@
+use crate::associative::{
+    BucketSelector, JumpHash, KeyRange, Parallel, Rendezvous, Serial,
+};
 use crate::builder::AssociativeBuilder;
 use crate::config::{
     ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
@@ -40,31 +43,243 @@ use std::hash::Hasher;
 ///                .unwrap()
 ///                .build();
 /// ```
+///
+/// An optional `threads` field can be set to process buckets in parallel
+/// on up to `threads` threads. See
+/// [`Parallel`](../../utils/associative/struct.Parallel.html) for details.
+/// In this mode, all the buckets must implement the
+/// [`Concurrent`](../../trait.Concurrent.html) trait, for instance by
+/// wrapping them in a [`Sequential`](../../struct.Sequential.html)
+/// container.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='AssociativeConfig'
+/// threads=2
+/// [[container]]
+/// id='SequentialConfig'
+/// [container.container]
+/// id='ArrayConfig'
+/// capacity=10
+/// [[container]]
+/// id='SequentialConfig'
+/// [container.container]
+/// id='ArrayConfig'
+/// capacity=10
+/// ");
+/// let mut container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// assert!(container.push(vec![(1, 2), (3, 4)]).is_empty());
+/// ```
+///
+/// An optional `selector` field chooses how keys are associated with
+/// buckets. See
+/// [`BucketSelector`](../../utils/associative/trait.BucketSelector.html).
+/// Possible values are "Modulo" (the default, i.e the key hash modulo
+/// the number of buckets), "JumpHash", "Rendezvous" and "KeyRange". The
+/// latter requires a `bounds` field with the sorted bounds between the
+/// key ranges of consecutive buckets, i.e one bound less than the number
+/// of buckets.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='AssociativeConfig'
+/// selector='KeyRange'
+/// bounds=[10]
+/// [[container]]
+/// id='ArrayConfig'
+/// capacity=2
+/// [[container]]
+/// id='ArrayConfig'
+/// capacity=2
+/// ");
+/// let mut container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// assert!(container.push(vec![(1, 1), (2, 2)]).is_empty());
+/// assert_eq!(container.push(vec![(3, 3)]).len(), 1);
+/// ```
+///
+/// An optional `overflow` field can be set to `true` to offer the elements
+/// evicted out of a full bucket to a second bucket before returning them.
+/// See [`Associative`](../../struct.Associative.html) overflow mode.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='AssociativeConfig'
+/// selector='KeyRange'
+/// bounds=[10]
+/// overflow=true
+/// [[container]]
+/// id='ArrayConfig'
+/// capacity=2
+/// [[container]]
+/// id='ArrayConfig'
+/// capacity=2
+/// ");
+/// let mut container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// assert!(container.push(vec![(1, 1), (2, 2)]).is_empty());
+/// assert!(container.push(vec![(3, 3)]).is_empty());
+/// ```
 #[derive(Deserialize, Serialize, Clone)]
 pub struct AssociativeConfig {
     #[allow(dead_code)]
     id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    threads: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selector: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bounds: Option<toml::value::Array>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overflow: Option<bool>,
     container: toml::value::Array,
 }
 
-impl<C, H, B> IntoConfig<AssociativeConfig> for AssociativeBuilder<C, H, B>
-where
-    H: Hasher + Clone,
-    B: IntoConfig<C>,
-    C: ConfigInstance,
-{
-    fn as_config(&self) -> AssociativeConfig {
-        let container_config = self.builder.as_config();
+impl AssociativeConfig {
+    fn from_builder<C, H, B, E>(
+        builder: &AssociativeBuilder<C, H, B, E>,
+        threads: Option<usize>,
+    ) -> Self
+    where
+        H: Hasher + Clone,
+        B: IntoConfig<C>,
+        C: ConfigInstance,
+    {
+        let container_config = builder.builder.as_config();
         let container_config_str = container_config.to_toml_string();
         let container_config_toml: toml::value::Value =
             toml::de::from_str(container_config_str.as_ref()).unwrap();
-        let container = vec![container_config_toml; self.num_sets];
+        let container = vec![container_config_toml; builder.num_sets];
 
         AssociativeConfig {
             id: String::from(AssociativeConfig::id()),
+            threads,
+            selector: None,
+            bounds: None,
+            overflow: builder.overflow.then_some(true),
             container,
         }
     }
+
+    fn build_with<'a, K, V, S>(
+        containers: Vec<DynBuildingBlock<'a, K, V>>,
+        selector: S,
+        threads: Option<usize>,
+        overflow: bool,
+        is_concurrent: bool,
+        is_resizable: bool,
+    ) -> DynBuildingBlock<'a, K, V>
+    where
+        K: 'a + GenericKey,
+        V: 'a + GenericValue,
+        S: 'a + BucketSelector<K>,
+    {
+        match (DynBuildingBlock::into_get_all(containers), threads) {
+            (Ok(containers), None) => {
+                let mut c = Associative::new(containers, selector);
+                c.overflow = overflow;
+                DynBuildingBlock::new_resizable_get(
+                    c,
+                    is_concurrent,
+                    is_resizable,
+                )
+            }
+            (Err(containers), None) => {
+                let mut c = Associative::new(containers, selector);
+                c.overflow = overflow;
+                DynBuildingBlock::new_resizable(
+                    c,
+                    is_concurrent,
+                    is_resizable,
+                )
+            }
+            // Containers were checked to be concurrent in `from_toml()`.
+            (Ok(containers), Some(threads)) => {
+                let mut c = Associative::new(
+                    containers
+                        .into_iter()
+                        .map(|c| {
+                            c.into_concurrent().unwrap_or_else(|_| {
+                                panic!(
+                                    "Non concurrent Associative bucket."
+                                )
+                            })
+                        })
+                        .collect(),
+                    selector,
+                )
+                .with_execution(Parallel::new(threads));
+                c.overflow = overflow;
+                DynBuildingBlock::new_resizable_get(
+                    c,
+                    is_concurrent,
+                    is_resizable,
+                )
+            }
+            (Err(containers), Some(threads)) => {
+                let mut c = Associative::new(
+                    containers
+                        .into_iter()
+                        .map(|c| {
+                            c.into_concurrent().unwrap_or_else(|_| {
+                                panic!(
+                                    "Non concurrent Associative bucket."
+                                )
+                            })
+                        })
+                        .collect(),
+                    selector,
+                )
+                .with_execution(Parallel::new(threads));
+                c.overflow = overflow;
+                DynBuildingBlock::new_resizable(
+                    c,
+                    is_concurrent,
+                    is_resizable,
+                )
+            }
+        }
+    }
+}
+
+impl<C, H, B> IntoConfig<AssociativeConfig>
+    for AssociativeBuilder<C, H, B, Serial>
+where
+    H: Hasher + Clone,
+    B: IntoConfig<C>,
+    C: ConfigInstance,
+{
+    fn as_config(&self) -> AssociativeConfig {
+        AssociativeConfig::from_builder(self, None)
+    }
+}
+
+impl<C, H, B> IntoConfig<AssociativeConfig>
+    for AssociativeBuilder<C, H, B, Parallel>
+where
+    H: Hasher + Clone,
+    B: IntoConfig<C>,
+    C: ConfigInstance,
+{
+    fn as_config(&self) -> AssociativeConfig {
+        AssociativeConfig::from_builder(
+            self,
+            Some(self.execution.threads()),
+        )
+    }
 }
 
 impl ConfigInstance for AssociativeConfig {
@@ -84,25 +299,107 @@ impl ConfigInstance for AssociativeConfig {
                 Err(e) => return Err(e),
             }
         }
-        Ok(cfg)
+        let valid_selector = match cfg.selector.as_deref() {
+            None | Some("Modulo") | Some("JumpHash")
+            | Some("Rendezvous") => cfg.bounds.is_none(),
+            Some("KeyRange") => matches!(
+                &cfg.bounds,
+                Some(b) if b.len() + 1 == cfg.container.len()
+            ),
+            Some(_) => false,
+        };
+        if !valid_selector {
+            return Err(ConfigError::ConfigFormatError(format!(
+                "Invalid AssociativeConfig selector {:?}. KeyRange selector requires one bound less than containers.",
+                cfg.selector
+            )));
+        }
+        match cfg.threads {
+            None => Ok(cfg),
+            Some(0) => Err(ConfigError::ConfigFormatError(String::from(
+                "AssociativeConfig threads must be greater than 0.",
+            ))),
+            Some(_) if !cfg.is_concurrent() => {
+                Err(ConfigError::ConfigFormatError(String::from(
+                    "AssociativeConfig with threads requires Concurrent containers.",
+                )))
+            }
+            Some(_) => Ok(cfg),
+        }
     }
 
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
         let is_concurrent = self.is_concurrent();
-        DynBuildingBlock::new(
-            Associative::new(
-                self.container
+        let is_resizable = self.is_resizable();
+        let threads = self.threads;
+        let overflow = self.overflow.unwrap_or(false);
+        let containers = self
+            .container
+            .into_iter()
+            .map(|cfg| GenericConfig::from_toml(&cfg).unwrap().build())
+            .collect();
+
+        match self.selector.as_deref() {
+            Some("JumpHash") => Self::build_with(
+                containers,
+                JumpHash::new(DefaultHasher::new()),
+                threads,
+                overflow,
+                is_concurrent,
+                is_resizable,
+            ),
+            Some("Rendezvous") => Self::build_with(
+                containers,
+                Rendezvous::new(DefaultHasher::new()),
+                threads,
+                overflow,
+                is_concurrent,
+                is_resizable,
+            ),
+            Some("KeyRange") => {
+                let bounds = self
+                    .bounds
+                    .unwrap()
                     .into_iter()
-                    .map(|cfg| {
-                        GenericConfig::from_toml(&cfg).unwrap().build()
+                    .map(|b| {
+                        b.try_into::<K>()
+                            .expect("Invalid AssociativeConfig bound.")
                     })
-                    .collect(),
+                    .collect();
+                Self::build_with(
+                    containers,
+                    KeyRange::new(bounds),
+                    threads,
+                    overflow,
+                    is_concurrent,
+                    is_resizable,
+                )
+            }
+            _ => Self::build_with(
+                containers,
                 DefaultHasher::new(),
+                threads,
+                overflow,
+                is_concurrent,
+                is_resizable,
             ),
-            is_concurrent,
-        )
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        self.container
+            .iter()
+            .map(|cfg| GenericConfig::from_toml(cfg).unwrap())
+            .all(|cfg| cfg.has_get_trait())
+    }
+
+    fn is_resizable(&self) -> bool {
+        self.container
+            .iter()
+            .map(|cfg| GenericConfig::from_toml(cfg).unwrap())
+            .all(|cfg| cfg.is_resizable())
     }
 
     fn is_concurrent(&self) -> bool {
@@ -116,11 +413,13 @@ impl ConfigInstance for AssociativeConfig {
 #[cfg(test)]
 mod tests {
     use super::AssociativeConfig;
-    use crate::builder::{ArrayBuilder, AssociativeBuilder};
+    use crate::builder::{
+        ArrayBuilder, AssociativeBuilder, SequentialBuilder,
+    };
     use crate::config::tests::test_config_builder;
-    use crate::config::{ConfigError, ConfigInstance};
+    use crate::config::{ConfigError, ConfigInstance, IntoConfig};
     use crate::objsafe::DynBuildingBlock;
-    use crate::BuildingBlock;
+    use crate::{BuildingBlock, Get};
     use std::collections::hash_map::DefaultHasher;
 
     #[test]
@@ -176,4 +475,166 @@ capacity={}
         );
         test_config_builder(builder);
     }
+
+    #[test]
+    fn test_parallel_associative_config() {
+        let config_str = "id='AssociativeConfig'
+threads=2
+[[container]]
+id='SequentialConfig'
+[container.container]
+id='ArrayConfig'
+capacity=10
+[[container]]
+id='SequentialConfig'
+[container.container]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = AssociativeConfig::from_toml(&value).unwrap();
+        assert_eq!(config.threads, Some(2));
+        assert!(config.is_concurrent());
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        let mut container = container.into_get().ok().unwrap();
+        assert_eq!(container.capacity(), 20);
+        assert!(container.push(vec![(1, 2), (3, 4)]).is_empty());
+        assert_eq!(*container.get(&1).unwrap(), 2);
+        assert_eq!(container.flush().count(), 2);
+        assert!(container.into_concurrent().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_parallel_associative_config() {
+        let config_str = "id='AssociativeConfig'
+threads=2
+[[container]]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        assert!(matches!(
+            AssociativeConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parallel_builder_as_config() {
+        let builder = AssociativeBuilder::new(
+            SequentialBuilder::new(ArrayBuilder::<()>::new(2)),
+            DefaultHasher::new(),
+            2,
+        )
+        .into_parallel(2);
+        test_config_builder(builder);
+    }
+
+    #[test]
+    fn test_selector_associative_config() {
+        for selector in ["Modulo", "JumpHash", "Rendezvous"] {
+            let config_str = format!(
+                "id='AssociativeConfig'
+selector='{}'
+[[container]]
+id='ArrayConfig'
+capacity=10
+[[container]]
+id='ArrayConfig'
+capacity=10
+",
+                selector
+            );
+            let value: toml::Value =
+                toml::from_str(config_str.as_str()).unwrap();
+            let config = AssociativeConfig::from_toml(&value).unwrap();
+            let mut container: DynBuildingBlock<u64, u64> = config.build();
+            assert!(container.push(vec![(1, 2), (3, 4)]).is_empty());
+            assert!(container.contains(&1));
+        }
+
+        let config_str = "id='AssociativeConfig'
+selector='KeyRange'
+bounds=[10]
+[[container]]
+id='ArrayConfig'
+capacity=2
+[[container]]
+id='ArrayConfig'
+capacity=2
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = AssociativeConfig::from_toml(&value).unwrap();
+        let mut container: DynBuildingBlock<u64, u64> = config.build();
+        assert!(container
+            .push(vec![(1, 1), (10, 10), (11, 11)])
+            .is_empty());
+        // The second bucket is full and evicts one of its keys.
+        let out = container.push(vec![(12, 12)]);
+        assert_eq!(out.len(), 1);
+        assert!(out[0].0 >= 10);
+        assert!(container.contains(&1));
+    }
+
+    #[test]
+    fn test_invalid_selector_associative_config() {
+        for selector in
+            ["selector='KeyRange'", "selector='Unknown'", "bounds=[10]"]
+        {
+            let config_str = format!(
+                "id='AssociativeConfig'
+{}
+[[container]]
+id='ArrayConfig'
+capacity=10
+",
+                selector
+            );
+            let value: toml::Value =
+                toml::from_str(config_str.as_str()).unwrap();
+            assert!(matches!(
+                AssociativeConfig::from_toml(&value),
+                Err(ConfigError::ConfigFormatError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_overflow_associative_config() {
+        let config_str = "id='AssociativeConfig'
+selector='KeyRange'
+bounds=[10]
+overflow=true
+[[container]]
+id='ArrayConfig'
+capacity=2
+[[container]]
+id='ArrayConfig'
+capacity=2
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = AssociativeConfig::from_toml(&value).unwrap();
+        assert_eq!(config.overflow, Some(true));
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        let mut container = container.into_get().ok().unwrap();
+        // The first bucket overflows in the second bucket.
+        assert!(container
+            .push(vec![(1, 1), (2, 2), (3, 3), (4, 4)])
+            .is_empty());
+        assert_eq!(container.push(vec![(5, 5)]).len(), 1);
+        assert_eq!((1..6).filter(|k| container.contains(k)).count(), 4);
+        assert_eq!(*container.get(&1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_overflow_builder_as_config() {
+        let builder = AssociativeBuilder::new(
+            ArrayBuilder::<()>::new(2),
+            DefaultHasher::new(),
+            2,
+        )
+        .with_overflow();
+        assert_eq!(builder.as_config().overflow, Some(true));
+        test_config_builder(builder);
+    }
 }
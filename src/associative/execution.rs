@@ -0,0 +1,374 @@
+use crate::BuildingBlock;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+/// Strategy dispatching the per-bucket work of an
+/// [`Associative`](../../struct.Associative.html) container.
+///
+/// [`Associative`](../../struct.Associative.html) container methods
+/// operating on several buckets at once, i.e
+/// [`take_multiple()`](../../trait.BuildingBlock.html#tymethod.take_multiple),
+/// [`pop()`](../../trait.BuildingBlock.html#tymethod.pop),
+/// [`push()`](../../trait.BuildingBlock.html#tymethod.push) and
+/// [`flush()`](../../trait.BuildingBlock.html#tymethod.flush),
+/// first split their input per bucket and then hand the buckets work to
+/// this trait. Outputs are always concatenated in buckets order, except
+/// for [`push()`](trait.BucketExecution.html#tymethod.push) that returns
+/// the elements evicted out of each bucket separately.
+///
+/// This trait is implemented by [`Serial`] that processes buckets one
+/// after the other, and by [`Parallel`] that processes buckets on several
+/// threads.
+pub trait BucketExecution<K, V, C> {
+    type FlushIterator: Iterator<Item = (K, V)>;
+
+    /// Take the keys in `keys[i]` out of `buckets[i]`. Keys that are not
+    /// found are left in their vector.
+    fn take_multiple(
+        &self,
+        buckets: &mut [C],
+        keys: &mut [Vec<K>],
+    ) -> Vec<(K, V)>;
+
+    /// Pop `sizes[i]` out of `buckets[i]`.
+    fn pop(&self, buckets: &mut [C], sizes: Vec<usize>) -> Vec<(K, V)>;
+
+    /// Push `elements[i]` in `buckets[i]` and return the elements evicted
+    /// out of `buckets[i]` at index `i`.
+    fn push(
+        &self,
+        buckets: &mut [C],
+        elements: Vec<Vec<(K, V)>>,
+    ) -> Vec<Vec<(K, V)>>;
+
+    /// Flush all the buckets.
+    fn flush(&self, buckets: &mut [C]) -> Self::FlushIterator;
+}
+
+/// Process [`Associative`](../../struct.Associative.html) buckets one after
+/// the other in the calling thread.
+///
+/// This is the default execution strategy of
+/// [`Associative`](../../struct.Associative.html) containers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Serial;
+
+impl<K, V, C> BucketExecution<K, V, C> for Serial
+where
+    C: BuildingBlock<K, V>,
+{
+    type FlushIterator =
+        std::iter::Flatten<std::vec::IntoIter<C::FlushIterator>>;
+
+    fn take_multiple(
+        &self,
+        buckets: &mut [C],
+        keys: &mut [Vec<K>],
+    ) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for (c, keys) in buckets.iter_mut().zip(keys.iter_mut()) {
+            if !keys.is_empty() {
+                out.append(&mut c.take_multiple(keys));
+            }
+        }
+        out
+    }
+
+    fn pop(&self, buckets: &mut [C], sizes: Vec<usize>) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for (c, size) in buckets.iter_mut().zip(sizes) {
+            if size > 0 {
+                out.append(&mut c.pop(size));
+            }
+        }
+        out
+    }
+
+    fn push(
+        &self,
+        buckets: &mut [C],
+        elements: Vec<Vec<(K, V)>>,
+    ) -> Vec<Vec<(K, V)>> {
+        buckets
+            .iter_mut()
+            .zip(elements)
+            .map(|(c, elements)| {
+                if elements.is_empty() {
+                    Vec::new()
+                } else {
+                    c.push(elements)
+                }
+            })
+            .collect()
+    }
+
+    fn flush(&self, buckets: &mut [C]) -> Self::FlushIterator {
+        buckets
+            .iter_mut()
+            .map(|c| c.flush())
+            .collect::<Vec<C::FlushIterator>>()
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// Process [`Associative`](../../struct.Associative.html) buckets
+/// concurrently on scoped threads.
+///
+/// On each call dispatching work to buckets, the buckets are split into
+/// up to `threads` contiguous chunks. Chunks of buckets that have no work
+/// to do are skipped. One chunk is processed in the calling thread and
+/// the other chunks are processed by threads spawned for the duration of
+/// the call with [`std::thread::scope()`]. The calling thread waits for
+/// all the chunks to be processed before returning.
+///
+/// Buckets as well as keys and values must be [`Send`] to be processed
+/// in parallel. Since each bucket is accessed by a single thread at a time,
+/// buckets do not need to be [`Concurrent`](../../trait.Concurrent.html).
+///
+/// If a bucket panics while being processed, the panic is propagated to
+/// the calling thread once all the chunks are done.
+#[derive(Clone, Copy, Debug)]
+pub struct Parallel {
+    threads: usize,
+}
+
+impl Parallel {
+    /// Create a parallel execution strategy using up to `threads` threads,
+    /// including the calling thread.
+    ///
+    /// Panics if `threads` is `0`.
+    pub fn new(threads: usize) -> Self {
+        assert!(threads > 0, "Parallel execution requires one thread.");
+        Parallel { threads }
+    }
+
+    /// Maximum number of threads processing buckets at once.
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Apply `f` on each bucket with its matching input and return the
+    /// outputs in buckets order. Buckets with an empty input are not
+    /// processed and output the default value.
+    fn scatter<C, T, R, F>(
+        &self,
+        buckets: &mut [C],
+        inputs: Vec<T>,
+        is_empty: fn(&T) -> bool,
+        f: F,
+    ) -> Vec<R>
+    where
+        C: Send,
+        T: Send,
+        R: Send + Default,
+        F: Fn(&mut C, T) -> R + Sync,
+    {
+        let run = |chunk: &mut [C], work: Vec<T>| -> Vec<R> {
+            chunk
+                .iter_mut()
+                .zip(work)
+                .map(
+                    |(c, t)| {
+                        if is_empty(&t) {
+                            R::default()
+                        } else {
+                            f(c, t)
+                        }
+                    },
+                )
+                .collect()
+        };
+        let run = &run;
+
+        let n = buckets.len();
+        let chunk_size = n.div_ceil(self.threads).max(1);
+        let mut inputs = inputs.into_iter();
+        let mut chunks: Vec<Option<(&mut [C], Vec<T>)>> = Vec::new();
+        let mut out: Vec<Vec<R>> = Vec::new();
+        for chunk in buckets.chunks_mut(chunk_size) {
+            let work: Vec<T> = inputs.by_ref().take(chunk.len()).collect();
+            if work.iter().all(is_empty) {
+                out.push((0..chunk.len()).map(|_| R::default()).collect());
+                chunks.push(None);
+            } else {
+                out.push(Vec::new());
+                chunks.push(Some((chunk, work)));
+            }
+        }
+
+        let mut pending = chunks
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, chunk)| chunk.map(|chunk| (i, chunk)));
+        let local = pending.next();
+        let panic = std::thread::scope(|scope| {
+            // Spawn all the chunks but one on their own thread.
+            let handles: Vec<_> = pending
+                .map(|(i, (chunk, work))| {
+                    (i, scope.spawn(move || run(chunk, work)))
+                })
+                .collect();
+            let mut panic = None;
+            if let Some((i, (chunk, work))) = local {
+                match catch_unwind(AssertUnwindSafe(|| run(chunk, work))) {
+                    Ok(v) => out[i] = v,
+                    Err(e) => panic = Some(e),
+                }
+            }
+            // Handles are joined in buckets order such that the panic of
+            // the lowest chunk is the one propagated.
+            for (i, handle) in handles {
+                match handle.join() {
+                    Ok(v) => out[i] = v,
+                    Err(e) => {
+                        if panic.is_none() {
+                            panic = Some(e)
+                        }
+                    }
+                }
+            }
+            panic
+        });
+        if let Some(e) = panic {
+            resume_unwind(e);
+        }
+        out.into_iter().flatten().collect()
+    }
+}
+
+impl<K, V, C> BucketExecution<K, V, C> for Parallel
+where
+    K: Send,
+    V: Send,
+    C: BuildingBlock<K, V> + Send,
+{
+    type FlushIterator =
+        std::iter::Flatten<std::vec::IntoIter<Vec<(K, V)>>>;
+
+    fn take_multiple(
+        &self,
+        buckets: &mut [C],
+        keys: &mut [Vec<K>],
+    ) -> Vec<(K, V)> {
+        self.scatter(
+            buckets,
+            keys.iter_mut().collect(),
+            |keys| keys.is_empty(),
+            |c, keys| c.take_multiple(keys),
+        )
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn pop(&self, buckets: &mut [C], sizes: Vec<usize>) -> Vec<(K, V)> {
+        self.scatter(
+            buckets,
+            sizes,
+            |size| *size == 0,
+            |c, size| c.pop(size),
+        )
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn push(
+        &self,
+        buckets: &mut [C],
+        elements: Vec<Vec<(K, V)>>,
+    ) -> Vec<Vec<(K, V)>> {
+        self.scatter(
+            buckets,
+            elements,
+            |elements| elements.is_empty(),
+            |c, elements| c.push(elements),
+        )
+    }
+
+    fn flush(&self, buckets: &mut [C]) -> Self::FlushIterator {
+        let n = buckets.len();
+        self.scatter(
+            buckets,
+            vec![(); n],
+            |_| false,
+            |c, _| c.flush().collect::<Vec<(K, V)>>(),
+        )
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Parallel;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    #[test]
+    fn scatter_skips_empty_chunks() {
+        let parallel = Parallel::new(4);
+        let mut buckets = vec![0usize; 8];
+        let mut inputs = vec![0usize; 8];
+        inputs[5] = 3;
+        let out: Vec<usize> = parallel.scatter(
+            &mut buckets,
+            inputs,
+            |n| *n == 0,
+            |c, n| {
+                *c += n;
+                n * 2
+            },
+        );
+        assert_eq!(out, vec![0, 0, 0, 0, 0, 6, 0, 0]);
+        assert_eq!(buckets, vec![0, 0, 0, 0, 0, 3, 0, 0]);
+    }
+
+    #[test]
+    fn scatter_bounds_threads() {
+        let parallel = Parallel::new(4);
+        let mut buckets = vec![(); 8];
+        for _ in 0..10 {
+            let threads: Mutex<HashSet<ThreadId>> =
+                Mutex::new(HashSet::new());
+            let _: Vec<()> = parallel.scatter(
+                &mut buckets,
+                vec![(); 8],
+                |_| false,
+                |_, _| {
+                    threads
+                        .lock()
+                        .unwrap()
+                        .insert(std::thread::current().id());
+                },
+            );
+            let threads = threads.into_inner().unwrap();
+            assert!(threads.len() <= 4);
+            assert!(threads.contains(&std::thread::current().id()));
+        }
+    }
+
+    #[test]
+    fn scatter_propagates_panic() {
+        let parallel = Parallel::new(4);
+        let mut buckets = vec![0usize; 8];
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _: Vec<()> = parallel.scatter(
+                    &mut buckets,
+                    (0..8).collect(),
+                    |_| false,
+                    |c, i: usize| {
+                        if i == 5 {
+                            panic!("bucket 5");
+                        }
+                        *c = 1;
+                    },
+                );
+            }));
+        assert!(result.is_err());
+        // Other chunks are done before the panic is propagated.
+        assert_eq!(buckets, vec![1, 1, 1, 1, 1, 0, 1, 1]);
+    }
+}
@@ -1,32 +1,52 @@
-use super::Associative;
+use super::{Associative, BucketSelector};
 use crate::utils::get::LifeTimeGuard;
-use crate::{Get, GetMut};
-use std::hash::{Hash, Hasher};
+use crate::{BuildingBlock, Get, GetMut};
 
-impl<K, V, C, H> Get<K, V> for Associative<C, H>
+impl<C, S, E> Associative<C, S, E> {
+    /// The set where `key` is found among the locked `buckets`: its
+    /// second set in overflow mode if its first set does not contain it,
+    /// else its first set.
+    fn get_set<K, V>(&self, buckets: &[C], key: &K) -> usize
+    where
+        S: BucketSelector<K>,
+        C: BuildingBlock<K, V>,
+    {
+        let i = self.set(key, buckets.len());
+        match self.overflow_set(key, buckets.len()) {
+            Some(j) if !buckets[i].contains(key) => j,
+            _ => i,
+        }
+    }
+}
+
+impl<K, V, C, S, E> Get<K, V> for Associative<C, S, E>
 where
-    K: Hash + Clone,
-    H: Hasher + Clone,
-    C: Get<K, V>,
+    S: BucketSelector<K>,
+    C: Get<K, V> + BuildingBlock<K, V>,
 {
-    type Target = C::Target;
+    type Target = <C as Get<K, V>>::Target;
     fn get(&mut self, key: &K) -> Option<LifeTimeGuard<Self::Target>> {
-        let i = self.set(key.clone());
-        self.containers[i].get(key)
+        self.with_buckets(|buckets| {
+            let i = self.get_set(buckets, key);
+            buckets[i].get(key).map(|v| LifeTimeGuard::new(v.unwrap()))
+        })
     }
 }
 
-impl<K, V, C, H> GetMut<K, V> for Associative<C, H>
+impl<K, V, C, S, E> GetMut<K, V> for Associative<C, S, E>
 where
-    K: Hash + Clone,
-    H: Hasher + Clone,
-    C: GetMut<K, V>,
+    S: BucketSelector<K>,
+    C: GetMut<K, V> + BuildingBlock<K, V>,
 {
-    type Target = C::Target;
+    type Target = <C as GetMut<K, V>>::Target;
 
     fn get_mut(&mut self, key: &K) -> Option<LifeTimeGuard<Self::Target>> {
-        let i = self.set(key.clone());
-        self.containers[i].get_mut(key)
+        self.with_buckets(|buckets| {
+            let i = self.get_set(buckets, key);
+            buckets[i]
+                .get_mut(key)
+                .map(|v| LifeTimeGuard::new(v.unwrap()))
+        })
     }
 }
 
@@ -34,7 +54,8 @@ where
 mod tests {
     use super::Associative;
     use crate::tests::{test_get, test_get_mut};
-    use crate::Array;
+    use crate::utils::associative::KeyRange;
+    use crate::{Array, BuildingBlock, Get, GetMut};
     use std::collections::hash_map::DefaultHasher;
 
     #[test]
@@ -48,4 +69,36 @@ mod tests {
             DefaultHasher::new(),
         ));
     }
+
+    #[test]
+    fn overflow_get() {
+        test_get(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            )
+            .with_overflow(),
+        );
+        test_get_mut(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            )
+            .with_overflow(),
+        );
+
+        // Keys overflowing in the second bucket are found there.
+        let mut c = Associative::new(
+            vec![Array::new(1); 2],
+            KeyRange::new(vec![10]),
+        )
+        .with_overflow();
+        assert!(c.push(vec![(1, 1), (2, 2)]).is_empty());
+        assert_eq!(*c.get(&1).unwrap(), 1);
+        assert_eq!(*c.get(&2).unwrap(), 2);
+        *c.get_mut(&1).unwrap() = 3;
+        *c.get_mut(&2).unwrap() = 4;
+        assert_eq!(*c.get(&1).unwrap(), 3);
+        assert_eq!(*c.get(&2).unwrap(), 4);
+    }
 }
@@ -0,0 +1,41 @@
+use super::Associative;
+use crate::Iter;
+
+impl<K, V, C, S, E> Iter<K, V> for Associative<C, S, E>
+where
+    C: Iter<K, V>,
+{
+    type Iterator<'a>
+        = std::vec::IntoIter<(K, V)>
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of each bucket in sequential order.
+    ///
+    /// The elements are collected while holding the lock on the list of
+    /// buckets such that the iteration is a snapshot of the container
+    /// content.
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.buckets()
+            .iter()
+            .flat_map(|c| c.iter())
+            .collect::<Vec<(K, V)>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Associative;
+    use crate::tests::test_iter;
+    use crate::Array;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[test]
+    fn iter() {
+        test_iter(Associative::new(
+            vec![Array::new(5); 10],
+            DefaultHasher::new(),
+        ));
+    }
+}
@@ -1,10 +1,19 @@
 #[allow(clippy::module_inception)]
 mod associative;
 pub use associative::Associative;
+mod execution;
+pub use execution::{BucketExecution, Parallel, Serial};
+mod selector;
+pub use selector::{BucketSelector, JumpHash, KeyRange, Rendezvous};
 mod multiset_hasher;
 pub use multiset_hasher::ExclusiveHasher;
 pub(crate) mod builder;
 mod building_block;
+mod concurrent;
 #[cfg(feature = "config")]
 pub(crate) mod config;
 mod get;
+mod iter;
+mod rebalance;
+mod resizable;
+mod try_building_block;
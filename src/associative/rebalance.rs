@@ -0,0 +1,310 @@
+use super::{Associative, BucketExecution, BucketSelector};
+use crate::{BuildingBlock, Iter};
+
+impl<C, S, E> Associative<C, S, E> {
+    /// The number of buckets/sets of this container.
+    pub fn num_buckets(&self) -> usize {
+        self.buckets().len()
+    }
+
+    /// Move the elements that do not live in their selected bucket
+    /// anymore to the right bucket among the locked `buckets` and return
+    /// the elements that did not fit in. In overflow mode, elements living
+    /// in their second bucket stay there.
+    ///
+    /// Every element of every bucket is enumerated: even when keys can
+    /// only move to the last bucket, they may come from any other bucket.
+    fn migrate<K, V>(&self, buckets: &mut [C]) -> Vec<(K, V)>
+    where
+        K: Clone,
+        V: Ord,
+        C: BuildingBlock<K, V> + Iter<K, V>,
+        S: BucketSelector<K>,
+        E: BucketExecution<K, V, C>,
+    {
+        let n = buckets.len();
+        let mut moved = Vec::new();
+        for (i, bucket) in buckets.iter_mut().enumerate() {
+            let mut keys: Vec<K> = bucket
+                .iter()
+                .map(|(k, _)| k)
+                .filter(|k| {
+                    self.set(k, n) != i
+                        && self.overflow_set(k, n) != Some(i)
+                })
+                .collect();
+            if !keys.is_empty() {
+                moved.append(&mut bucket.take_multiple(&mut keys));
+            }
+        }
+        self.push_buckets(buckets, moved)
+    }
+
+    /// Add a bucket at the end of the buckets of this container.
+    ///
+    /// The content of the new bucket is kept and the keys of
+    /// existing buckets that are now associated with another bucket are
+    /// migrated there. Only these keys are taken out of their bucket and
+    /// pushed in their new bucket. How many keys move depends on
+    /// the [`BucketSelector`](utils/associative/trait.BucketSelector.html)
+    /// of this container. With
+    /// [`JumpHash`](utils/associative/struct.JumpHash.html) or
+    /// [`Rendezvous`](utils/associative/struct.Rendezvous.html) selectors,
+    /// only the keys moving to the new bucket are migrated, whereas most of
+    /// the keys move with a modulo selector.
+    ///
+    /// All the elements of all the buckets are enumerated to find the keys
+    /// to migrate. Elements are enumerated with the buckets
+    /// [`Iter`](trait.Iter.html) implementation, such that this method
+    /// works the same with in-memory buckets and buckets that store their
+    /// elements in a stream. The elements that do not fit in their new
+    /// bucket are returned.
+    ///
+    /// The list of buckets is shared with
+    /// [`Concurrent`](trait.Concurrent.html) clones of this container.
+    /// It is locked for writing during the migration and clones see the
+    /// new bucket afterward.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use byoc::{Array, Associative, BuildingBlock};
+    /// use byoc::utils::associative::JumpHash;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let mut c = Associative::new(vec![Array::new(10); 2],
+    ///                              JumpHash::new(DefaultHasher::new()));
+    /// c.push((0..10).map(|i| (i, i)).collect());
+    /// assert!(c.add_bucket(Array::new(10)).is_empty());
+    /// assert_eq!(c.num_buckets(), 3);
+    /// assert_eq!(c.size(), 10);
+    /// ```
+    pub fn add_bucket<K, V>(&mut self, bucket: C) -> Vec<(K, V)>
+    where
+        K: Clone,
+        V: Ord,
+        C: BuildingBlock<K, V> + Iter<K, V>,
+        S: BucketSelector<K>,
+        E: BucketExecution<K, V, C>,
+    {
+        let mut buckets = self.buckets_mut();
+        buckets.push(bucket);
+        self.migrate(&mut buckets)
+    }
+
+    /// Remove the last bucket of this container.
+    ///
+    /// The elements of the removed bucket are pushed into the remaining
+    /// buckets, as well as the elements of remaining buckets that are now
+    /// associated with another bucket. See
+    /// [`add_bucket()`](struct.Associative.html#method.add_bucket)
+    /// for details on elements migration and
+    /// [`Concurrent`](trait.Concurrent.html) clones. If the
+    /// [`BucketSelector`](utils/associative/trait.BucketSelector.html) of
+    /// this container is
+    /// [consistent](utils/associative/trait.BucketSelector.html#method.is_consistent),
+    /// only the keys of the removed bucket move and remaining buckets
+    /// are not enumerated, unless this container is in overflow mode.
+    ///
+    /// The removed bucket is returned empty along with the elements that
+    /// did not fit in their new bucket. If this container has only one
+    /// bucket, nothing is removed and `None` is returned.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use byoc::{Array, Associative, BuildingBlock};
+    /// use byoc::utils::associative::Rendezvous;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let mut c = Associative::new(vec![Array::new(10); 3],
+    ///                              Rendezvous::new(DefaultHasher::new()));
+    /// c.push((0..10).map(|i| (i, i)).collect());
+    /// let (bucket, out) = c.remove_bucket().unwrap();
+    /// assert_eq!(bucket.size(), 0);
+    /// assert!(out.is_empty());
+    /// assert_eq!(c.size(), 10);
+    /// ```
+    pub fn remove_bucket<K, V>(&mut self) -> Option<(C, Vec<(K, V)>)>
+    where
+        K: Clone,
+        V: Ord,
+        C: BuildingBlock<K, V> + Iter<K, V>,
+        S: BucketSelector<K>,
+        E: BucketExecution<K, V, C>,
+    {
+        let mut buckets = self.buckets_mut();
+        if buckets.len() < 2 {
+            return None;
+        }
+        let mut bucket = buckets.pop().unwrap();
+        let mut out =
+            self.push_buckets(&mut buckets, bucket.flush().collect());
+        // The second bucket of a key may change even if its first bucket
+        // does not.
+        if self.overflow || !self.selector.is_consistent() {
+            out.append(&mut self.migrate(&mut buckets));
+        }
+        Some((bucket, out))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Associative;
+    use crate::utils::associative::{JumpHash, KeyRange, Rendezvous};
+    use crate::{Array, BuildingBlock, Concurrent, Sequential};
+    use std::collections::hash_map::DefaultHasher;
+
+    #[test]
+    fn add_remove_bucket() {
+        let mut c = Associative::new(
+            vec![Array::new(100); 8],
+            JumpHash::new(DefaultHasher::new()),
+        );
+        assert!(c
+            .push((0..400u16).map(|i| (i, i as u32)).collect())
+            .is_empty());
+
+        // Adding buckets only moves keys to new buckets.
+        for n in 8..12 {
+            let before: Vec<usize> =
+                (0..400u16).map(|k| c.set(&k, n)).collect();
+            assert!(c.add_bucket(Array::new(100)).is_empty());
+            assert_eq!(c.num_buckets(), n + 1);
+            for (k, i) in before.into_iter().enumerate() {
+                let k = k as u16;
+                let j = c.set(&k, n + 1);
+                assert!(i == j || j == n);
+                assert!(c.buckets()[j].contains(&k));
+            }
+        }
+        assert_eq!(c.size(), 400);
+
+        // Removing buckets moves keys back.
+        for n in (8..12).rev() {
+            let (bucket, out) = c.remove_bucket().unwrap();
+            assert!(out.is_empty());
+            assert_eq!(bucket.size(), 0);
+            assert_eq!(c.num_buckets(), n);
+        }
+        assert_eq!(c.size(), 400);
+        for k in 0..400u16 {
+            assert!(c.buckets()[c.set(&k, 8)].contains(&k));
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        // All keys above 10 go in the second bucket when there are two
+        // buckets, the content of the third bucket on removal does not fit.
+        let mut c = Associative::new(
+            vec![Array::new(5); 3],
+            KeyRange::new(vec![10u16, 20]),
+        );
+        assert!(c
+            .push((15..25).map(|i| (i, i as u32)).collect())
+            .is_empty());
+        let (_, out) = c.remove_bucket().unwrap();
+        assert_eq!(out.len(), 5);
+        assert_eq!(c.size(), 5);
+    }
+
+    #[test]
+    fn concurrent_buckets() {
+        let buckets: Vec<Sequential<Array<(u16, u32)>>> =
+            (0..4).map(|_| Sequential::new(Array::new(100))).collect();
+        let clones: Vec<Sequential<Array<(u16, u32)>>> =
+            buckets.iter().map(Concurrent::clone).collect();
+        let mut c = Associative::new(
+            buckets,
+            Rendezvous::new(DefaultHasher::new()),
+        );
+        assert!(c
+            .push((0..100u16).map(|i| (i, i as u32)).collect())
+            .is_empty());
+
+        let (removed, out) = c.remove_bucket().unwrap();
+        assert!(out.is_empty());
+        assert_eq!(removed.size(), 0);
+        assert_eq!(clones[3].size(), 0);
+        assert_eq!(
+            clones[..3].iter().map(|b| b.size()).sum::<usize>(),
+            100
+        );
+
+        // Clones of the container share its buckets and see buckets
+        // added or removed by another clone.
+        let mut clone = Concurrent::clone(&c);
+        assert!(c.add_bucket(removed).is_empty());
+        assert_eq!(clone.num_buckets(), 4);
+        assert_eq!(clone.size(), 100);
+        for k in 0..100u16 {
+            assert!(clone.contains(&k));
+        }
+        let (removed, out) = clone.remove_bucket().unwrap();
+        assert!(out.is_empty());
+        assert_eq!(removed.size(), 0);
+        assert_eq!(c.num_buckets(), 3);
+        assert_eq!(c.size(), 100);
+        assert!(clone.push(vec![(100, 100)]).is_empty());
+        assert!(c.contains(&100));
+    }
+
+    #[test]
+    fn overflow_buckets() {
+        let mut c = Associative::new(
+            vec![Array::new(10); 4],
+            JumpHash::new(DefaultHasher::new()),
+        )
+        .with_overflow();
+        let out = c.push((0..40u16).map(|i| (i, i as u32)).collect());
+        assert_eq!(c.size() + out.len(), 40);
+        assert!(out.iter().all(|(k, _)| !c.contains(k)));
+
+        // Elements stay in either of their candidate buckets.
+        let size = c.size();
+        assert!(c.add_bucket(Array::new(10)).is_empty());
+        assert_eq!(c.size(), size);
+        for (i, bucket) in c.buckets().iter().enumerate() {
+            for k in 0..40u16 {
+                if bucket.contains(&k) {
+                    assert!(
+                        c.set(&k, 5) == i
+                            || c.overflow_set(&k, 5) == Some(i)
+                    );
+                }
+            }
+        }
+        let (_, out) = c.remove_bucket().unwrap();
+        assert_eq!(c.size() + out.len(), size);
+        assert_eq!((0..40u16).filter(|k| c.contains(k)).count(), c.size());
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn stream_buckets() {
+        use crate::stream::{ByteStream, VecStreamFactory};
+        use crate::Iter;
+
+        let mut c = Associative::new(
+            (0..2)
+                .map(|_| ByteStream::new(VecStreamFactory {}, 1000))
+                .collect(),
+            JumpHash::new(DefaultHasher::new()),
+        );
+        assert!(c
+            .push((0..100u16).map(|i| (i, i as u32)).collect())
+            .is_empty());
+        assert!(c
+            .add_bucket(ByteStream::new(VecStreamFactory {}, 1000))
+            .is_empty());
+        assert_eq!(c.iter().count(), 100);
+        for k in 0..100u16 {
+            assert!(c.buckets()[c.set(&k, 3)].contains(&k));
+        }
+        let (_, out) = c.remove_bucket().unwrap();
+        assert!(out.is_empty());
+        assert_eq!(c.iter().count(), 100);
+    }
+}
@@ -0,0 +1,44 @@
+use super::Associative;
+use crate::utils::size::split_capacity;
+use crate::{BuildingBlock, Resizable};
+
+impl<K, V, C, S, E> Resizable<K, V> for Associative<C, S, E>
+where
+    C: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    /// Change the [`Associative`] container capacity.
+    ///
+    /// The new capacity is split between the buckets/sets of this container
+    /// proportionally to their current capacities. Each set is resized
+    /// with its own [`resize()`](trait.Resizable.html#tymethod.resize)
+    /// method and the victims of all sets are returned. Since an element can
+    /// only live in the set its key hashes to, victims are not moved
+    /// to another set.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.with_buckets(|buckets| {
+            let capacities: Vec<usize> =
+                buckets.iter().map(|c| c.capacity()).collect();
+            buckets
+                .iter_mut()
+                .zip(split_capacity(&capacities, new_capacity))
+                .flat_map(|(c, capacity)| c.resize(capacity))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Associative;
+    use crate::tests::test_resizable;
+    use crate::Array;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[test]
+    fn resizable() {
+        test_resizable(Associative::new(
+            vec![Array::new(5); 10],
+            DefaultHasher::new(),
+        ));
+    }
+}
@@ -0,0 +1,282 @@
+use std::hash::{Hash, Hasher};
+
+/// Placement of keys into the buckets of an
+/// [`Associative`](../../struct.Associative.html) container.
+///
+/// A bucket selector maps a key to the index of the bucket where the key
+/// is stored. The mapping must be constant for a given number of buckets,
+/// i.e the same key must always be associated with the same bucket as
+/// long as the number of buckets does not change.
+///
+/// Any [`Hasher`] is also a bucket selector and is the default selector of
+/// [`Associative`](../../struct.Associative.html) containers: keys are
+/// hashed with a clone of the hasher and the bucket index is the hash
+/// modulo the number of buckets. This is the cheapest selector and it
+/// spreads keys evenly on buckets. However, when the number of buckets
+/// changes, almost all the keys change bucket. Other implementations
+/// trade lookup cost for stability of the key placement when the number
+/// of buckets changes, see [`JumpHash`] and [`Rendezvous`], or keep keys
+/// close in the key space in the same bucket, see [`KeyRange`].
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::utils::associative::{BucketSelector, JumpHash};
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// let selector = JumpHash::new(DefaultHasher::new());
+/// let bucket = selector.select(&"some key", 10);
+/// assert!(bucket < 10);
+/// // The same key is always associated with the same bucket.
+/// assert_eq!(selector.select(&"some key", 10), bucket);
+/// ```
+pub trait BucketSelector<K> {
+    /// Index of the bucket in `0..n_buckets` where `key` belongs.
+    ///
+    /// `n_buckets` is always greater than `0`.
+    fn select(&self, key: &K, n_buckets: usize) -> usize;
+
+    /// Whether adding a bucket after `n` buckets only moves keys to the
+    /// new bucket, i.e `select(key, n + 1)` is either `select(key, n)` or
+    /// `n` for any key and any `n`.
+    ///
+    /// Removing the last bucket of a consistent selector then only moves
+    /// the keys of this bucket, such that
+    /// [`remove_bucket()`](../../struct.Associative.html#method.remove_bucket)
+    /// does not look for keys to move in the other buckets. Selectors are
+    /// not consistent by default.
+    fn is_consistent(&self) -> bool {
+        false
+    }
+}
+
+impl<K: Hash, H: Hasher + Clone> BucketSelector<K> for H {
+    /// Select the bucket of `key` as its hash modulo `n_buckets`.
+    fn select(&self, key: &K, n_buckets: usize) -> usize {
+        let mut hasher = self.clone();
+        key.hash(&mut hasher);
+        (hasher.finish() % (n_buckets as u64)) as usize
+    }
+}
+
+/// Select the bucket of a key with
+/// [jump consistent hashing](https://arxiv.org/abs/1406.2294).
+///
+/// Keys are spread evenly on buckets and when a bucket is added at the end
+/// of the buckets, only the keys moving to the new bucket change bucket.
+/// Similarly, removing the last bucket only moves the keys of this bucket.
+/// The cost of a lookup is logarithmic in the number of buckets.
+#[derive(Clone, Debug)]
+pub struct JumpHash<H: Hasher + Clone> {
+    hasher: H,
+}
+
+impl<H: Hasher + Clone> JumpHash<H> {
+    /// Create a jump consistent hashing selector.
+    ///
+    /// Keys are hashed with a clone of `hasher` to seed the jumps
+    /// between buckets.
+    pub fn new(hasher: H) -> Self {
+        JumpHash { hasher }
+    }
+}
+
+impl<K: Hash, H: Hasher + Clone> BucketSelector<K> for JumpHash<H> {
+    fn select(&self, key: &K, n_buckets: usize) -> usize {
+        let mut hasher = self.hasher.clone();
+        key.hash(&mut hasher);
+        let mut key = hasher.finish();
+        let mut b: u64 = 0;
+        let mut j: u64 = 0;
+
+        while j < n_buckets as u64 {
+            b = j;
+            key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+            j = ((b + 1) as f64 * ((1u64 << 31) as f64)
+                / (((key >> 33) + 1) as f64)) as u64;
+        }
+        b as usize
+    }
+
+    fn is_consistent(&self) -> bool {
+        true
+    }
+}
+
+/// Select the bucket of a key with
+/// [rendezvous hashing](https://en.wikipedia.org/wiki/Rendezvous_hashing).
+///
+/// The key is hashed together with each bucket index and the bucket
+/// with the highest hash is selected. When a bucket is added at the end of
+/// the buckets, only the keys selecting the new bucket change bucket.
+/// When the last bucket is removed, only the keys of the removed bucket
+/// change bucket: they move off the removed bucket to the bucket with
+/// their next highest hash. The cost of a lookup is linear in the number
+/// of buckets.
+#[derive(Clone, Debug)]
+pub struct Rendezvous<H: Hasher + Clone> {
+    hasher: H,
+}
+
+impl<H: Hasher + Clone> Rendezvous<H> {
+    /// Create a rendezvous hashing selector.
+    ///
+    /// Each key is hashed together with each bucket index with a clone of
+    /// `hasher`.
+    pub fn new(hasher: H) -> Self {
+        Rendezvous { hasher }
+    }
+}
+
+impl<K: Hash, H: Hasher + Clone> BucketSelector<K> for Rendezvous<H> {
+    fn select(&self, key: &K, n_buckets: usize) -> usize {
+        (0..n_buckets)
+            .max_by_key(|i| {
+                let mut hasher = self.hasher.clone();
+                key.hash(&mut hasher);
+                i.hash(&mut hasher);
+                hasher.finish()
+            })
+            .unwrap_or(0)
+    }
+
+    fn is_consistent(&self) -> bool {
+        true
+    }
+}
+
+/// Select the bucket of a key from sorted key ranges.
+///
+/// This selector is built from a sorted list of bounds.
+/// The first bucket holds the keys strictly lower than the first bound,
+/// the bucket `i` holds the keys greater or equal to the bound `i-1` and
+/// strictly lower than the bound `i`, and the last bucket holds the keys
+/// greater or equal to the last bound. Keys that are close in the key space
+/// stay together in the same bucket. If there are less buckets than
+/// ranges, the keys of the last ranges go to the last bucket.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::utils::associative::{BucketSelector, KeyRange};
+///
+/// let selector = KeyRange::new(vec![10, 20]);
+/// assert_eq!(selector.select(&5, 3), 0);
+/// assert_eq!(selector.select(&10, 3), 1);
+/// assert_eq!(selector.select(&19, 3), 1);
+/// assert_eq!(selector.select(&25, 3), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyRange<K: Ord> {
+    bounds: Vec<K>,
+}
+
+impl<K: Ord> KeyRange<K> {
+    /// Create a key range selector from a list of bounds.
+    ///
+    /// `bounds[i]` is the lowest key of the bucket `i + 1`, such that
+    /// `n` bounds split the key space into `n + 1` buckets. Panics if
+    /// `bounds` are not sorted in strictly increasing order.
+    pub fn new(bounds: Vec<K>) -> Self {
+        assert!(
+            bounds.windows(2).all(|w| w[0] < w[1]),
+            "KeyRange bounds must be sorted in strictly increasing order."
+        );
+        KeyRange { bounds }
+    }
+
+    /// The bounds between the key ranges.
+    pub fn bounds(&self) -> &[K] {
+        &self.bounds
+    }
+}
+
+impl<K: Ord> BucketSelector<K> for KeyRange<K> {
+    fn select(&self, key: &K, n_buckets: usize) -> usize {
+        let i = self.bounds.partition_point(|bound| bound <= key);
+        std::cmp::min(i, n_buckets - 1)
+    }
+
+    fn is_consistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketSelector, JumpHash, KeyRange, Rendezvous};
+    use std::collections::hash_map::DefaultHasher;
+
+    /// Count how many keys out of `0..n_keys` change bucket when the number
+    /// of buckets goes from `n_buckets` to `n_buckets + 1`.
+    fn moved_keys<S: BucketSelector<u32>>(
+        selector: &S,
+        n_keys: u32,
+        n_buckets: usize,
+    ) -> usize {
+        (0..n_keys)
+            .filter(|k| {
+                let before = selector.select(k, n_buckets);
+                let after = selector.select(k, n_buckets + 1);
+                assert!(before < n_buckets);
+                assert!(after < n_buckets + 1);
+                before != after
+            })
+            .count()
+    }
+
+    #[test]
+    fn consistent_hashing() {
+        let n_keys = 10000;
+        let n_buckets = 8;
+        let modulo = moved_keys(&DefaultHasher::new(), n_keys, n_buckets);
+        let jump = moved_keys(
+            &JumpHash::new(DefaultHasher::new()),
+            n_keys,
+            n_buckets,
+        );
+        let rendezvous = moved_keys(
+            &Rendezvous::new(DefaultHasher::new()),
+            n_keys,
+            n_buckets,
+        );
+
+        // About 1/9 of the keys should move to the new bucket.
+        assert!(jump < (n_keys as usize) / 6);
+        assert!(rendezvous < (n_keys as usize) / 6);
+        assert!(modulo > (n_keys as usize) / 2);
+
+        // Moved keys only go to the new bucket.
+        let jump = JumpHash::new(DefaultHasher::new());
+        let rendezvous = Rendezvous::new(DefaultHasher::new());
+        let key_range = KeyRange::new(vec![1000, 2000, 5000]);
+        let modulo = DefaultHasher::new();
+        assert!(!BucketSelector::<u32>::is_consistent(&modulo));
+        for k in 0..n_keys {
+            for s in [
+                &jump as &dyn BucketSelector<u32>,
+                &rendezvous,
+                &key_range,
+            ] {
+                assert!(s.is_consistent());
+                let before = s.select(&k, n_buckets);
+                let after = s.select(&k, n_buckets + 1);
+                assert!(before == after || after == n_buckets);
+            }
+        }
+    }
+
+    #[test]
+    fn key_range() {
+        let selector = KeyRange::new(vec![10u32, 20, 30]);
+        assert_eq!(selector.select(&0, 4), 0);
+        assert_eq!(selector.select(&9, 4), 0);
+        assert_eq!(selector.select(&10, 4), 1);
+        assert_eq!(selector.select(&29, 4), 2);
+        assert_eq!(selector.select(&30, 4), 3);
+        assert_eq!(selector.select(&1000, 4), 3);
+        // Last ranges go to the last bucket.
+        assert_eq!(selector.select(&30, 2), 1);
+        assert_eq!(selector.select(&10, 2), 1);
+    }
+}
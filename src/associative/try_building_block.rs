@@ -0,0 +1,244 @@
+use super::building_block::pop_plan;
+use super::{Associative, BucketExecution, BucketSelector};
+use crate::{Error, TryBuildingBlock};
+
+impl<C, S, E> Associative<C, S, E> {
+    /// Flush the locked `buckets` of this container one after the other.
+    fn try_flush_buckets<K, V>(
+        buckets: &mut [C],
+    ) -> Result<Vec<(K, V)>, Error>
+    where
+        C: TryBuildingBlock<K, V>,
+    {
+        let mut out = Vec::new();
+        for c in buckets.iter_mut() {
+            out.append(&mut c.try_flush()?);
+        }
+        Ok(out)
+    }
+}
+
+impl<K, V, C, S, E> TryBuildingBlock<K, V> for Associative<C, S, E>
+where
+    K: Clone,
+    V: Ord,
+    C: TryBuildingBlock<K, V>,
+    S: BucketSelector<K>,
+    E: BucketExecution<K, V, C>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        let mut capacity = 0;
+        for c in self.buckets().iter() {
+            capacity += c.try_capacity()?;
+        }
+        Ok(capacity)
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        let mut size = 0;
+        for c in self.buckets().iter() {
+            size += c.try_size()?;
+        }
+        Ok(size)
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        let buckets = self.buckets();
+        let i = self.set(key, buckets.len());
+        match (
+            buckets[i].try_contains(key)?,
+            self.overflow_set(key, buckets.len()),
+        ) {
+            (false, Some(j)) => buckets[j].try_contains(key),
+            (found, _) => Ok(found),
+        }
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        self.with_buckets(|buckets| {
+            let n_sets = buckets.len();
+            let i = self.set(key, n_sets);
+            match (
+                buckets[i].try_take(key)?,
+                self.overflow_set(key, n_sets),
+            ) {
+                (None, Some(j)) => buckets[j].try_take(key),
+                (found, _) => Ok(found),
+            }
+        })
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let mut ret = Vec::with_capacity(keys.len());
+        self.with_buckets(|buckets| {
+            let n_sets = buckets.len();
+
+            // Rearrange keys per set.
+            let mut set_keys: Vec<Vec<K>> = Vec::with_capacity(n_sets);
+            for _ in 0..n_sets {
+                set_keys.push(Vec::with_capacity(keys.len()));
+            }
+            for k in keys.drain(0..keys.len()) {
+                set_keys[self.set(&k, n_sets)].push(k);
+            }
+
+            // Take from each bucket.
+            // If a bucket fails, the keys that were not looked up yet are
+            // still put back in the input keys.
+            let mut result = Ok(());
+            for (c, keys) in buckets.iter_mut().zip(set_keys.iter_mut()) {
+                if !keys.is_empty() {
+                    match c.try_take_multiple(keys) {
+                        Ok(mut v) => ret.append(&mut v),
+                        Err(e) => {
+                            result = Err(e);
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Take the keys not found from their second bucket.
+            if result.is_ok() && self.overflow && n_sets > 1 {
+                let mut overflow_keys: Vec<Vec<K>> =
+                    (0..n_sets).map(|_| Vec::new()).collect();
+                for k in set_keys.iter_mut().flat_map(|sk| sk.drain(..)) {
+                    overflow_keys[self.overflow_set(&k, n_sets).unwrap()]
+                        .push(k);
+                }
+                for (c, keys) in
+                    buckets.iter_mut().zip(overflow_keys.iter_mut())
+                {
+                    if !keys.is_empty() {
+                        match c.try_take_multiple(keys) {
+                            Ok(mut v) => ret.append(&mut v),
+                            Err(e) => {
+                                result = Err(e);
+                                break;
+                            }
+                        }
+                    }
+                }
+                set_keys = overflow_keys;
+            }
+
+            // Put the remaining keys back in the input keys.
+            for mut sk in set_keys.into_iter() {
+                keys.append(&mut sk);
+            }
+
+            result.map(|_| ret)
+        })
+    }
+
+    fn try_pop(&mut self, size: usize) -> Result<Vec<(K, V)>, Error> {
+        self.with_buckets(|buckets| {
+            let mut sizes = Vec::with_capacity(buckets.len());
+            for c in buckets.iter() {
+                sizes.push(c.try_size()?);
+            }
+
+            match pop_plan(sizes, size) {
+                None => Self::try_flush_buckets(buckets),
+                Some(plan) => {
+                    let mut victims = Vec::<(K, V)>::new();
+                    for (bucket_index, pop_size) in plan.into_iter() {
+                        let bucket = &mut buckets[bucket_index];
+                        victims.append(&mut bucket.try_pop(pop_size)?);
+                    }
+                    Ok(victims)
+                }
+            }
+        })
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let n = elements.len();
+        self.with_buckets(|buckets| {
+            let n_sets = buckets.len();
+            let mut set_elements: Vec<Vec<(K, V)>> =
+                Vec::with_capacity(n_sets);
+            for _ in 0..n_sets {
+                set_elements.push(Vec::with_capacity(n));
+            }
+            for e in elements.into_iter() {
+                set_elements[self.set(&e.0, n_sets)].push(e);
+            }
+
+            // Take pushed keys out of their second bucket.
+            let mut out = Vec::with_capacity(n);
+            for e in set_elements.iter().flatten() {
+                if let Some(j) = self.overflow_set(&e.0, n_sets) {
+                    if let Some(taken) = buckets[j].try_take(&e.0)? {
+                        out.push(taken);
+                    }
+                }
+            }
+
+            let mut overflow_elements: Vec<Vec<(K, V)>> =
+                (0..n_sets).map(|_| Vec::new()).collect();
+            for (i, v) in set_elements.into_iter().enumerate() {
+                for e in buckets[i].try_push(v)?.into_iter() {
+                    // Offer the victims evicted out of their first bucket to
+                    // their second bucket.
+                    match self.overflow_set(&e.0, n_sets) {
+                        Some(j)
+                            if self.set(&e.0, n_sets) == i
+                                && !buckets[i].try_contains(&e.0)? =>
+                        {
+                            overflow_elements[j].push(e)
+                        }
+                        _ => out.push(e),
+                    }
+                }
+            }
+            for (j, v) in overflow_elements.into_iter().enumerate() {
+                if !v.is_empty() {
+                    out.append(&mut (buckets[j].try_push(v)?));
+                }
+            }
+            Ok(out)
+        })
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        self.with_buckets(Self::try_flush_buckets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Associative;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            ),
+            true,
+        );
+    }
+
+    #[test]
+    fn overflow_try_building_block() {
+        test_try_building_block(
+            Associative::new(
+                vec![Array::new(5); 10],
+                DefaultHasher::new(),
+            )
+            .with_overflow(),
+            true,
+        );
+    }
+}
@@ -27,6 +27,14 @@ where
         self.bb.iter().any(|c| c.contains(key))
     }
 
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.bb.iter().any(|c| c.contains_if(key, predicate))
+    }
+
     /// Take the matching key/value pair out of the container.
     ///
     /// This method iterates through all batches and stop at the first batch
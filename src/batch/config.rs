@@ -66,12 +66,42 @@ impl ConfigInstance for BatchConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        let c = self
+        let is_resizable = self.is_resizable();
+        let containers = self
             .container
             .into_iter()
             .map(|cfg| GenericConfig::from_toml(&cfg).unwrap().build())
-            .fold(Batch::new(), |acc, batch| acc.append(batch));
-        DynBuildingBlock::new(c, false)
+            .collect();
+        match DynBuildingBlock::into_get_all(containers) {
+            Ok(containers) => DynBuildingBlock::new_resizable_get(
+                containers
+                    .into_iter()
+                    .fold(Batch::new(), |acc, batch| acc.append(batch)),
+                false,
+                is_resizable,
+            ),
+            Err(containers) => DynBuildingBlock::new_resizable(
+                containers
+                    .into_iter()
+                    .fold(Batch::new(), |acc, batch| acc.append(batch)),
+                false,
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        self.container
+            .iter()
+            .map(|cfg| GenericConfig::from_toml(cfg).unwrap())
+            .all(|cfg| cfg.has_get_trait())
+    }
+
+    fn is_resizable(&self) -> bool {
+        self.container
+            .iter()
+            .map(|cfg| GenericConfig::from_toml(cfg).unwrap())
+            .all(|cfg| cfg.is_resizable())
     }
 }
 
@@ -0,0 +1,37 @@
+use super::Batch;
+use crate::Iter;
+
+impl<K, V, C> Iter<K, V> for Batch<C>
+where
+    C: Iter<K, V>,
+{
+    type Iterator<'a>
+        = std::iter::Flatten<std::vec::IntoIter<C::Iterator<'a>>>
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of each batch in sequential order.
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.bb
+            .iter()
+            .map(|c| c.iter())
+            .collect::<Vec<C::Iterator<'_>>>()
+            .into_iter()
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Batch;
+    use crate::tests::test_iter;
+    use crate::Array;
+
+    #[test]
+    fn iter() {
+        test_iter(Batch::<Array<(u16, u32)>>::new());
+        test_iter(Batch::from([Array::new(0)]));
+        test_iter(Batch::from([Array::new(0), Array::new(10)]));
+        test_iter(Batch::from([Array::new(10), Array::new(10)]));
+    }
+}
@@ -5,3 +5,6 @@ mod building_block;
 #[cfg(feature = "config")]
 pub(crate) mod config;
 mod get;
+mod iter;
+mod resizable;
+mod try_building_block;
@@ -0,0 +1,44 @@
+use super::Batch;
+use crate::utils::size::split_capacity;
+use crate::{BuildingBlock, Resizable};
+
+impl<K, V, C> Resizable<K, V> for Batch<C>
+where
+    V: Ord,
+    C: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    /// Change the [`Batch`] container capacity.
+    ///
+    /// The new capacity is split between batches proportionally to their
+    /// current capacities. Each batch is resized with its own
+    /// [`resize()`](trait.Resizable.html#tymethod.resize) method.
+    /// The victims of all batches are then
+    /// [pushed](struct.Batch.html#method.push) back in the container and
+    /// the elements that do not fit anymore are returned.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        let capacities: Vec<usize> =
+            self.bb.iter().map(|c| c.capacity()).collect();
+        let victims: Vec<(K, V)> = self
+            .bb
+            .iter_mut()
+            .zip(split_capacity(&capacities, new_capacity))
+            .flat_map(|(c, capacity)| c.resize(capacity))
+            .collect();
+        self.push(victims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Batch;
+    use crate::tests::test_resizable;
+    use crate::Array;
+
+    #[test]
+    fn resizable() {
+        test_resizable(Batch::<Array<(u16, u32)>>::new());
+        test_resizable(Batch::from([Array::new(0)]));
+        test_resizable(Batch::from([Array::new(0), Array::new(10)]));
+        test_resizable(Batch::from([Array::new(10), Array::new(10)]));
+    }
+}
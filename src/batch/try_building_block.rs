@@ -0,0 +1,128 @@
+use super::Batch;
+use crate::{Error, TryBuildingBlock};
+use std::collections::LinkedList;
+
+impl<K, V, C> TryBuildingBlock<K, V> for Batch<C>
+where
+    V: Ord,
+    C: TryBuildingBlock<K, V>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        let mut capacity = 0;
+        for c in self.bb.iter() {
+            capacity += c.try_capacity()?;
+        }
+        Ok(capacity)
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        let mut size = 0;
+        for c in self.bb.iter() {
+            size += c.try_size()?;
+        }
+        Ok(size)
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        for c in self.bb.iter() {
+            if c.try_contains(key)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        for c in self.bb.iter_mut() {
+            if let Some(kv) = c.try_take(key)? {
+                return Ok(Some(kv));
+            }
+        }
+        Ok(None)
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let mut out = Vec::with_capacity(keys.len());
+        for c in self.bb.iter_mut() {
+            if keys.is_empty() {
+                break;
+            }
+            out.append(&mut c.try_take_multiple(keys)?)
+        }
+        Ok(out)
+    }
+
+    fn try_pop(&mut self, mut size: usize) -> Result<Vec<(K, V)>, Error> {
+        let mut out = Vec::new();
+        for bb in self.bb.iter_mut().rev() {
+            let bb_size = bb.try_size()?;
+            out.append(&mut bb.try_pop(size)?);
+            let popped_size = bb_size - bb.try_size()?;
+            if popped_size >= size {
+                break;
+            }
+            size -= popped_size;
+        }
+        Ok(out)
+    }
+
+    fn try_push(
+        &mut self,
+        mut values: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let mut bb = LinkedList::new();
+        loop {
+            if values.is_empty() {
+                break;
+            }
+            let mut c = match self.bb.pop_front() {
+                None => break,
+                Some(c) => c,
+            };
+            let push = c.try_push(values);
+            // Batches are put back in the list even if they fail.
+            bb.push_back(c);
+            match push {
+                Ok(v) => values = v,
+                Err(e) => {
+                    self.bb.append(&mut bb);
+                    return Err(e);
+                }
+            }
+        }
+        self.bb.append(&mut bb);
+        Ok(values)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        let mut out = Vec::new();
+        for c in self.bb.iter_mut() {
+            out.append(&mut c.try_flush()?);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Batch;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(Batch::<Array<(u16, u32)>>::new(), true);
+        test_try_building_block(Batch::from([Array::new(0)]), true);
+        test_try_building_block(
+            Batch::from([Array::new(0), Array::new(10)]),
+            true,
+        );
+        test_try_building_block(
+            Batch::from([Array::new(10), Array::new(10)]),
+            true,
+        );
+    }
+}
@@ -148,6 +148,6 @@ where
     V: 'a + Ord,
 {
     fn from(btree: BTree<K, V>) -> Self {
-        crate::DynBuildingBlock::new(btree, false)
+        crate::DynBuildingBlock::new_resizable_get(btree, false, true)
     }
 }
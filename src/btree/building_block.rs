@@ -49,6 +49,14 @@ where
         self.map.contains_key(key)
     }
 
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        matches!(self.map.get(key), Some(v) if predicate(v))
+    }
+
     /// Insert key/value pairs in the container.
     ///
     /// The total `size` of the elements to push is computed using
@@ -37,6 +37,17 @@ pub struct BTreeConfig {
     capacity: usize,
 }
 
+impl BTreeConfig {
+    /// Build the [`BTree`] described by this configuration.
+    pub(crate) fn btree<K, V>(&self) -> BTree<K, V>
+    where
+        K: Copy + Ord,
+        V: Ord,
+    {
+        BTree::new(self.capacity)
+    }
+}
+
 impl ConfigInstance for BTreeConfig {
     fn id() -> &'static str {
         "BTreeConfig"
@@ -55,7 +66,15 @@ impl ConfigInstance for BTreeConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        DynBuildingBlock::new(BTree::new(self.capacity), false)
+        DynBuildingBlock::new_resizable_get(self.btree(), false, true)
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
     }
 }
 
@@ -0,0 +1,40 @@
+use super::{BTree, BTreeCell};
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, GetEntry, GetMut};
+
+impl<K, V> GetEntry<K, V> for BTree<K, V>
+where
+    K: Copy + Ord,
+    V: Ord,
+{
+    type Target = BTreeCell<K, V>;
+
+    /// Look up the key once. Vacant entries are inserted with the
+    /// [`BTree`] [`push()`](trait.BuildingBlock.html#method.push) method.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        match self.get_mut(&key).map(LifeTimeGuard::unwrap) {
+            Some(value) => Entry::Occupied(OccupiedEntry::new(
+                key,
+                LifeTimeGuard::new(value),
+            )),
+            None => Entry::Vacant(VacantEntry::new(
+                key,
+                Box::new(move |k, v| self.push(vec![(k, v)])),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use crate::tests::test_entry;
+
+    #[test]
+    fn entry() {
+        test_entry(BTree::new(0));
+        test_entry(BTree::new(10));
+        test_entry(BTree::new(100));
+    }
+}
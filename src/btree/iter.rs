@@ -0,0 +1,35 @@
+use super::BTree;
+use crate::Iter;
+use std::collections::btree_map;
+use std::rc::Rc;
+
+impl<K, V> Iter<K, V> for BTree<K, V>
+where
+    K: Copy + Ord,
+    V: Clone + Ord,
+{
+    type Iterator<'a>
+        = std::iter::Map<
+        btree_map::Iter<'a, K, Rc<V>>,
+        fn((&'a K, &'a Rc<V>)) -> (K, V),
+    >
+    where
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.map.iter().map(|(k, v)| (*k, V::clone(v)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use crate::tests::test_iter;
+
+    #[test]
+    fn iter() {
+        test_iter(BTree::new(0));
+        test_iter(BTree::new(10));
+        test_iter(BTree::new(100));
+    }
+}
@@ -2,7 +2,12 @@
 mod btree;
 pub use btree::BTree;
 mod building_block;
+mod entry;
 mod get;
+mod iter;
+mod range;
+mod resizable;
+mod try_building_block;
 pub use get::BTreeCell;
 pub(crate) mod builder;
 #[cfg(feature = "config")]
@@ -0,0 +1,50 @@
+use super::BTree;
+use crate::{BuildingBlock, RangeGet, RangeTake};
+use std::ops::RangeBounds;
+
+impl<K: Copy + Ord, V: Ord> RangeGet<K, V> for BTree<K, V> {
+    /// Check whether the container contains at least one key within
+    /// `range`.
+    ///
+    /// This method looks up the first key of the range in the underlying
+    /// [`BTreeMap`](std::collections::BTreeMap) of the container.
+    fn contains_range<R: RangeBounds<K>>(&self, range: R) -> bool {
+        self.map.range(range).next().is_some()
+    }
+
+    /// Get the keys of the container within `range` sorted in ascending
+    /// order.
+    ///
+    /// This method only iterates the keys within the range in the
+    /// underlying [`BTreeMap`](std::collections::BTreeMap) of the
+    /// container.
+    fn keys_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<K> {
+        self.map.range(range).map(|(k, _)| *k).collect()
+    }
+}
+
+impl<K: Copy + Ord, V: Ord> RangeTake<K, V> for BTree<K, V> {
+    /// Take all the key/value pairs with a key within `range` out of the
+    /// container.
+    ///
+    /// The elements are returned in ascending order of keys.
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)> {
+        self.keys_in_range(range)
+            .into_iter()
+            .map(|k| self.take(&k).unwrap())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use crate::tests::test_range;
+
+    #[test]
+    fn range() {
+        test_range(BTree::new(0));
+        test_range(BTree::new(10));
+        test_range(BTree::new(100));
+    }
+}
@@ -0,0 +1,30 @@
+use super::BTree;
+use crate::{BuildingBlock, Resizable};
+
+impl<K: Copy + Ord, V: Ord> Resizable<K, V> for BTree<K, V> {
+    /// Change the [`BTree`] capacity.
+    ///
+    /// If the elements of the container do not fit in the new capacity,
+    /// the elements with the greatest values are popped out until they do.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity;
+        if self.total_size > new_capacity {
+            self.pop(self.total_size - new_capacity)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        test_resizable(BTree::new(0));
+        test_resizable(BTree::new(10));
+        test_resizable(BTree::new(100));
+    }
+}
@@ -0,0 +1,17 @@
+use super::BTree;
+use crate::TryBuildingBlock;
+
+impl<K: Copy + Ord, V: Ord> TryBuildingBlock<K, V> for BTree<K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use crate::tests::test_try_building_block;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(BTree::new(0), true);
+        test_try_building_block(BTree::new(10), true);
+        test_try_building_block(BTree::new(100), true);
+    }
+}
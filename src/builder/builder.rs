@@ -2,11 +2,15 @@
 use crate::builder::CompressedBuilder;
 #[cfg(feature = "stream")]
 use crate::builder::StreamBuilder;
-use crate::builder::{ArrayBuilder, BTreeBuilder};
+use crate::builder::{
+    ArcCacheBuilder, ArrayBuilder, BTreeBuilder, ClockBuilder,
+    ClockProBuilder, HashMapBuilder,
+};
 #[cfg(feature = "stream")]
 use crate::stream::StreamFactory;
 #[cfg(feature = "stream")]
 use serde::{de::DeserializeOwned, Serialize};
+use std::hash::Hash;
 
 /// Entry point to build a container from builder pattern chain.
 ///
@@ -36,6 +40,12 @@ use serde::{de::DeserializeOwned, Serialize};
 pub struct Builder {}
 
 impl Builder {
+    pub fn arc<K: Clone + Eq + Hash, V>(
+        capacity: usize,
+    ) -> ArcCacheBuilder<K, V> {
+        ArcCacheBuilder::new(capacity)
+    }
+
     pub fn array<T>(capacity: usize) -> ArrayBuilder<T> {
         ArrayBuilder::new(capacity)
     }
@@ -46,6 +56,24 @@ impl Builder {
         BTreeBuilder::new(capacity)
     }
 
+    pub fn clock<K: Clone + Eq + Hash, V>(
+        capacity: usize,
+    ) -> ClockBuilder<K, V> {
+        ClockBuilder::new(capacity)
+    }
+
+    pub fn clock_pro<K: Clone + Eq + Hash, V>(
+        capacity: usize,
+    ) -> ClockProBuilder<K, V> {
+        ClockProBuilder::new(capacity)
+    }
+
+    pub fn hashmap<K: Clone + Eq + Hash, V: Ord>(
+        capacity: usize,
+    ) -> HashMapBuilder<K, V> {
+        HashMapBuilder::new(capacity)
+    }
+
     #[cfg(feature = "stream")]
     pub fn byte_stream<
         T: DeserializeOwned + Serialize,
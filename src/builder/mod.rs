@@ -61,28 +61,41 @@ pub trait Build<C> {
     fn build(self) -> C;
 }
 
+pub use crate::arc::builder::ArcCacheBuilder;
 pub use crate::array::builder::ArrayBuilder;
 pub use crate::associative::builder::AssociativeBuilder;
 pub use crate::btree::builder::BTreeBuilder;
+pub use crate::clock::builder::ClockBuilder;
+pub use crate::clock_pro::builder::ClockProBuilder;
 #[cfg(feature = "compression")]
 pub use crate::compression::builder::CompressedBuilder;
 pub use crate::decorator::builder::DecoratorBuilder;
+pub use crate::eviction_listener::builder::EvictionListenerBuilder;
 pub use crate::exclusive::builder::ExclusiveBuilder;
 pub use crate::flush_stopper::builder::FlushStopperBuilder;
+pub use crate::hashmap::builder::HashMapBuilder;
+pub use crate::hierarchy::builder::HierarchyBuilder;
 pub use crate::inclusive::builder::InclusiveBuilder;
+pub use crate::loader::builder::LoaderBuilder;
 pub use crate::profiler::builder::ProfilerBuilder;
 pub use crate::sequential::builder::SequentialBuilder;
+pub use crate::slru::builder::SlruBuilder;
 #[cfg(feature = "socket")]
 pub use crate::socket::builder::{
     ServerBuild, SocketClientBuilder, SocketServerBuilder,
 };
 #[cfg(feature = "stream")]
 pub use crate::stream::builder::StreamBuilder;
+pub use crate::tinylfu::builder::TinyLfuAdmissionBuilder;
 
 pub use crate::associative::builder::AssociativeBuild;
 pub use crate::decorator::builder::DecoratorBuild;
+pub use crate::eviction_listener::builder::EvictionListenerBuild;
 pub use crate::exclusive::builder::ExclusiveBuild;
 pub use crate::flush_stopper::builder::FlushStopperBuild;
 pub use crate::inclusive::builder::InclusiveBuild;
+pub use crate::loader::builder::LoaderBuild;
 pub use crate::profiler::builder::ProfilerBuild;
 pub use crate::sequential::builder::SequentialBuild;
+pub use crate::slru::builder::SlruBuild;
+pub use crate::tinylfu::builder::TinyLfuAdmissionBuild;
@@ -0,0 +1,50 @@
+use crate::builder::Build;
+use crate::ClockCache;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// `ClockCache` builder.
+///
+/// This builder can be consumed later to spawn a
+/// [`ClockCache`](../../struct.ClockCache.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::Build;
+/// use byoc::builder::ClockBuilder;
+///
+/// let mut container = ClockBuilder::new(2).build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct ClockBuilder<K: Clone + Eq + Hash, V> {
+    pub(super) capacity: usize,
+    unused: PhantomData<(K, V)>,
+}
+
+impl<K: Clone + Eq + Hash, V> ClockBuilder<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        ClockBuilder {
+            capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Clone for ClockBuilder<K, V> {
+    fn clone(&self) -> Self {
+        ClockBuilder {
+            capacity: self.capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Build<ClockCache<K, V>>
+    for ClockBuilder<K, V>
+{
+    fn build(self) -> ClockCache<K, V> {
+        ClockCache::new(self.capacity)
+    }
+}
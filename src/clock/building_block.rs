@@ -0,0 +1,156 @@
+use super::ClockCache;
+use crate::BuildingBlock;
+use std::hash::Hash;
+
+impl<K, V> BuildingBlock<K, V> for ClockCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Get the maximum number of elements fitting in the container.
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of elements in the container.
+    fn size(&self) -> usize {
+        self.index.len()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        match self.index.get(key) {
+            None => false,
+            Some(&i) => predicate(&self.slot(i).1),
+        }
+    }
+
+    type FlushIterator = std::iter::Map<
+        std::iter::Flatten<std::vec::IntoIter<Option<(K, V, bool)>>>,
+        fn((K, V, bool)) -> (K, V),
+    >;
+
+    /// Empty the container and return its elements.
+    fn flush(&mut self) -> Self::FlushIterator {
+        self.index.clear();
+        self.hand = 0;
+        self.slots
+            .split_off(0)
+            .into_iter()
+            .flatten()
+            .map(|(k, v, _)| (k, v))
+    }
+
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        let i = *self.index.get(key)?;
+        Some(self.remove(i))
+    }
+
+    /// Evict up to `size` elements from the container.
+    ///
+    /// Victims are chosen one after the other by sweeping the clock hand
+    /// over the elements. Elements with their reference bit set have it
+    /// cleared and are skipped. The first element found with a cleared
+    /// reference bit is evicted.
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        let mut out = Vec::with_capacity(size.min(self.index.len()));
+        while out.len() < size {
+            match self.sweep() {
+                None => break,
+                Some(i) => out.push(self.remove(i)),
+            }
+        }
+        out
+    }
+
+    /// Insert key/value pairs in the container.
+    ///
+    /// Elements whose key is already in the container replace the
+    /// value in the container, which is returned, and set the element
+    /// reference bit. Other elements are inserted in free slots if any,
+    /// or replace the element under the clock hand after the hand is
+    /// swept like in [`pop()`](struct.ClockCache.html#method.pop). Replaced
+    /// elements are returned. If the container capacity is `0`, all
+    /// `elements` are returned.
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for (key, value) in elements {
+            match self.index.get(&key) {
+                Some(&i) => {
+                    let slot = self.slot_mut(i);
+                    slot.2 = true;
+                    let value = std::mem::replace(&mut slot.1, value);
+                    out.push((key, value));
+                }
+                None => {
+                    if let Some(e) = self.insert(key, value) {
+                        out.push(e);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockCache;
+    use crate::tests::{test_building_block, test_concurrent};
+    use crate::{BuildingBlock, Get, Sequential};
+
+    #[test]
+    fn building_block() {
+        test_building_block(ClockCache::new(0), true);
+        test_building_block(ClockCache::new(10), true);
+        test_building_block(ClockCache::new(100), true);
+    }
+
+    #[test]
+    fn concurrent() {
+        test_concurrent(Sequential::new(ClockCache::new(0)), 64);
+        test_concurrent(Sequential::new(ClockCache::new(100)), 64);
+    }
+
+    #[test]
+    fn second_chance() {
+        let mut c = ClockCache::new(3);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32), (2u16, 2u32)]);
+        assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        assert_eq!(*c.get(&2u16).unwrap(), 2u32);
+
+        // Referenced elements are skipped once.
+        assert_eq!(c.push(vec![(3u16, 3u32)]), vec![(1u16, 1u32)]);
+        assert_eq!(c.pop(1), vec![(0u16, 0u32)]);
+
+        // The hand reaches "3" before "2" which reference bit was just
+        // cleared.
+        assert_eq!(c.pop(1), vec![(3u16, 3u32)]);
+        assert_eq!(c.pop(1), vec![(2u16, 2u32)]);
+        assert!(c.pop(1).is_empty());
+    }
+
+    #[test]
+    fn ring_order() {
+        let mut c = ClockCache::new(4);
+        c.push(vec![(1u16, 1u32), (2, 2), (3, 3), (4, 4)]);
+
+        // Consecutive evictions follow the insertion order.
+        assert_eq!(c.pop(1), vec![(1u16, 1u32)]);
+        assert_eq!(c.pop(1), vec![(2u16, 2u32)]);
+
+        // Taking an element out does not change the others order.
+        c.push(vec![(5u16, 5u32), (6, 6)]);
+        assert_eq!(c.take(&3u16), Some((3u16, 3u32)));
+        assert_eq!(c.pop(1), vec![(4u16, 4u32)]);
+        assert_eq!(c.pop(1), vec![(5u16, 5u32)]);
+        assert_eq!(c.pop(1), vec![(6u16, 6u32)]);
+        assert!(c.pop(1).is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// In-memory container with a CLOCK eviction policy.
+///
+/// [`ClockCache`] stores its elements in a [`std::vec::Vec`] used as a
+/// circular buffer. Every element carries a reference bit that is set
+/// when the element is accessed with the [`Get`](trait.Get.html) or
+/// [`GetMut`](trait.GetMut.html) traits. When an element has to be
+/// evicted, a hand sweeps the buffer from where it last stopped: elements
+/// with their reference bit set get a second chance and have it cleared,
+/// and the first element found with a cleared reference bit is evicted.
+///
+/// Unlike [`Array`](struct.Array.html) which sorts its elements on every
+/// eviction, each eviction in a [`ClockCache`] runs in amortized constant
+/// time. Elements are indexed by key in a [`std::collections::HashMap`]
+/// such that lookups are constant time as well.
+///
+/// The capacity of a [`ClockCache`] is the number of elements it can hold.
+/// This container cannot contain duplicate keys.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{BuildingBlock, ClockCache, Get};
+///
+/// let mut c = ClockCache::new(2);
+/// c.push(vec![("first", 1), ("second", 2)]);
+///
+/// // Accessing "first" gives it a second chance when evicting.
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+/// let out = c.push(vec![("third", 3)]);
+/// assert_eq!(out, vec![("second", 2)]);
+/// assert!(c.contains(&"first"));
+/// ```
+///
+/// [`ClockCache`] can also be built from a
+/// [builder pattern](builder/struct.Builder.html#method.clock) and a
+/// [configuration](config/struct.ClockConfig.html).
+pub struct ClockCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    // Maximum number of elements.
+    pub(super) capacity: usize,
+    // Position of the next element to inspect in `slots`.
+    pub(super) hand: usize,
+    // Elements with their reference bit, in the circular buffer order.
+    // Removed elements leave an empty slot until the buffer is compacted.
+    pub(super) slots: Vec<Option<(K, V, bool)>>,
+    // Position of each key in `slots`.
+    pub(super) index: HashMap<K, usize>,
+}
+
+impl<K: Clone + Eq + Hash, V> ClockCache<K, V> {
+    /// Create a new [`ClockCache`] container holding up to `capacity`
+    /// elements.
+    pub fn new(capacity: usize) -> Self {
+        ClockCache {
+            capacity,
+            hand: 0,
+            slots: Vec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+        }
+    }
+
+    pub(super) fn slot(&self, i: usize) -> &(K, V, bool) {
+        self.slots[i].as_ref().unwrap()
+    }
+
+    pub(super) fn slot_mut(&mut self, i: usize) -> &mut (K, V, bool) {
+        self.slots[i].as_mut().unwrap()
+    }
+
+    /// Move the hand forward until it points to an element with a
+    /// cleared reference bit, clearing the reference bit of the elements
+    /// it passes. Returns the position of this element or `None` if
+    /// the container is empty.
+    pub(super) fn sweep(&mut self) -> Option<usize> {
+        if self.index.is_empty() {
+            return None;
+        }
+        loop {
+            if self.hand >= self.slots.len() {
+                self.hand = 0;
+            }
+            if let Some((_, _, referenced)) = &mut self.slots[self.hand] {
+                if !*referenced {
+                    return Some(self.hand);
+                }
+                *referenced = false;
+            }
+            self.hand += 1;
+        }
+    }
+
+    /// Remove the element at position `i` in `slots`.
+    ///
+    /// The slot is left empty such that the other elements keep their
+    /// position relative to the hand. Empty slots are reclaimed when they
+    /// outnumber the elements.
+    pub(super) fn remove(&mut self, i: usize) -> (K, V) {
+        let (key, value, _) = self.slots[i].take().unwrap();
+        self.index.remove(&key);
+        if self.slots.len() > 2 * self.index.len() {
+            self.compact();
+        }
+        (key, value)
+    }
+
+    /// Remove empty slots, keeping the elements and the hand in the same
+    /// order.
+    fn compact(&mut self) {
+        let hand = self.hand;
+        self.hand = 0;
+        let mut j = 0;
+        for i in 0..self.slots.len() {
+            if i == hand {
+                self.hand = j;
+            }
+            if let Some((k, _, _)) = &self.slots[i] {
+                *self.index.get_mut(k).unwrap() = j;
+                self.slots.swap(i, j);
+                j += 1;
+            }
+        }
+        self.slots.truncate(j);
+    }
+
+    /// Insert a new key/value pair.
+    ///
+    /// If the container is full, the element under the hand is replaced
+    /// and returned. If the container capacity is `0`, the key/value pair
+    /// is returned.
+    pub(super) fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+        if self.index.len() < self.capacity {
+            self.index.insert(key.clone(), self.slots.len());
+            self.slots.push(Some((key, value, false)));
+            return None;
+        }
+
+        let i = self.sweep()?;
+        let (k, v, _) = std::mem::replace(
+            self.slot_mut(i),
+            (key.clone(), value, false),
+        );
+        self.index.remove(&k);
+        self.index.insert(key, i);
+        self.hand = i + 1;
+        Some((k, v))
+    }
+}
+
+impl<'a, K, V> From<ClockCache<K, V>> for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Clone + Eq + Hash,
+    V: 'a,
+{
+    fn from(container: ClockCache<K, V>) -> Self {
+        crate::DynBuildingBlock::new_resizable_get(container, false, true)
+    }
+}
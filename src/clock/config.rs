@@ -0,0 +1,124 @@
+use crate::builder::ClockBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericKey, GenericValue, IntoConfig,
+};
+use crate::objsafe::DynBuildingBlock;
+use crate::ClockCache;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// Configuration format for [`ClockCache`](../struct.ClockCache.html)
+/// containers.
+///
+/// This configuration format is composed of two key/value fields that
+/// must be present:      
+/// - `id = "ClockConfig"` and
+/// - `capacity = <int>`
+///
+/// The `id` field must be exactly "ClockConfig" while the capacity
+/// will set the maximum number of key/value pairs that the container can
+/// hold.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id = 'ClockConfig'
+/// capacity = 10
+/// ");
+/// let container: DynBuildingBlock<u64, u64> =
+///            ConfigBuilder::from_string(config_str.as_str())
+///            .unwrap()
+///            .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct ClockConfig {
+    #[allow(dead_code)]
+    id: String,
+    capacity: usize,
+}
+
+impl ConfigInstance for ClockConfig {
+    fn id() -> &'static str {
+        "ClockConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        toml::from_str(&toml).map_err(|e| {
+            ConfigError::ConfigFormatError(format!(
+                "Invalid ClockConfig: {}\n{:?}",
+                toml, e
+            ))
+        })
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        DynBuildingBlock::new_resizable_get(
+            ClockCache::new(self.capacity),
+            false,
+            true,
+        )
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> IntoConfig<ClockConfig>
+    for ClockBuilder<K, V>
+{
+    fn as_config(&self) -> ClockConfig {
+        ClockConfig {
+            id: String::from(ClockConfig::id()),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockConfig;
+    use crate::builder::ClockBuilder;
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_clock_config() {
+        let capacity = 1008;
+        let config_str =
+            format!("id='ClockConfig'\ncapacity={}", capacity);
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = ClockConfig::from_toml(&value).unwrap();
+        assert_eq!(config.capacity, capacity);
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_invalid_clock_config() {
+        let config_str = "id='ClockConfig'\ncapacity='ten'".to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            ClockConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder = ClockBuilder::<(), ()>::new(2);
+        test_config_builder(builder);
+    }
+}
@@ -0,0 +1,88 @@
+use super::ClockCache;
+use crate::utils::get::LifeTimeGuard;
+use crate::{Get, GetMut};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+
+/// Read-only cell representing a reference to a value inside a
+/// [`ClockCache`](struct.ClockCache.html) container.
+pub struct ClockCell<V> {
+    t: *const V,
+}
+
+impl<V> Deref for ClockCell<V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // The cell is wrapped in a `LifeTimeGuard` borrowing the container
+        // mutably. Therefore, the container cannot be modified while this
+        // cell is alive.
+        unsafe { self.t.as_ref().unwrap() }
+    }
+}
+
+/// Read-write cell holding a reference to a value inside a
+/// [`ClockCache`](struct.ClockCache.html) container.
+pub struct ClockMutCell<V> {
+    t: *mut V,
+}
+
+impl<V> Deref for ClockMutCell<V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // See `ClockCell` Deref trait method.
+        unsafe { self.t.as_ref().unwrap() }
+    }
+}
+
+impl<V> DerefMut for ClockMutCell<V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // See `ClockCell` Deref trait method.
+        unsafe { self.t.as_mut().unwrap() }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Get<K, V> for ClockCache<K, V> {
+    type Target = ClockCell<V>;
+
+    /// Accessed elements have their reference bit set.
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let i = *self.index.get(key)?;
+        let (_, v, referenced) = self.slot_mut(i);
+        *referenced = true;
+        Some(LifeTimeGuard::new(ClockCell { t: v }))
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> GetMut<K, V> for ClockCache<K, V> {
+    type Target = ClockMutCell<V>;
+
+    /// Accessed elements have their reference bit set.
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let i = *self.index.get(key)?;
+        let (_, v, referenced) = self.slot_mut(i);
+        *referenced = true;
+        Some(LifeTimeGuard::new(ClockMutCell { t: v }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockCache;
+    use crate::tests::{test_get, test_get_mut};
+
+    #[test]
+    fn get() {
+        test_get(ClockCache::new(0));
+        test_get(ClockCache::new(10));
+        test_get(ClockCache::new(100));
+        test_get_mut(ClockCache::new(0));
+        test_get_mut(ClockCache::new(10));
+        test_get_mut(ClockCache::new(100));
+    }
+}
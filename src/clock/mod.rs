@@ -0,0 +1,9 @@
+#[allow(clippy::module_inception)]
+mod clock;
+pub use clock::ClockCache;
+pub(crate) mod builder;
+mod building_block;
+#[cfg(feature = "config")]
+pub(crate) mod config;
+mod get;
+mod resizable;
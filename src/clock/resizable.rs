@@ -0,0 +1,32 @@
+use super::ClockCache;
+use crate::{BuildingBlock, Resizable};
+use std::hash::Hash;
+
+impl<K: Clone + Eq + Hash, V> Resizable<K, V> for ClockCache<K, V> {
+    /// Change the [`ClockCache`] capacity.
+    ///
+    /// If the elements do not fit in the new capacity, victims are
+    /// evicted with the [`pop()`](trait.BuildingBlock.html#method.pop)
+    /// method.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity;
+        if self.size() > new_capacity {
+            self.pop(self.size() - new_capacity)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockCache;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        test_resizable(ClockCache::new(0));
+        test_resizable(ClockCache::new(10));
+        test_resizable(ClockCache::new(100));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::builder::Build;
+use crate::ClockProCache;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// `ClockProCache` builder.
+///
+/// This builder can be consumed later to spawn a
+/// [`ClockProCache`](../../struct.ClockProCache.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::Build;
+/// use byoc::builder::ClockProBuilder;
+///
+/// let mut container = ClockProBuilder::new(2).build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct ClockProBuilder<K: Clone + Eq + Hash, V> {
+    pub(super) capacity: usize,
+    unused: PhantomData<(K, V)>,
+}
+
+impl<K: Clone + Eq + Hash, V> ClockProBuilder<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        ClockProBuilder {
+            capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Clone for ClockProBuilder<K, V> {
+    fn clone(&self) -> Self {
+        ClockProBuilder {
+            capacity: self.capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Build<ClockProCache<K, V>>
+    for ClockProBuilder<K, V>
+{
+    fn build(self) -> ClockProCache<K, V> {
+        ClockProCache::new(self.capacity)
+    }
+}
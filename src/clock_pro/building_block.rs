@@ -0,0 +1,184 @@
+use super::ClockProCache;
+use crate::BuildingBlock;
+use std::hash::Hash;
+
+impl<K, V> BuildingBlock<K, V> for ClockProCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Get the maximum number of elements fitting in the container.
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of resident elements in the container.
+    ///
+    /// Non-resident keys are not counted.
+    fn size(&self) -> usize {
+        self.hot + self.cold
+    }
+
+    /// Returns whether `key` is resident in the container.
+    ///
+    /// Non-resident keys are not contained in the container.
+    fn contains(&self, key: &K) -> bool {
+        match self.index.get(key) {
+            None => false,
+            Some(&i) => self.page(i).value.is_some(),
+        }
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        match self.index.get(key) {
+            None => false,
+            Some(&i) => {
+                matches!(&self.page(i).value, Some(v) if predicate(v))
+            }
+        }
+    }
+
+    type FlushIterator = std::vec::IntoIter<(K, V)>;
+
+    /// Empty the container and return its resident elements.
+    ///
+    /// Non-resident keys and the adapted cold pages target are reset as
+    /// well.
+    fn flush(&mut self) -> Self::FlushIterator {
+        self.index.clear();
+        self.free.clear();
+        self.hand_hot = None;
+        self.hand_cold = None;
+        self.hand_test = None;
+        self.hot = 0;
+        self.cold = 0;
+        self.non_resident = 0;
+        self.cold_target = self.capacity.min(1);
+
+        self.pages
+            .split_off(0)
+            .into_iter()
+            .flatten()
+            .filter_map(|page| page.value.map(|v| (page.key, v)))
+            .collect::<Vec<(K, V)>>()
+            .into_iter()
+    }
+
+    /// Take a resident element out of the container.
+    ///
+    /// The key of the element is not remembered as a non-resident key
+    /// since it is not evicted.
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        let i = *self.index.get(key)?;
+        let page = self.page(i);
+        page.value.as_ref()?;
+        if page.hot {
+            self.hot -= 1;
+        } else {
+            self.cold -= 1;
+        }
+        let page = self.release(i);
+        Some((page.key, page.value.unwrap()))
+    }
+
+    /// Evict up to `size` elements from the container.
+    ///
+    /// Victims are chosen one after the other by the cold hand of the
+    /// CLOCK-Pro policy.
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        let mut out = Vec::with_capacity(size.min(self.size()));
+        while out.len() < size && self.size() > 0 {
+            out.push(self.run_hand_cold());
+        }
+        out
+    }
+
+    /// Insert key/value pairs in the container.
+    ///
+    /// Elements are inserted one after the other. Elements whose key is
+    /// already resident replace the resident value, which is returned, and
+    /// set the page reference bit. Other elements are inserted following
+    /// the CLOCK-Pro policy and the elements evicted to make room for
+    /// them are returned. If the container capacity is `0`, all `elements`
+    /// are returned.
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for (key, value) in elements {
+            let page = self
+                .index
+                .get(&key)
+                .copied()
+                .map(|i| self.page_mut(i))
+                .filter(|page| page.value.is_some());
+            match page {
+                Some(page) => {
+                    page.referenced = true;
+                    let value = page.value.replace(value).unwrap();
+                    out.push((key, value));
+                }
+                None => out.append(&mut self.insert(key, value)),
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockProCache;
+    use crate::tests::{test_building_block, test_concurrent};
+    use crate::{BuildingBlock, Get, Sequential};
+
+    #[test]
+    fn building_block() {
+        test_building_block(ClockProCache::new(0), true);
+        test_building_block(ClockProCache::new(10), true);
+        test_building_block(ClockProCache::new(100), true);
+    }
+
+    #[test]
+    fn concurrent() {
+        test_concurrent(Sequential::new(ClockProCache::new(0)), 64);
+        test_concurrent(Sequential::new(ClockProCache::new(100)), 64);
+    }
+
+    #[test]
+    fn scan_resistance() {
+        let mut c = ClockProCache::new(4);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+
+        // A scan of keys accessed once does not evict hot pages.
+        for i in 2u16..100u16 {
+            c.push(vec![(i, i as u32)]);
+        }
+        assert!(c.contains(&0u16));
+        assert!(c.contains(&1u16));
+        assert_eq!(c.size(), 4);
+        assert!(c.non_resident <= 4);
+    }
+
+    #[test]
+    fn adaptation() {
+        let mut c = ClockProCache::new(4);
+        c.push(vec![
+            (0u16, 0u32),
+            (1u16, 1u32),
+            (2u16, 2u32),
+            (3u16, 3u32),
+        ]);
+        assert_eq!(c.cold_target(), 1);
+        assert_eq!(c.push(vec![(4u16, 4u32)]), vec![(0u16, 0u32)]);
+
+        // Inserting a key evicted during its test period inserts it as a
+        // hot page and grows the cold pages target.
+        assert_eq!(c.push(vec![(0u16, 0u32)]), vec![(1u16, 1u32)]);
+        assert_eq!(c.cold_target(), 2);
+        assert!(c.contains(&0u16));
+        assert!(c.page(c.index[&0u16]).hot);
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A page of a [`ClockProCache`] container circular list.
+pub(super) struct Page<K, V> {
+    pub(super) key: K,
+    // Value of the page if it is resident.
+    pub(super) value: Option<V>,
+    // Whether the page is a hot page. Cold pages are not hot.
+    pub(super) hot: bool,
+    // Whether a cold page is in its test period.
+    pub(super) test: bool,
+    pub(super) referenced: bool,
+    prev: usize,
+    next: usize,
+}
+
+/// In-memory container with a CLOCK-Pro eviction policy.
+///
+/// [`ClockProCache`] implements the policy described by S. Jiang, F. Chen
+/// and X. Zhang in "CLOCK-Pro: An Effective Improvement of the CLOCK
+/// Replacement". Like in a [`ClockCache`](struct.ClockCache.html)
+/// container, elements (pages) carry a reference bit set when they are
+/// accessed with the [`Get`](trait.Get.html) and
+/// [`GetMut`](trait.GetMut.html) traits.
+/// Pages are kept in a single circular list and split between hot pages,
+/// that were accessed frequently, and cold pages. Three hands sweep the
+/// list:
+/// - The cold hand looks for a resident cold page to evict. Cold pages
+///   that were accessed during their test period are promoted to hot pages
+///   instead, and other accessed cold pages start a new test period.
+/// - The hot hand demotes unaccessed hot pages to cold pages when there
+///   are too many hot pages, and ends the test period of the cold pages it
+///   passes.
+/// - The test hand ends the test period of cold pages and forgets the keys
+///   of evicted pages when there are too many of them.
+///
+/// Evicted cold pages in their test period are remembered without their
+/// value. Inserting one of these keys means that the page was evicted too
+/// early: the key is inserted as a hot page and the target number of
+/// resident cold pages is increased. Conversely, the target number of
+/// resident cold pages is decreased whenever a test period ends without
+/// the page being accessed. As a result, one-time scans of many keys only
+/// go through cold pages and do not flush the frequently accessed hot
+/// pages.
+///
+/// Hands only move forward and each eviction runs in amortized constant
+/// time. The capacity of a [`ClockProCache`] is the number of elements it can
+/// hold. It remembers up to the same number of evicted keys. This
+/// container cannot contain duplicate keys.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{BuildingBlock, ClockProCache, Get};
+///
+/// let mut c = ClockProCache::new(3);
+/// c.push(vec![("first", 1), ("second", 2), ("third", 3)]);
+///
+/// // Accessing "first" protects it from one-time insertions.
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+/// assert_eq!(c.push(vec![("fourth", 4)]), vec![("second", 2)]);
+/// assert_eq!(c.push(vec![("fifth", 5)]), vec![("third", 3)]);
+/// assert!(c.contains(&"first"));
+/// ```
+///
+/// [`ClockProCache`] can also be built from a
+/// [builder pattern](builder/struct.Builder.html#method.clock_pro) and a
+/// [configuration](config/struct.ClockProConfig.html).
+pub struct ClockProCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    // Maximum number of resident pages.
+    pub(super) capacity: usize,
+    // Target number of resident cold pages.
+    pub(super) cold_target: usize,
+    // Storage of the circular list pages.
+    pub(super) pages: Vec<Option<Page<K, V>>>,
+    // Free slots in `pages`.
+    pub(super) free: Vec<usize>,
+    // Position of each key in `pages`.
+    pub(super) index: HashMap<K, usize>,
+    // Hands pointing to pages of the circular list.
+    pub(super) hand_hot: Option<usize>,
+    pub(super) hand_cold: Option<usize>,
+    pub(super) hand_test: Option<usize>,
+    // Number of hot pages.
+    pub(super) hot: usize,
+    // Number of resident cold pages.
+    pub(super) cold: usize,
+    // Number of non-resident cold pages.
+    pub(super) non_resident: usize,
+}
+
+impl<K: Clone + Eq + Hash, V> ClockProCache<K, V> {
+    /// Create a new [`ClockProCache`] container holding up to `capacity`
+    /// elements.
+    pub fn new(capacity: usize) -> Self {
+        ClockProCache {
+            capacity,
+            cold_target: capacity.min(1),
+            pages: Vec::new(),
+            free: Vec::new(),
+            index: HashMap::new(),
+            hand_hot: None,
+            hand_cold: None,
+            hand_test: None,
+            hot: 0,
+            cold: 0,
+            non_resident: 0,
+        }
+    }
+
+    /// Get the current target number of resident cold pages.
+    ///
+    /// This target is adapted online and ranges from `1` to the
+    /// container capacity.
+    pub fn cold_target(&self) -> usize {
+        self.cold_target
+    }
+
+    pub(super) fn page(&self, i: usize) -> &Page<K, V> {
+        self.pages[i].as_ref().unwrap()
+    }
+
+    pub(super) fn page_mut(&mut self, i: usize) -> &mut Page<K, V> {
+        self.pages[i].as_mut().unwrap()
+    }
+
+    /// Insert page `i` at the head of the circular list, i.e right
+    /// behind the hot hand.
+    fn link(&mut self, i: usize) {
+        match self.hand_hot {
+            None => {
+                let page = self.page_mut(i);
+                page.prev = i;
+                page.next = i;
+                self.hand_hot = Some(i);
+                self.hand_cold = Some(i);
+                self.hand_test = Some(i);
+            }
+            Some(next) => {
+                let prev = self.page(next).prev;
+                let page = self.page_mut(i);
+                page.prev = prev;
+                page.next = next;
+                self.page_mut(prev).next = i;
+                self.page_mut(next).prev = i;
+            }
+        }
+    }
+
+    /// Remove page `i` from the circular list. Hands pointing to this page
+    /// move to the next page.
+    fn unlink(&mut self, i: usize) {
+        let (prev, next) = {
+            let page = self.page(i);
+            (page.prev, page.next)
+        };
+        let next = if next == i {
+            None
+        } else {
+            self.page_mut(prev).next = next;
+            self.page_mut(next).prev = prev;
+            Some(next)
+        };
+        for hand in
+            [&mut self.hand_hot, &mut self.hand_cold, &mut self.hand_test]
+        {
+            if *hand == Some(i) {
+                *hand = next;
+            }
+        }
+    }
+
+    fn move_to_head(&mut self, i: usize) {
+        self.unlink(i);
+        self.link(i);
+    }
+
+    /// Remove page `i` from the circular list and from the container.
+    pub(super) fn release(&mut self, i: usize) -> Page<K, V> {
+        self.unlink(i);
+        let page = self.pages[i].take().unwrap();
+        self.free.push(i);
+        self.index.remove(&page.key);
+        page
+    }
+
+    fn decrease_cold_target(&mut self) {
+        self.cold_target =
+            self.cold_target.saturating_sub(1).max(self.capacity.min(1));
+    }
+
+    /// Demote hot pages until there are not more hot pages than
+    /// allowed by the cold pages target.
+    pub(super) fn balance(&mut self) {
+        while self.hot > self.capacity - self.cold_target {
+            self.run_hand_hot();
+        }
+    }
+
+    /// Move the hot hand forward until it demotes one hot page.
+    ///
+    /// Referenced hot pages have their reference bit cleared. Cold pages
+    /// passed by the hand end their test period and non-resident pages are
+    /// forgotten. There must be at least one hot page.
+    fn run_hand_hot(&mut self) {
+        loop {
+            let i = self.hand_hot.unwrap();
+            let next = self.page(i).next;
+            let page = self.page_mut(i);
+            if page.hot {
+                if !page.referenced {
+                    page.hot = false;
+                    self.hot -= 1;
+                    self.cold += 1;
+                    self.hand_hot = Some(next);
+                    return;
+                }
+                page.referenced = false;
+            } else if page.test {
+                page.test = false;
+                let resident = page.value.is_some();
+                self.decrease_cold_target();
+                if !resident {
+                    self.release(i);
+                    self.non_resident -= 1;
+                    continue;
+                }
+            }
+            self.hand_hot = Some(next);
+        }
+    }
+
+    /// Move the test hand forward until it forgets one non-resident page.
+    ///
+    /// Resident cold pages passed by the hand end their test period.
+    /// There must be at least one non-resident page.
+    pub(super) fn run_hand_test(&mut self) {
+        loop {
+            let i = self.hand_test.unwrap();
+            let next = self.page(i).next;
+            let page = self.page_mut(i);
+            if !page.hot && page.test {
+                page.test = false;
+                let resident = page.value.is_some();
+                self.decrease_cold_target();
+                if !resident {
+                    self.release(i);
+                    self.non_resident -= 1;
+                    return;
+                }
+            }
+            self.hand_test = Some(next);
+        }
+    }
+
+    /// Move the cold hand forward until it evicts one resident cold page
+    /// and return the evicted element.
+    ///
+    /// Referenced cold pages in their test period are promoted to hot
+    /// pages and other referenced cold pages start a new test period.
+    /// Evicted pages in their test period are kept as non-resident pages.
+    /// There must be at least one resident page.
+    pub(super) fn run_hand_cold(&mut self) -> (K, V) {
+        loop {
+            if self.cold == 0 {
+                self.run_hand_hot();
+            }
+            let i = self.hand_cold.unwrap();
+            let next = self.page(i).next;
+            let page = self.page_mut(i);
+            if page.hot || page.value.is_none() {
+                self.hand_cold = Some(next);
+            } else if page.referenced {
+                page.referenced = false;
+                if page.test {
+                    page.hot = true;
+                    page.test = false;
+                    self.cold -= 1;
+                    self.hot += 1;
+                    self.move_to_head(i);
+                    self.balance();
+                } else {
+                    page.test = true;
+                    self.move_to_head(i);
+                }
+            } else if page.test {
+                let value = page.value.take().unwrap();
+                let key = page.key.clone();
+                self.cold -= 1;
+                self.non_resident += 1;
+                self.hand_cold = Some(next);
+                while self.non_resident > self.capacity {
+                    self.run_hand_test();
+                }
+                return (key, value);
+            } else {
+                self.cold -= 1;
+                let page = self.release(i);
+                return (page.key, page.value.unwrap());
+            }
+        }
+    }
+
+    /// Insert a key that is not resident in the container.
+    ///
+    /// If the container is full, a resident cold page is evicted first
+    /// and returned. If the container capacity is `0`, the key/value pair
+    /// is returned.
+    pub(super) fn insert(&mut self, key: K, value: V) -> Vec<(K, V)> {
+        if self.capacity == 0 {
+            return vec![(key, value)];
+        }
+
+        let mut out = Vec::new();
+        if self.hot + self.cold >= self.capacity {
+            out.push(self.run_hand_cold());
+        }
+
+        match self.index.get(&key) {
+            // The key was evicted during its test period.
+            Some(&i) => {
+                self.cold_target =
+                    (self.cold_target + 1).min(self.capacity);
+                let page = self.page_mut(i);
+                page.value = Some(value);
+                page.hot = true;
+                page.test = false;
+                page.referenced = false;
+                self.non_resident -= 1;
+                self.hot += 1;
+                self.move_to_head(i);
+                self.balance();
+            }
+            None => {
+                let page = Page {
+                    key: key.clone(),
+                    value: Some(value),
+                    hot: false,
+                    test: true,
+                    referenced: false,
+                    prev: 0,
+                    next: 0,
+                };
+                let i = match self.free.pop() {
+                    Some(i) => {
+                        self.pages[i] = Some(page);
+                        i
+                    }
+                    None => {
+                        self.pages.push(Some(page));
+                        self.pages.len() - 1
+                    }
+                };
+                self.index.insert(key, i);
+                self.link(i);
+                self.cold += 1;
+            }
+        }
+        out
+    }
+}
+
+impl<'a, K, V> From<ClockProCache<K, V>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Clone + Eq + Hash,
+    V: 'a,
+{
+    fn from(container: ClockProCache<K, V>) -> Self {
+        crate::DynBuildingBlock::new_resizable_get(container, false, true)
+    }
+}
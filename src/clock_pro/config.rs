@@ -0,0 +1,124 @@
+use crate::builder::ClockProBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericKey, GenericValue, IntoConfig,
+};
+use crate::objsafe::DynBuildingBlock;
+use crate::ClockProCache;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// Configuration format for [`ClockProCache`](../struct.ClockProCache.html)
+/// containers.
+///
+/// This configuration format is composed of two key/value fields that
+/// must be present:      
+/// - `id = "ClockProConfig"` and
+/// - `capacity = <int>`
+///
+/// The `id` field must be exactly "ClockProConfig" while the capacity
+/// will set the maximum number of key/value pairs that the container can
+/// hold.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id = 'ClockProConfig'
+/// capacity = 10
+/// ");
+/// let container: DynBuildingBlock<u64, u64> =
+///            ConfigBuilder::from_string(config_str.as_str())
+///            .unwrap()
+///            .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct ClockProConfig {
+    #[allow(dead_code)]
+    id: String,
+    capacity: usize,
+}
+
+impl ConfigInstance for ClockProConfig {
+    fn id() -> &'static str {
+        "ClockProConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        toml::from_str(&toml).map_err(|e| {
+            ConfigError::ConfigFormatError(format!(
+                "Invalid ClockProConfig: {}\n{:?}",
+                toml, e
+            ))
+        })
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        DynBuildingBlock::new_resizable_get(
+            ClockProCache::new(self.capacity),
+            false,
+            true,
+        )
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> IntoConfig<ClockProConfig>
+    for ClockProBuilder<K, V>
+{
+    fn as_config(&self) -> ClockProConfig {
+        ClockProConfig {
+            id: String::from(ClockProConfig::id()),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockProConfig;
+    use crate::builder::ClockProBuilder;
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_clock_pro_config() {
+        let capacity = 1008;
+        let config_str =
+            format!("id='ClockProConfig'\ncapacity={}", capacity);
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = ClockProConfig::from_toml(&value).unwrap();
+        assert_eq!(config.capacity, capacity);
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_invalid_clock_pro_config() {
+        let config_str = "id='ClockProConfig'\ncapacity='ten'".to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            ClockProConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder = ClockProBuilder::<(), ()>::new(2);
+        test_config_builder(builder);
+    }
+}
@@ -0,0 +1,90 @@
+use super::ClockProCache;
+use crate::utils::get::LifeTimeGuard;
+use crate::{Get, GetMut};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+
+/// Read-only cell representing a reference to a value inside an
+/// [`ClockProCache`](struct.ClockProCache.html) container.
+pub struct ClockProCell<V> {
+    t: *const V,
+}
+
+impl<V> Deref for ClockProCell<V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // The cell is wrapped in a `LifeTimeGuard` borrowing the container
+        // mutably. Therefore, the container cannot be modified while this
+        // cell is alive.
+        unsafe { self.t.as_ref().unwrap() }
+    }
+}
+
+/// Read-write cell holding a reference to a value inside an
+/// [`ClockProCache`](struct.ClockProCache.html) container.
+pub struct ClockProMutCell<V> {
+    t: *mut V,
+}
+
+impl<V> Deref for ClockProMutCell<V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // See `ClockProCell` Deref trait method.
+        unsafe { self.t.as_ref().unwrap() }
+    }
+}
+
+impl<V> DerefMut for ClockProMutCell<V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // See `ClockProCell` Deref trait method.
+        unsafe { self.t.as_mut().unwrap() }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Get<K, V> for ClockProCache<K, V> {
+    type Target = ClockProCell<V>;
+
+    /// Accessed elements have their reference bit set.
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let i = *self.index.get(key)?;
+        let page = self.page_mut(i);
+        let v = page.value.as_ref()?;
+        page.referenced = true;
+        Some(LifeTimeGuard::new(ClockProCell { t: v }))
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> GetMut<K, V> for ClockProCache<K, V> {
+    type Target = ClockProMutCell<V>;
+
+    /// Accessed elements have their reference bit set.
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let i = *self.index.get(key)?;
+        let page = self.page_mut(i);
+        let v = page.value.as_mut()?;
+        page.referenced = true;
+        Some(LifeTimeGuard::new(ClockProMutCell { t: v }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockProCache;
+    use crate::tests::{test_get, test_get_mut};
+
+    #[test]
+    fn get() {
+        test_get(ClockProCache::new(0));
+        test_get(ClockProCache::new(10));
+        test_get(ClockProCache::new(100));
+        test_get_mut(ClockProCache::new(0));
+        test_get_mut(ClockProCache::new(10));
+        test_get_mut(ClockProCache::new(100));
+    }
+}
@@ -0,0 +1,9 @@
+#[allow(clippy::module_inception)]
+mod clock_pro;
+pub use clock_pro::ClockProCache;
+pub(crate) mod builder;
+mod building_block;
+#[cfg(feature = "config")]
+pub(crate) mod config;
+mod get;
+mod resizable;
@@ -0,0 +1,40 @@
+use super::ClockProCache;
+use crate::{BuildingBlock, Resizable};
+use std::hash::Hash;
+
+impl<K: Clone + Eq + Hash, V> Resizable<K, V> for ClockProCache<K, V> {
+    /// Change the [`ClockProCache`] capacity.
+    ///
+    /// If the resident elements do not fit in the new capacity, victims are
+    /// evicted with the [`pop()`](trait.BuildingBlock.html#method.pop)
+    /// method. Non-resident keys are forgotten until they fit the new
+    /// capacity as well.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        let out = if self.size() > new_capacity {
+            self.pop(self.size() - new_capacity)
+        } else {
+            Vec::new()
+        };
+        self.capacity = new_capacity;
+        self.cold_target =
+            self.cold_target.min(new_capacity).max(new_capacity.min(1));
+        while self.non_resident > new_capacity {
+            self.run_hand_test();
+        }
+        self.balance();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClockProCache;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        test_resizable(ClockProCache::new(0));
+        test_resizable(ClockProCache::new(10));
+        test_resizable(ClockProCache::new(100));
+    }
+}
@@ -4,6 +4,76 @@ use crate::utils::size::find_cut_at_size;
 use crate::BuildingBlock;
 use serde::{de::DeserializeOwned, Serialize};
 
+/// Merge the `values` to insert in a [`Compressed`] container with the
+/// elements `vec` of the container.
+///
+/// After this function returns, `vec` contains the elements to write in
+/// the container and the returned vector contains the elements that do not
+/// fit in the container.
+///
+/// `room` is the space left in the container and `total_size` is the
+/// serialized size of `values`.
+pub(super) fn split_push<K, V>(
+    capacity: u64,
+    room: u64,
+    vec: &mut Vec<(K, V)>,
+    mut values: Vec<(K, V)>,
+    total_size: u64,
+) -> Vec<(K, V)>
+where
+    K: Serialize,
+    V: Serialize + Ord,
+{
+    // The closure to get the size of an element in the input vector.
+    let get_serialized_size =
+        |kv: &(K, V)| match bincode::serialized_size(&kv) {
+            Ok(s) => s as usize,
+            Err(_) => 0usize,
+        };
+
+    // Here we create the vector to write in the container stream and the
+    // vector to return.
+    if total_size > capacity {
+        // If there is more values to insert than the container capacity:
+        // We remove the highest values from the values to insert.
+        values.sort_unstable_by(|(_, v1), (_, v2)| v1.cmp(v2));
+        let (cut, _, _) = find_cut_at_size(
+            &values,
+            get_serialized_size,
+            (total_size - capacity) as usize,
+        );
+        vec.append(&mut values.split_off(cut));
+        // We will add the new truncated values and will return the old
+        // values with the right hand side of the truncateture from
+        // the new values to insert.
+        std::mem::swap(vec, &mut values);
+        values
+    } else if total_size <= room {
+        // If there is enough room for the new values, we add them to
+        // the old one and we will insert the combination of both and
+        // return an empty vector.
+        vec.append(&mut values);
+        Vec::new()
+    } else {
+        // If none of the previous branches are taken, we need to evict
+        // some old values to make more rooms for the new ones.
+        // First we sort the old values.
+        vec.sort_unstable_by(|(_, v1), (_, v2)| v1.cmp(v2));
+        // Then we find the cut to leave just enough room for the new
+        // values.
+        let (cut, _, _) = find_cut_at_size(
+            &*vec,
+            get_serialized_size,
+            (total_size - room) as usize,
+        );
+
+        // We will insert the new values and return the evicted values.
+        let victims = vec.split_off(cut);
+        vec.append(&mut values);
+        victims
+    }
+}
+
 impl<K, V, S> BuildingBlock<K, V> for Compressed<(K, V), S>
 where
     K: Serialize + DeserializeOwned + Ord,
@@ -42,6 +112,24 @@ where
         v.iter().any(|(k, _)| k == key)
     }
 
+    /// Check if container contains a matching key which value satisfies
+    /// `predicate`.
+    ///
+    /// This method unpacks the vector of key/values in memory and iterate it
+    /// one by one to find a matching key.
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        let v: Vec<(K, V)> = match self.read() {
+            Err(_) => return false,
+            Ok(v) => v,
+        };
+
+        v.iter().any(|(k, v)| k == key && predicate(v))
+    }
+
     /// Take the matching key/value pair out of the container.
     ///
     /// This method unpacks the vector of key/values in memory and iterates it
@@ -226,7 +314,7 @@ where
     /// At the end, the vector of inserted values is serialized then compressed
     /// and written to the underlying
     /// [`Stream`](utils/stream/trait.Stream.html).
-    fn push(&mut self, mut values: Vec<(K, V)>) -> Vec<(K, V)> {
+    fn push(&mut self, values: Vec<(K, V)>) -> Vec<(K, V)> {
         // Read and decompress bytes from the stream.
         let bytes = match self.read_bytes() {
             Err(_) => return values,
@@ -250,63 +338,13 @@ where
         };
         // The room for insertion in the container.
         let room = self.capacity - (bytes.len() as u64);
-        // The closure to get the size of an element in the input vector.
-        let get_serialized_size =
-            |kv: &(K, V)| match bincode::serialized_size(&kv) {
-                Ok(s) => s as usize,
-                Err(_) => 0usize,
-            };
-
-        // Here we create the vector to write in the container stream and the
-        // vector to return.
-        //
-        // It does not make sense to create a type here to remove the
-        // "type_complexity"
-        #[allow(clippy::type_complexity)]
-        let (in_vec, out_vec): (Vec<(K, V)>, Vec<(K, V)>) =
-            if total_size > self.capacity {
-                // If there is more values to insert than the container capacity:
-                // We remove the highest values from the values to insert.
-                values.sort_unstable_by(|(_, v1), (_, v2)| v1.cmp(v2));
-                let (cut, _, _) = find_cut_at_size(
-                    &values,
-                    get_serialized_size,
-                    (total_size - self.capacity) as usize,
-                );
-                vec.append(&mut values.split_off(cut));
-                // We will add the new truncated values and will return the old
-                // values with the right hand side of the truncateture from
-                // the new values to insert.
-                (values, vec)
-            } else if total_size <= room {
-                // If there is enough room for the new values, we add them to
-                // the old one and we will insert the combination of both and
-                // return an empty vector.
-                vec.append(&mut values);
-                (vec, Vec::new())
-            } else {
-                // If none of the previous branches are taken, we need to evict
-                // some old values to make more rooms for the new ones.
-                // First we sort the old values.
-                vec.sort_unstable_by(|(_, v1), (_, v2)| v1.cmp(v2));
-                // Then we find the cut to leave just enough room for the new
-                // values.
-                let (cut, _, _) = find_cut_at_size(
-                    &vec,
-                    get_serialized_size,
-                    (total_size - room) as usize,
-                );
-
-                // We will insert the new values and return the evicted values.
-                let victims = vec.split_off(cut);
-                vec.append(&mut values);
-                (vec, victims)
-            };
+        let out =
+            split_push(self.capacity, room, &mut vec, values, total_size);
 
         // Write new vector to stream and return not inserted keys.
-        self.write(&in_vec)
+        self.write(&vec)
             .expect("An error occurred while rewriting the stream");
-        out_vec
+        out
     }
 
     type FlushIterator = std::vec::IntoIter<(K, V)>;
@@ -197,6 +197,6 @@ where
     S: 'a + Stream,
 {
     fn from(compressed: Compressed<(K, V), S>) -> Self {
-        crate::DynBuildingBlock::new(compressed, false)
+        crate::DynBuildingBlock::new_resizable_get(compressed, false, true)
     }
 }
@@ -100,7 +100,7 @@ impl ConfigInstance for CompressedConfig {
             Some(s) => {
                 let container =
                     Compressed::new(FileStream::from(&s), self.capacity);
-                DynBuildingBlock::new(container, false)
+                DynBuildingBlock::new_resizable_get(container, false, true)
             }
             None => {
                 #[cfg(feature = "tempfile")]
@@ -108,7 +108,9 @@ impl ConfigInstance for CompressedConfig {
                     let mut factory = TempFileStreamFactory {};
                     let container =
                         Compressed::new(factory.create(), self.capacity);
-                    DynBuildingBlock::new(container, false)
+                    DynBuildingBlock::new_resizable_get(
+                        container, false, true,
+                    )
                 }
                 #[cfg(not(feature = "tempfile"))]
                 {
@@ -118,6 +120,14 @@ impl ConfigInstance for CompressedConfig {
             }
         }
     }
+
+    fn has_get_trait(&self) -> bool {
+        self.filename.is_some() || cfg!(feature = "tempfile")
+    }
+
+    fn is_resizable(&self) -> bool {
+        self.has_get_trait()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,42 @@
+use super::Compressed;
+use crate::stream::Stream;
+use crate::Iter;
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, S> Iter<K, V> for Compressed<(K, V), S>
+where
+    K: Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+    S: Stream,
+{
+    type Iterator<'a>
+        = std::vec::IntoIter<(K, V)>
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of the container.
+    ///
+    /// All the elements are decompressed in memory before being iterated.
+    /// If the elements cannot be read from the underlying stream, the
+    /// returned iterator is empty.
+    fn iter(&self) -> Self::Iterator<'_> {
+        match self.read() {
+            Err(_) => Vec::new().into_iter(),
+            Ok(v) => v.into_iter(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Compressed;
+    use crate::stream::VecStream;
+    use crate::tests::test_iter;
+
+    #[test]
+    fn iter() {
+        for i in [0usize, 10usize, 100usize] {
+            test_iter(Compressed::new(VecStream::new(), i));
+        }
+    }
+}
@@ -3,6 +3,9 @@ mod compression;
 pub use compression::Compressed;
 mod building_block;
 mod get;
+mod iter;
+mod resizable;
+mod try_building_block;
 pub use get::{CompressedCell, CompressedMutCell};
 pub(crate) mod builder;
 #[cfg(feature = "config")]
@@ -0,0 +1,40 @@
+use super::Compressed;
+use crate::stream::Stream;
+use crate::{BuildingBlock, Resizable};
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, S> Resizable<K, V> for Compressed<(K, V), S>
+where
+    K: Serialize + DeserializeOwned + Ord,
+    V: Serialize + DeserializeOwned + Ord,
+    S: Stream,
+{
+    /// Change the [`Compressed`] container capacity.
+    ///
+    /// If the serialized elements of the container do not fit in the new
+    /// capacity, the elements with the greatest values are popped out until
+    /// they do.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity as u64;
+        let size = self.size();
+        if size > new_capacity {
+            self.pop(size - new_capacity)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Compressed;
+    use crate::stream::VecStream;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        for i in [0usize, 10usize, 100usize] {
+            test_resizable(Compressed::new(VecStream::new(), i));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::building_block::split_push;
+use super::Compressed;
+use crate::stream::{IOError, Stream};
+use crate::utils::size::find_cut_at_size;
+use crate::{Error, TryBuildingBlock};
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, S> TryBuildingBlock<K, V> for Compressed<(K, V), S>
+where
+    K: Serialize + DeserializeOwned + Ord,
+    V: Serialize + DeserializeOwned + Ord,
+    S: Stream,
+{
+    fn try_size(&self) -> Result<usize, Error> {
+        Ok(self.read_bytes()?.len())
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        Ok(self.read()?.iter().any(|(k, _)| k == key))
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        let mut v = self.read()?;
+        let ret = match v.iter().position(|(k, _)| k == key) {
+            None => return Ok(None),
+            Some(i) => v.swap_remove(i),
+        };
+        self.write(&v)?;
+        Ok(Some(ret))
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let mut v = self.read()?;
+        let mut out = Vec::with_capacity(keys.len());
+        keys.sort();
+
+        // See `take_multiple()` for why this collect is needed.
+        #[allow(clippy::needless_collect)]
+        let matches: Vec<(usize, usize)> = v
+            .iter()
+            .enumerate()
+            .filter_map(|(container_i, (k, _))| {
+                keys.binary_search(k)
+                    .ok()
+                    .map(|keys_i| (container_i, keys_i))
+            })
+            .collect();
+
+        let mut matched_keys: Vec<usize> = matches
+            .into_iter()
+            .rev()
+            .map(|(container_i, keys_i)| {
+                out.push(v.swap_remove(container_i));
+                keys_i
+            })
+            .collect();
+
+        if !out.is_empty() {
+            self.write(&v)?;
+        }
+
+        matched_keys.sort();
+        for key_i in matched_keys.into_iter().rev() {
+            keys.swap_remove(key_i);
+        }
+        Ok(out)
+    }
+
+    fn try_pop(&mut self, size: usize) -> Result<Vec<(K, V)>, Error> {
+        let mut v = self.read()?;
+        if v.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        v.sort_by(|(k_a, v_a), (k_b, v_b)| {
+            (v_a, k_a).partial_cmp(&(v_b, k_b)).unwrap()
+        });
+        let (split, split_size, _) = find_cut_at_size(
+            &v,
+            |e| match bincode::serialized_size(e) {
+                Err(_) => 0usize,
+                Ok(s) => s as usize,
+            },
+            size,
+        );
+
+        let out = if split_size < size {
+            std::mem::take(&mut v)
+        } else {
+            v.split_off(split)
+        };
+        self.write(&v)?;
+        Ok(out)
+    }
+
+    fn try_push(
+        &mut self,
+        values: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let bytes = self.read_bytes()?;
+        let mut vec = if !bytes.is_empty() {
+            bincode::deserialize_from(bytes.as_slice())
+                .map_err(IOError::Deserialize)?
+        } else {
+            Vec::new()
+        };
+        let total_size = bincode::serialized_size(&values)
+            .map_err(IOError::Serialize)?;
+        let room = self.capacity - (bytes.len() as u64);
+        let out =
+            split_push(self.capacity, room, &mut vec, values, total_size);
+        self.write(&vec)?;
+        Ok(out)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        let v = self.read()?;
+        self.stream.resize(0).map_err(IOError::Seek)?;
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Compressed;
+    use crate::stream::VecStream;
+    use crate::tests::test_try_building_block;
+
+    #[test]
+    fn try_building_block() {
+        for i in [0usize, 10usize, 100usize] {
+            test_try_building_block(
+                Compressed::new(VecStream::new(), i),
+                true,
+            );
+        }
+    }
+}
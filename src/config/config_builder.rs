@@ -1,12 +1,12 @@
 use super::{
     ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
 };
-use crate::decorator::config::DecorationType;
-use crate::decorator::{Fifo, Lrfu, Lru};
+use crate::decorator::config::{DecorationType, DecoratorConfig};
+use crate::decorator::{Fifo, Lfu, Lrfu, Lru, Ttl};
 use crate::objsafe::DynBuildingBlock;
 use crate::utils::timestamp::Counter;
-use crate::Decorator;
 use serde::Serialize;
+use std::time::Duration;
 use toml;
 
 /// `BuildingBlock` builder from a generic configuration.
@@ -101,14 +101,21 @@ use toml;
 /// to allow to set a decorator a single time at the top level of the container
 /// configuration.
 ///
-/// At the moment only three policies are supported via a key/value attribute
+/// At the moment only five policies are supported via a key/value attribute
 /// at the top of the configuration:
 /// * `decorator.kind='Fifo'` the [`Fifo`](../decorator/struct.Fifo.html) policy,
 /// * `decorator.kind='Lru'` the [`Lru`](../decorator/struct.Lru.html) policy,
+/// * `decorator.kind='Lfu'` the [`Lfu`](../decorator/struct.Lfu.html) policy,
 /// * `decorator.kind='Lrfu'` the [`Lrfu`](../decorator/struct.Lrfu.html) policy,
+/// * `decorator.kind='Ttl'` the [`Ttl`](../decorator/struct.Ttl.html) policy,
 ///
-/// where the `exponent` attribute `Lrfu` last policy can be configured with
-/// an additional key/value attribute: `decorator.exponent=<value>`.
+/// where the `exponent` attribute `Lrfu` policy can be configured with
+/// an additional key/value attribute: `decorator.exponent=<value>`, and
+/// where the time to live in seconds of the `Ttl` policy must be set with
+/// the same attribute, as a non-negative number.
+///
+/// The same attribute optionally sets the period after which the access
+/// counts of the `Lfu` policy are halved, as a positive integer.
 ///
 /// ## Examples
 ///
@@ -173,6 +180,13 @@ impl ConfigInstance for ConfigBuilder {
 		Some(toml::value::Value::String(s)) => match s.as_ref() {
 		"Fifo" => DecorationType::Fifo,
 		"Lru" => DecorationType::Lru,
+		"Lfu" => {
+		    match t.get("exponent") {
+			None => DecorationType::Lfu(None),
+			Some(&toml::value::Value::Integer(i)) if i > 0 => DecorationType::Lfu(Some(i as u64)),
+			_ => return Err(ConfigError::ConfigFormatError(format!("Invalid aging exponent for decorator {}. Must be a positive integer.", s)))
+		    }
+		},
 		"Lrfu" => {
 		    match table.get("decorator.Lrfu.exponent") {
 			None => DecorationType::Lrfu(1.0),
@@ -180,7 +194,13 @@ impl ConfigInstance for ConfigBuilder {
 			_ => return Err(ConfigError::ConfigFormatError(format!("Invalid exponent format for decorator {},", s)))
 		    }
 		},
-		_ => return Err(ConfigError::ConfigFormatError(format!("Invalid decorator.kind value {}. Must be one of: Fifo, Lru, Lrfu", s))),
+		"Ttl" => {
+		    match t.get("exponent") {
+			Some(&toml::value::Value::Float(f)) if f.is_finite() && f >= 0.0 => DecorationType::Ttl(f as f32),
+			_ => return Err(ConfigError::ConfigFormatError(format!("Invalid or missing time to live exponent for decorator {},", s)))
+		    }
+		},
+		_ => return Err(ConfigError::ConfigFormatError(format!("Invalid decorator.kind value {}. Must be one of: Fifo, Lru, Lfu, Lrfu, Ttl", s))),
 		},
 	    _ => return Err(ConfigError::ConfigFormatError(String::from("Invalid decorator attribute. Must be 'decorator.kind' or 'decorator.Lrfu'."))),
             },
@@ -200,22 +220,46 @@ impl ConfigInstance for ConfigBuilder {
         self,
     ) -> DynBuildingBlock<'a, K, V> {
         let has_concurrent_trait = self.is_concurrent();
+        let is_resizable = self.is_resizable();
         match self.decorator {
             DecorationType::None => self.config.build(),
-            DecorationType::Fifo => DynBuildingBlock::new(
-                Decorator::new(self.config.build(), Fifo::new()),
+            DecorationType::Fifo => DecoratorConfig::build_with(
+                self.config.build(),
+                Fifo::new(),
                 has_concurrent_trait,
+                is_resizable,
             ),
-            DecorationType::Lru => DynBuildingBlock::new(
-                Decorator::new(self.config.build(), Lru::<Counter>::new()),
+            DecorationType::Lru => DecoratorConfig::build_with(
+                self.config.build(),
+                Lru::<Counter>::new(),
                 has_concurrent_trait,
+                is_resizable,
             ),
-            DecorationType::Lrfu(e) => DynBuildingBlock::new(
-                Decorator::new(
+            DecorationType::Lfu(aging) => {
+                let lfu = match aging {
+                    None => Lfu::<Counter>::new(),
+                    Some(period) => {
+                        Lfu::<Counter>::new().with_aging(period)
+                    }
+                };
+                DecoratorConfig::build_with(
                     self.config.build(),
-                    Lrfu::<Counter>::new(e),
-                ),
+                    lfu,
+                    has_concurrent_trait,
+                    is_resizable,
+                )
+            }
+            DecorationType::Lrfu(e) => DecoratorConfig::build_with(
+                self.config.build(),
+                Lrfu::<Counter>::new(e),
                 has_concurrent_trait,
+                is_resizable,
+            ),
+            DecorationType::Ttl(ttl) => DecoratorConfig::build_with(
+                self.config.build(),
+                Ttl::new(Fifo::new(), Duration::from_secs_f32(ttl)),
+                has_concurrent_trait,
+                is_resizable,
             ),
         }
     }
@@ -223,13 +267,21 @@ impl ConfigInstance for ConfigBuilder {
     fn is_concurrent(&self) -> bool {
         self.config.has_concurrent_trait
     }
+
+    fn has_get_trait(&self) -> bool {
+        self.config.has_get_trait
+    }
+
+    fn is_resizable(&self) -> bool {
+        self.config.is_resizable
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::config::{ConfigBuilder, ConfigError, ConfigInstance};
     use crate::objsafe::{DynBuildingBlock, DynConcurrent};
-    use crate::tests::test_concurrent;
+    use crate::tests::{test_concurrent, test_get, test_get_mut};
     use crate::BuildingBlock;
 
     #[test]
@@ -291,6 +343,53 @@ decorator.Lrfu.exponent=0.5
         assert_eq!(array.capacity(), capacity);
     }
 
+    #[test]
+    fn test_lfu_config() {
+        let capacity = 10;
+        let config_str = format!(
+            "
+id='ArrayConfig'
+capacity={}
+decorator.kind='Lfu'
+decorator.exponent=1000
+",
+            capacity
+        );
+        let array: DynBuildingBlock<u64, u64> =
+            ConfigBuilder::from_string(config_str.as_str())
+                .unwrap()
+                .build();
+        assert_eq!(array.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_invalid_ttl_config() {
+        let config_str = "
+id='ArrayConfig'
+capacity=10
+decorator.kind='Ttl'
+decorator.exponent=-1.0
+";
+        assert!(matches!(
+            ConfigBuilder::from_string(config_str),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_lfu_config() {
+        let config_str = "
+id='ArrayConfig'
+capacity=10
+decorator.kind='Lfu'
+decorator.exponent=0
+";
+        assert!(matches!(
+            ConfigBuilder::from_string(config_str),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
     #[test]
     fn test_invalid_concurrent() {
         let capacity = 10;
@@ -332,4 +431,28 @@ capacity={}
         assert_eq!(container.capacity(), capacity);
         test_concurrent(container, 64);
     }
+
+    #[test]
+    fn test_get_config() {
+        let config_str = "
+id='ExclusiveConfig'
+[front]
+id='ArrayConfig'
+capacity=10
+[back]
+id='BTreeConfig'
+capacity=10
+[decorator]
+kind='Lru'
+";
+        let config = ConfigBuilder::from_string(config_str).unwrap();
+        assert!(config.has_get_trait());
+        let container: DynBuildingBlock<u16, u32> = config.build();
+        assert!(container.has_get_trait());
+        test_get(container.into_get().ok().unwrap());
+
+        let container: DynBuildingBlock<u16, u32> =
+            ConfigBuilder::from_string(config_str).unwrap().build();
+        test_get_mut(container.into_get().ok().unwrap());
+    }
 }
@@ -1,21 +1,29 @@
 use super::{ConfigError, ConfigInstance, GenericKey, GenericValue};
 
+use crate::arc::config::ArcCacheConfig;
 use crate::array::config::ArrayConfig;
 use crate::associative::config::AssociativeConfig;
 use crate::batch::config::BatchConfig;
 use crate::btree::config::BTreeConfig;
+use crate::clock::config::ClockConfig;
+use crate::clock_pro::config::ClockProConfig;
 #[cfg(feature = "compression")]
 use crate::compression::config::CompressedConfig;
 use crate::exclusive::config::ExclusiveConfig;
 use crate::flush_stopper::config::FlushStopperConfig;
+use crate::hashmap::config::HashMapConfig;
+use crate::hierarchy::config::HierarchyConfig;
 use crate::objsafe::DynBuildingBlock;
 // use crate::inclusive::config::InclusiveConfig;
+use crate::loader::config::LoaderConfig;
 use crate::profiler::config::ProfilerConfig;
 use crate::sequential::config::SequentialConfig;
+use crate::slru::config::SlruConfig;
 #[cfg(feature = "socket")]
 use crate::socket::config::SocketClientConfig;
 #[cfg(feature = "stream")]
 use crate::stream::config::StreamConfig;
+use crate::tinylfu::config::TinyLfuAdmissionConfig;
 
 use serde::Serialize;
 use toml;
@@ -24,6 +32,8 @@ use toml;
 #[derive(Clone, Serialize)]
 pub(crate) struct GenericConfig {
     pub has_concurrent_trait: bool,
+    pub has_get_trait: bool,
+    pub is_resizable: bool,
     toml_config: toml::Value,
 }
 
@@ -41,6 +51,8 @@ impl GenericConfig {
         let toml_value = v.clone();
         C::from_toml(&v).map(move |cfg| GenericConfig {
             has_concurrent_trait: cfg.is_concurrent(),
+            has_get_trait: cfg.has_get_trait(),
+            is_resizable: cfg.is_resizable(),
             toml_config: toml_value,
         })
     }
@@ -86,12 +98,15 @@ impl ConfigInstance for GenericConfig {
         // Check id field is a valid id and if it is, try to build the
         // associated config.
         match id.as_str() {
+            "ArcCacheConfig" => Self::from_config::<ArcCacheConfig>(value),
             "ArrayConfig" => Self::from_config::<ArrayConfig>(value),
             "AssociativeConfig" => {
                 Self::from_config::<AssociativeConfig>(value)
             }
             "BatchConfig" => Self::from_config::<BatchConfig>(value),
             "BTreeConfig" => Self::from_config::<BTreeConfig>(value),
+            "ClockConfig" => Self::from_config::<ClockConfig>(value),
+            "ClockProConfig" => Self::from_config::<ClockProConfig>(value),
             #[cfg(feature = "compression")]
             "CompressedConfig" => {
                 Self::from_config::<CompressedConfig>(value)
@@ -102,19 +117,28 @@ impl ConfigInstance for GenericConfig {
             "FlushStopperConfig" => {
                 Self::from_config::<FlushStopperConfig>(value)
             }
+            "HashMapConfig" => Self::from_config::<HashMapConfig>(value),
+            "HierarchyConfig" => {
+                Self::from_config::<HierarchyConfig>(value)
+            }
             // "InclusiveConfig" => {
             //     Self::from_config::<InclusiveConfig>(value)
             // }
+            "LoaderConfig" => Self::from_config::<LoaderConfig>(value),
             "ProfilerConfig" => Self::from_config::<ProfilerConfig>(value),
             "SequentialConfig" => {
                 Self::from_config::<SequentialConfig>(value)
             }
+            "SlruConfig" => Self::from_config::<SlruConfig>(value),
             #[cfg(feature = "socket")]
             "SocketClientConfig" => {
                 Self::from_config::<SocketClientConfig>(value)
             }
             #[cfg(feature = "stream")]
             "StreamConfig" => Self::from_config::<StreamConfig>(value),
+            "TinyLfuAdmissionConfig" => {
+                Self::from_config::<TinyLfuAdmissionConfig>(value)
+            }
             unknown => Err(ConfigError::ConfigFormatError(format!(
                 "Invalid container configuration type: {}",
                 unknown
@@ -138,6 +162,11 @@ impl ConfigInstance for GenericConfig {
             .unwrap();
 
         match id {
+            "ArcCacheConfig" => {
+                Self::into_config::<ArcCacheConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
             "ArrayConfig" => {
                 Self::into_config::<ArrayConfig>(&self.toml_config)
                     .unwrap()
@@ -158,6 +187,16 @@ impl ConfigInstance for GenericConfig {
                     .unwrap()
                     .build()
             }
+            "ClockConfig" => {
+                Self::into_config::<ClockConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
+            "ClockProConfig" => {
+                Self::into_config::<ClockProConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
             #[cfg(feature = "compression")]
             "CompressedConfig" => {
                 Self::into_config::<CompressedConfig>(&self.toml_config)
@@ -174,11 +213,26 @@ impl ConfigInstance for GenericConfig {
                     .unwrap()
                     .build()
             }
+            "HashMapConfig" => {
+                Self::into_config::<HashMapConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
+            "HierarchyConfig" => {
+                Self::into_config::<HierarchyConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
             // "InclusiveConfig" => {
             //     Self::into_config::<InclusiveConfig>(&self.toml_config)
             //         .unwrap()
             //         .build()
             // }
+            "LoaderConfig" => {
+                Self::into_config::<LoaderConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
             "ProfilerConfig" => {
                 Self::into_config::<ProfilerConfig>(&self.toml_config)
                     .unwrap()
@@ -189,6 +243,11 @@ impl ConfigInstance for GenericConfig {
                     .unwrap()
                     .build()
             }
+            "SlruConfig" => {
+                Self::into_config::<SlruConfig>(&self.toml_config)
+                    .unwrap()
+                    .build()
+            }
             #[cfg(feature = "socket")]
             "SocketClientConfig" => {
                 Self::into_config::<SocketClientConfig>(&self.toml_config)
@@ -201,9 +260,25 @@ impl ConfigInstance for GenericConfig {
                     .unwrap()
                     .build()
             }
+            "TinyLfuAdmissionConfig" => Self::into_config::<
+                TinyLfuAdmissionConfig,
+            >(&self.toml_config)
+            .unwrap()
+            .build(),
             unknown => {
                 panic!("Invalid container configuration type: {}", unknown)
             }
         }
     }
+    fn is_concurrent(&self) -> bool {
+        self.has_concurrent_trait
+    }
+
+    fn has_get_trait(&self) -> bool {
+        self.has_get_trait
+    }
+
+    fn is_resizable(&self) -> bool {
+        self.is_resizable
+    }
 }
@@ -37,6 +37,11 @@
 //! detected at runtime and the former struct can be turned respectively into a
 //! [`DynConcurrent`](../struct.DynConcurrent.html)
 //! [`BuildingBlock`](../trait.BuildingBlock.html)
+//!
+//! Similarly, if it implements the [`Get`](../trait.Get.html) and
+//! [`GetMut`](../trait.GetMut.html) traits, it can be turned into a
+//! [`DynGetBuildingBlock`](../struct.DynGetBuildingBlock.html) with its
+//! [`into_get()`](../struct.DynBuildingBlock.html#method.into_get) method.
 
 use serde::{de::DeserializeOwned, Serialize};
 use std::cmp::Ord;
@@ -56,11 +61,11 @@ use toml;
 /// requirements of a container, the key has to satisfy at least all of the
 /// containers keys trait bounds.
 pub trait GenericKey:
-    Ord + Copy + Hash + Serialize + DeserializeOwned + Clone
+    Ord + Copy + Hash + Serialize + DeserializeOwned + Clone + Send
 {
 }
-impl<T: Ord + Copy + Hash + Serialize + DeserializeOwned + Clone>
-    GenericKey for T
+impl<T> GenericKey for T where
+    T: Ord + Copy + Hash + Serialize + DeserializeOwned + Clone + Send
 {
 }
 
@@ -76,10 +81,13 @@ impl<T: Ord + Copy + Hash + Serialize + DeserializeOwned + Clone>
 /// requirements of a container, the value has to satisfy at least all of the
 /// containers keys trait bounds.
 pub trait GenericValue:
-    Ord + Serialize + DeserializeOwned + Clone
+    Ord + Serialize + DeserializeOwned + Clone + Send
+{
+}
+impl<T: Ord + Serialize + DeserializeOwned + Clone + Send> GenericValue
+    for T
 {
 }
-impl<T: Ord + Serialize + DeserializeOwned + Clone> GenericValue for T {}
 
 /// Trait to create configuration instances from a `&str`, a [`std::fs::File`],
 /// or a [`toml::Value`].
@@ -160,6 +168,21 @@ where
     fn is_concurrent(&self) -> bool {
         false
     }
+
+    /// Return whether this configuration represents a
+    /// [`BuildingBlock`](../trait.BuildingBlock.html) that implements the
+    /// [`Get`](../trait.Get.html) and [`GetMut`](../trait.GetMut.html)
+    /// traits.
+    fn has_get_trait(&self) -> bool {
+        false
+    }
+
+    /// Return whether this configuration represents a
+    /// [`BuildingBlock`](../trait.BuildingBlock.html) that can be resized
+    /// with the [`Resizable`](../trait.Resizable.html) trait.
+    fn is_resizable(&self) -> bool {
+        false
+    }
 }
 
 /// Convert an object into [`ConfigInstance`].
@@ -189,22 +212,30 @@ pub(crate) use generic_config::GenericConfig;
 
 /// The collection of available configurations.
 pub mod configs {
+    pub use crate::arc::config::ArcCacheConfig;
     pub use crate::array::config::ArrayConfig;
     pub use crate::associative::config::AssociativeConfig;
     pub use crate::batch::config::BatchConfig;
     pub use crate::btree::config::BTreeConfig;
+    pub use crate::clock::config::ClockConfig;
+    pub use crate::clock_pro::config::ClockProConfig;
     #[cfg(feature = "compression")]
     pub use crate::compression::config::CompressedConfig;
     pub use crate::exclusive::config::ExclusiveConfig;
+    pub use crate::hashmap::config::HashMapConfig;
+    pub use crate::hierarchy::config::HierarchyConfig;
     // pub use crate::inclusive::config::InclusiveConfig;
+    pub use crate::loader::config::LoaderConfig;
     pub use crate::profiler::config::ProfilerConfig;
     pub use crate::sequential::config::SequentialConfig;
+    pub use crate::slru::config::SlruConfig;
     #[cfg(feature = "socket")]
     pub use crate::socket::config::{
         SocketClientConfig, SocketServerConfig,
     };
     #[cfg(feature = "stream")]
     pub use crate::stream::config::StreamConfig;
+    pub use crate::tinylfu::config::TinyLfuAdmissionConfig;
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use super::decorator::{unwrap_evicted, unwrap_unexpired};
 use super::{Decoration, DecorationFactory};
 use crate::BuildingBlock;
 use crate::Decorator;
@@ -27,10 +28,21 @@ where
     /// Check if container contains a matching key.
     ///
     /// This calls and returns the value of the decorated container
-    /// [`contains()`](trait.BuildingBlock.html#method.contains)
-    /// method.
+    /// [`contains_if()`](trait.BuildingBlock.html#tymethod.contains_if)
+    /// method such that expired values are considered absent.
     fn contains(&self, key: &K) -> bool {
-        self.container.contains(key)
+        self.container
+            .contains_if(key, &|d: &F::Item| !d.is_expired())
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, &|d: &F::Item| {
+            !d.is_expired() && predicate(d.peek())
+        })
     }
 
     /// Take the matching key/value pair out of the container.
@@ -38,9 +50,10 @@ where
     /// This calls and returns the value of the decorated container
     /// [`take()`](trait.BuildingBlock.html#method.take)
     /// method and will remove the decoration from the taken value before
-    /// returning it.
+    /// returning it. If the taken value expired, it is dropped and `None`
+    /// is returned.
     fn take(&mut self, key: &K) -> Option<(K, V)> {
-        self.container.take(key).map(|(k, r)| (k, r.unwrap()))
+        self.container.take(key).and_then(unwrap_unexpired)
     }
 
     /// Take multiple keys out of a container at once.
@@ -48,27 +61,54 @@ where
     /// This calls and returns the value of the decorated container
     /// [`take_multiple()`](trait.BuildingBlock.html#method.take_multiple)
     /// method and will remove the decoration from the taken values before
-    /// returning them.
+    /// returning them. Expired values are dropped.
     fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
         self.container
             .take_multiple(keys)
             .into_iter()
-            .map(|(k, r)| (k, r.unwrap()))
+            .filter_map(unwrap_unexpired)
             .collect()
     }
 
     /// Free up to `size` space from the container.
     ///
-    /// This calls and returns the value of the decorated container
-    /// [`pop()`](trait.BuildingBlock.html#method.pop)
-    /// method on decorated values. It will remove the decoration from the
-    /// evicted values before returning them.
+    /// If the decoration may expire, expired values are first taken out of
+    /// the decorated container and dropped: the container is flushed and
+    /// its unexpired values are pushed back. If this does not free `n`
+    /// space, this calls and returns the value of the decorated container
+    /// [`pop()`](trait.BuildingBlock.html#method.pop) method to free the
+    /// remaining space in the decorated container order. It will notify the
+    /// decoration of the evicted values and remove it before returning
+    /// them.
     fn pop(&mut self, n: usize) -> Vec<(K, V)> {
-        self.container
-            .pop(n)
-            .into_iter()
-            .map(|(k, r)| (k, r.unwrap()))
-            .collect()
+        let mut out = Vec::new();
+        let mut n = n;
+        if F::Item::EXPIRES {
+            let size = self.container.size();
+            let elements = self
+                .container
+                .flush()
+                .filter(|(_, d)| !d.is_expired())
+                .collect();
+            out.extend(
+                self.container
+                    .push(elements)
+                    .into_iter()
+                    .filter_map(unwrap_evicted),
+            );
+            n = n.saturating_sub(
+                size.saturating_sub(self.container.size()),
+            );
+        }
+        if n > 0 {
+            out.extend(
+                self.container
+                    .pop(n)
+                    .into_iter()
+                    .filter_map(unwrap_evicted),
+            );
+        }
+        out
     }
 
     /// Insert key/value pairs in the container.
@@ -76,42 +116,61 @@ where
     /// This calls and returns the value of the decorated container
     /// [`push()`](trait.BuildingBlock.html#method.push)
     /// method. Inserted values will be decorated by the container before
-    /// insertion.
+    /// insertion. Expired values coming out of the container are dropped.
+    ///
+    /// Values replaced by the inserted values, i.e the returned values
+    /// whose key is still in the decorated container, are not notified
+    /// as evicted. Only the decoration of the elements evicted by the
+    /// container is.
     fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
-        let (container, factory) =
-            (&mut self.container, &mut self.factory);
-        container
-            .push(
-                elements
-                    .into_iter()
-                    .map(|(k, v)| (k, factory.wrap(v)))
-                    .collect(),
-            )
+        let factory = &mut self.factory;
+        let elements = elements
             .into_iter()
-            .map(|(k, r)| (k, r.unwrap()))
+            .map(|(k, v)| (k, factory.wrap(v)))
+            .collect();
+        let out = self.container.push(elements);
+        let container = &self.container;
+        out.into_iter()
+            .filter_map(|(k, d)| {
+                if container.contains(&k) {
+                    unwrap_unexpired((k, d))
+                } else {
+                    unwrap_evicted((k, d))
+                }
+            })
             .collect()
     }
 
-    type FlushIterator =
-        std::iter::Map<C::FlushIterator, fn((K, F::Item)) -> (K, V)>;
+    type FlushIterator = std::iter::FilterMap<
+        C::FlushIterator,
+        fn((K, F::Item)) -> Option<(K, V)>,
+    >;
 
     /// Empty the container and retrieve all of its elements.
     ///
     /// This calls and returns the value of the decorated container
     /// [`flush()`](trait.BuildingBlock.html#method.flush)
     /// method and will remove the decoration from the flushed value to return
-    /// on each iteration.
+    /// on each iteration. Expired values are dropped.
     fn flush(&mut self) -> Self::FlushIterator {
-        self.container.flush().map(|(k, r)| (k, r.unwrap()))
+        self.container.flush().filter_map(unwrap_unexpired)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Decorator;
-    use crate::decorator::Default;
+    use crate::decorator::{
+        Default, Fifo, Gdsf, Lfu, Policy, Random, Ttl,
+    };
     use crate::tests::test_building_block;
-    use crate::Array;
+    use crate::utils::timestamp::{Clock, Counter, Timestamp};
+    use crate::{
+        ArcCache, Array, BTree, BuildingBlock, ClockCache, ClockProCache,
+        DynBuildingBlock, Get, HashMapContainer, Inclusive, Slru,
+        TinyLfuAdmission,
+    };
+    use std::time::Duration;
 
     #[test]
     fn building_block() {
@@ -122,4 +181,286 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn building_block_lfu() {
+        for i in [0usize, 10usize, 100usize] {
+            test_building_block(
+                Decorator::new(Array::new(i), Lfu::<Counter>::new()),
+                true,
+            );
+            test_building_block(
+                Decorator::new(
+                    Array::new(i),
+                    Lfu::<Counter>::new().with_aging(10),
+                ),
+                true,
+            );
+        }
+    }
+
+    #[test]
+    fn lfu_eviction() {
+        let mut c = Decorator::new(Array::new(3), Lfu::<Counter>::new());
+        c.push(vec![(0u16, 0u32), (1u16, 1u32), (2u16, 2u32)]);
+        for _ in 0..2 {
+            assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        }
+        assert_eq!(*c.get(&2u16).unwrap(), 2u32);
+
+        // Least frequently used elements are evicted first.
+        assert_eq!(c.pop(1), vec![(1u16, 1u32)]);
+        assert_eq!(c.pop(1), vec![(2u16, 2u32)]);
+        assert_eq!(c.pop(1), vec![(0u16, 0u32)]);
+    }
+
+    #[test]
+    fn building_block_gdsf() {
+        for i in [0usize, 10usize, 100usize] {
+            test_building_block(
+                Decorator::new(
+                    Array::new(i),
+                    Gdsf::new(|v: &u32| *v as usize),
+                ),
+                true,
+            );
+        }
+    }
+
+    #[test]
+    fn gdsf_eviction() {
+        let mut c = Decorator::new(
+            Array::new(3),
+            Gdsf::new(|v: &u32| *v as usize),
+        );
+        c.push(vec![(0u16, 1u32), (1u16, 100u32), (2u16, 100u32)]);
+        for _ in 0..150 {
+            assert_eq!(*c.get(&2u16).unwrap(), 100u32);
+        }
+
+        // Large and rarely used elements are evicted first.
+        assert_eq!(c.pop(1), vec![(1u16, 100u32)]);
+        assert_eq!(c.pop(1), vec![(0u16, 1u32)]);
+
+        // The inflation value is now the priority of the last victim.
+        // Newly inserted elements rank before elements that were not
+        // accessed since.
+        c.push(vec![(3u16, 1u32)]);
+        assert_eq!(c.pop(1), vec![(2u16, 100u32)]);
+    }
+
+    #[test]
+    fn gdsf_replace() {
+        let gdsf = Gdsf::new(|v: &u32| *v as usize);
+        let mut c = Decorator::new(BTree::new(2), gdsf.clone());
+        c.push(vec![(0u16, 1u32)]);
+
+        // Replaced values are returned without raising the inflation.
+        assert_eq!(c.push(vec![(0u16, 2u32)]), vec![(0u16, 1u32)]);
+        assert_eq!(gdsf.inflation(), 0.0);
+        assert_eq!(c.pop(1), vec![(0u16, 2u32)]);
+        assert_eq!(gdsf.inflation(), 0.5);
+    }
+
+    #[test]
+    fn building_block_policy() {
+        for i in [0usize, 10usize, 100usize] {
+            test_building_block(
+                Decorator::new(
+                    Array::new(i),
+                    Policy::new(
+                        |v: &u32| *v,
+                        |_: &mut u32| {},
+                        |m: &u32| *m,
+                    ),
+                ),
+                true,
+            );
+        }
+    }
+
+    #[test]
+    fn policy_eviction() {
+        // Least frequently used policy.
+        let lfu = Policy::new(
+            |_: &u32| 0u64,
+            |count: &mut u64| *count += 1,
+            |count: &u64| std::cmp::Reverse(*count),
+        );
+        let mut c = Decorator::new(Array::new(3), lfu);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32), (2u16, 2u32)]);
+        for _ in 0..2 {
+            assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        }
+        assert_eq!(*c.get(&2u16).unwrap(), 2u32);
+
+        // Least frequently used elements are evicted first.
+        assert_eq!(c.pop(1), vec![(1u16, 1u32)]);
+        assert_eq!(c.pop(1), vec![(2u16, 2u32)]);
+        assert_eq!(c.pop(1), vec![(0u16, 0u32)]);
+
+        // Policies can be built at runtime behind a dynamic container.
+        let largest =
+            Policy::new(|v: &u32| *v, |_: &mut u32| {}, |v: &u32| *v);
+        let mut c: DynBuildingBlock<u16, u32> =
+            Decorator::new(Array::new(3), largest).into();
+        c.push(vec![(0u16, 4u32), (1u16, 9u32), (2u16, 1u32)]);
+        assert_eq!(c.pop(1), vec![(1u16, 9u32)]);
+        assert_eq!(c.pop(1), vec![(0u16, 4u32)]);
+    }
+
+    #[test]
+    fn building_block_random() {
+        for i in [0usize, 10usize, 100usize] {
+            test_building_block(
+                Decorator::new(Array::new(i), Random::with_seed(i as u64)),
+                true,
+            );
+        }
+    }
+
+    #[test]
+    fn building_block_ttl() {
+        for i in [0usize, 10usize, 100usize] {
+            test_building_block(
+                Decorator::new(
+                    Array::new(i),
+                    Ttl::new(Default {}, Duration::from_secs(60)),
+                ),
+                true,
+            );
+        }
+    }
+
+    #[test]
+    fn ttl_expiration() {
+        let ttl = Duration::from_secs(60);
+        let mut c =
+            Decorator::new(Array::new(4), Ttl::new(Fifo::new(), ttl));
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        Clock::advance(ttl);
+        c.push(vec![(2u16, 2u32), (3u16, 3u32)]);
+
+        // Expired values are absent.
+        assert!(c.get(&0u16).is_none());
+        assert!(!c.contains(&0u16));
+        assert!(c.take(&1u16).is_none());
+        assert_eq!(c.size(), 2);
+
+        // Expired values are evicted first, although the wrapped
+        // decoration orders the largest value first.
+        let mut c =
+            Decorator::new(Array::new(3), Ttl::new(Default {}, ttl));
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        Clock::advance(ttl);
+        c.push(vec![(4u16, 9u32)]);
+        assert!(c.pop(1).is_empty());
+        assert_eq!(c.size(), 1);
+        assert_eq!(c.pop(1), vec![(4u16, 9u32)]);
+        assert_eq!(c.size(), 0);
+    }
+
+    #[test]
+    fn ttl_pop_expired_first() {
+        let ttl = Duration::from_secs(60);
+        let mut c =
+            Decorator::new(Array::new(5), Ttl::new(Default {}, ttl));
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        Clock::advance(ttl);
+        c.push(vec![(2u16, 5u32), (3u16, 3u32), (4u16, 4u32)]);
+
+        // Expired values free two slots and the remaining slot is freed
+        // in the wrapped decoration order.
+        assert_eq!(c.pop(3), vec![(2u16, 5u32)]);
+        assert_eq!(c.size(), 2);
+        assert!(c.contains(&3u16));
+        assert!(c.contains(&4u16));
+    }
+
+    #[test]
+    fn contains_if_does_not_touch() {
+        let mut c = Decorator::new(Array::new(2), Lfu::<Counter>::new());
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+
+        // Membership probes are not accesses.
+        for _ in 0..4 {
+            assert!(c.contains(&0u16));
+            assert!(c.contains_if(&0u16, &|v| *v == 0u32));
+        }
+        assert_eq!(c.pop(1), vec![(0u16, 0u32)]);
+    }
+
+    fn ttl_contains<C>(container: C)
+    where
+        Decorator<C, u32, Ttl<Fifo>>: BuildingBlock<u16, u32>,
+    {
+        let ttl = Duration::from_secs(60);
+        let mut c = Decorator::new(container, Ttl::new(Fifo::new(), ttl));
+        assert!(c.push(vec![(0u16, 0u32)]).is_empty());
+        assert!(c.contains(&0u16));
+        Clock::advance(ttl);
+        assert!(!c.contains(&0u16));
+    }
+
+    #[test]
+    fn ttl_contains_in_memory_containers() {
+        ttl_contains(ClockCache::new(4));
+        ttl_contains(ClockProCache::new(4));
+        ttl_contains(ArcCache::new(4));
+        ttl_contains(Slru::new(Array::new(4), Array::new(4)));
+        ttl_contains(TinyLfuAdmission::new(Array::new(4)));
+        ttl_contains(Inclusive::new(Array::new(2), Array::new(4)));
+    }
+
+    #[test]
+    fn lfu_aging_ordered_containers() {
+        let lfu = Lfu::<Counter>::new().with_aging(10);
+        let mut btree = Decorator::new(BTree::new(4), lfu.clone());
+        let mut hashmap = Decorator::new(HashMapContainer::new(4), lfu);
+
+        // The order of values does not change while time passes.
+        btree.push(vec![(1u16, 1u32), (2u16, 2u32)]);
+        hashmap.push(vec![(1u16, 1u32), (2u16, 2u32)]);
+        for _ in 0..5 {
+            assert_eq!(*btree.get(&1u16).unwrap(), 1u32);
+            assert_eq!(*hashmap.get(&1u16).unwrap(), 1u32);
+        }
+        for _ in 0..1000 {
+            Counter::now();
+        }
+        assert_eq!(btree.take(&2u16), Some((2u16, 2u32)));
+        assert_eq!(hashmap.take(&2u16), Some((2u16, 2u32)));
+        assert_eq!(btree.take(&1u16), Some((1u16, 1u32)));
+        assert_eq!(hashmap.take(&1u16), Some((1u16, 1u32)));
+    }
+
+    #[test]
+    fn ttl_ordered_containers() {
+        let ttl = Duration::from_secs(60);
+        let mut btree = Decorator::new(
+            BTree::new(4),
+            Ttl::new(Lfu::<Counter>::new(), ttl),
+        );
+        let mut hashmap = Decorator::new(
+            HashMapContainer::new(4),
+            Ttl::new(Lfu::<Counter>::new(), ttl),
+        );
+
+        // The order of values does not change when some of them expire.
+        btree.push(vec![(1u16, 1u32)]);
+        hashmap.push(vec![(1u16, 1u32)]);
+        for _ in 0..5 {
+            assert_eq!(*btree.get(&1u16).unwrap(), 1u32);
+            assert_eq!(*hashmap.get(&1u16).unwrap(), 1u32);
+        }
+        Clock::advance(ttl / 2);
+        btree.push(vec![(2u16, 2u32)]);
+        hashmap.push(vec![(2u16, 2u32)]);
+        Clock::advance(ttl / 2);
+        assert_eq!(btree.take(&2u16), Some((2u16, 2u32)));
+        assert_eq!(hashmap.take(&2u16), Some((2u16, 2u32)));
+        assert!(btree.take(&1u16).is_none());
+        assert!(hashmap.take(&1u16).is_none());
+    }
 }
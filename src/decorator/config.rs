@@ -3,18 +3,21 @@ use crate::config::{
     ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
     IntoConfig,
 };
-use crate::decorator::{Fifo, Lrfu, Lru};
+use crate::decorator::{DecorationFactory, Fifo, Lfu, Lrfu, Lru, Ttl};
 use crate::objsafe::DynBuildingBlock;
 use crate::utils::timestamp::{Counter, Timestamp};
 use crate::Decorator;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Deserialize, Serialize, Copy, Clone)]
 #[serde(tag = "kind", content = "exponent")]
 pub enum DecorationType {
     Lrfu(f32),
     Lru,
+    Lfu(Option<u64>),
     Fifo,
+    Ttl(f32),
     None,
 }
 
@@ -44,6 +47,15 @@ impl Default for DecorationType {
 /// * `decorator.exponent` field that sets the floating point value for the
 /// [`Lrfu`](../decorator/struct.Lrfu.html) decorator.
 ///
+/// The `decorator.exponent` field also sets the optional aging period of
+/// the [`Lfu`](../decorator/struct.Lfu.html) decorator, which must be a
+/// positive integer. Without it, access counts are never aged.
+///
+/// The `decorator.exponent` field also sets the time to live in seconds of
+/// the values decorated with the [`Ttl`](../decorator/struct.Ttl.html)
+/// decorator. Values that did not expire yet are ordered with the
+/// [`Fifo`](../decorator/struct.Fifo.html) decorator.
+///
 /// Below is an example of the configuration of a
 /// [`Decorator`](../struct.Decorator.html) wrapping an
 /// [`Array`](../struct.Array.html) container.
@@ -87,6 +99,35 @@ impl DecoratorConfig {
             container,
         }
     }
+
+    /// Decorate `container` with the decorations made by `factory`.
+    ///
+    /// The values of the decorated container can be accessed in place if
+    /// the values of `container` can.
+    pub(crate) fn build_with<'a, K, V, F>(
+        container: DynBuildingBlock<'a, K, F::Item>,
+        factory: F,
+        has_concurrent_trait: bool,
+        is_resizable: bool,
+    ) -> DynBuildingBlock<'a, K, V>
+    where
+        K: 'a + GenericKey,
+        V: 'a,
+        F: 'a + DecorationFactory<V> + Clone + Send + Sync,
+    {
+        match container.into_get() {
+            Ok(container) => DynBuildingBlock::new_resizable_get(
+                Decorator::new(container, factory),
+                has_concurrent_trait,
+                is_resizable,
+            ),
+            Err(container) => DynBuildingBlock::new_resizable(
+                Decorator::new(container, factory),
+                has_concurrent_trait,
+                is_resizable,
+            ),
+        }
+    }
 }
 
 impl<C, V, B, T> IntoConfig<DecoratorConfig>
@@ -116,6 +157,21 @@ where
     }
 }
 
+impl<C, V, B, T> IntoConfig<DecoratorConfig>
+    for DecoratorBuilder<C, V, Lfu<T>, B>
+where
+    C: ConfigInstance,
+    B: IntoConfig<C>,
+    T: Timestamp,
+{
+    fn as_config(&self) -> DecoratorConfig {
+        DecoratorConfig::from_builder(
+            &self.builder,
+            DecorationType::Lfu(self.decorator.aging()),
+        )
+    }
+}
+
 impl<C, V, B> IntoConfig<DecoratorConfig>
     for DecoratorBuilder<C, V, Fifo, B>
 where
@@ -127,6 +183,20 @@ where
     }
 }
 
+impl<C, V, B> IntoConfig<DecoratorConfig>
+    for DecoratorBuilder<C, V, Ttl<Fifo>, B>
+where
+    C: ConfigInstance,
+    B: IntoConfig<C>,
+{
+    fn as_config(&self) -> DecoratorConfig {
+        DecoratorConfig::from_builder(
+            &self.builder,
+            DecorationType::Ttl(self.decorator.ttl().as_secs_f32()),
+        )
+    }
+}
+
 impl ConfigInstance for DecoratorConfig {
     fn id() -> &'static str {
         "DecoratorConfig"
@@ -134,7 +204,22 @@ impl ConfigInstance for DecoratorConfig {
 
     fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
         let toml = toml::to_string(&value).unwrap();
-        toml::from_str(&toml).map_err(ConfigError::TomlFormatError)
+        let config: Self =
+            toml::from_str(&toml).map_err(ConfigError::TomlFormatError)?;
+        match config.decorator {
+            DecorationType::Ttl(ttl) if !(ttl.is_finite() && ttl >= 0.0) => {
+                Err(ConfigError::ConfigFormatError(format!(
+                    "Invalid time to live {} for decorator Ttl. Must be a non-negative number of seconds.",
+                    ttl
+                )))
+            }
+            DecorationType::Lfu(Some(0)) => {
+                Err(ConfigError::ConfigFormatError(String::from(
+                    "Invalid aging period 0 for decorator Lfu. Must be a positive integer.",
+                )))
+            }
+            _ => Ok(config),
+        }
     }
 
     fn is_concurrent(&self) -> bool {
@@ -146,39 +231,63 @@ impl ConfigInstance for DecoratorConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
+        let is_concurrent = self.is_concurrent();
+        let is_resizable = self.is_resizable();
+        let container = GenericConfig::from_toml(&self.container).unwrap();
         match self.decorator {
-            DecorationType::Lrfu(exponent) => DynBuildingBlock::new(
-                Decorator::new(
-                    GenericConfig::from_toml(&self.container)
-                        .unwrap()
-                        .build(),
-                    Lrfu::<Counter>::new(exponent),
-                ),
-                self.is_concurrent(),
-            ),
-            DecorationType::Lru => DynBuildingBlock::new(
-                Decorator::new(
-                    GenericConfig::from_toml(&self.container)
-                        .unwrap()
-                        .build(),
-                    Lru::<Counter>::new(),
-                ),
-                self.is_concurrent(),
+            DecorationType::Lrfu(exponent) => Self::build_with(
+                container.build(),
+                Lrfu::<Counter>::new(exponent),
+                is_concurrent,
+                is_resizable,
             ),
-            DecorationType::Fifo => DynBuildingBlock::new(
-                Decorator::new(
-                    GenericConfig::from_toml(&self.container)
-                        .unwrap()
-                        .build(),
-                    Fifo::new(),
-                ),
-                self.is_concurrent(),
+            DecorationType::Lru => Self::build_with(
+                container.build(),
+                Lru::<Counter>::new(),
+                is_concurrent,
+                is_resizable,
             ),
-            DecorationType::None => {
-                GenericConfig::from_toml(&self.container).unwrap().build()
+            DecorationType::Lfu(aging) => {
+                let lfu = match aging {
+                    None => Lfu::<Counter>::new(),
+                    Some(period) => {
+                        Lfu::<Counter>::new().with_aging(period)
+                    }
+                };
+                Self::build_with(
+                    container.build(),
+                    lfu,
+                    is_concurrent,
+                    is_resizable,
+                )
             }
+            DecorationType::Fifo => Self::build_with(
+                container.build(),
+                Fifo::new(),
+                is_concurrent,
+                is_resizable,
+            ),
+            DecorationType::Ttl(ttl) => Self::build_with(
+                container.build(),
+                Ttl::new(Fifo::new(), Duration::from_secs_f32(ttl)),
+                is_concurrent,
+                is_resizable,
+            ),
+            DecorationType::None => container.build(),
         }
     }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .is_resizable()
+    }
 }
 
 #[cfg(test)]
@@ -187,9 +296,11 @@ mod tests {
     use crate::builder::{ArrayBuilder, DecoratorBuilder};
     use crate::config::tests::test_config_builder;
     use crate::config::{ConfigError, ConfigInstance};
-    use crate::decorator::Fifo;
+    use crate::decorator::{Fifo, Lfu, Ttl};
     use crate::objsafe::DynBuildingBlock;
+    use crate::utils::timestamp::Counter;
     use crate::BuildingBlock;
+    use std::time::Duration;
 
     #[test]
     fn test_valid_decorator_config() {
@@ -239,4 +350,88 @@ capacity=10
         );
         test_config_builder(builder);
     }
+
+    #[test]
+    fn test_lfu_decorator_config() {
+        for decorator in ["", "decorator.exponent=100\n"] {
+            let config_str = format!(
+                "
+id='DecoratorConfig'
+decorator.kind='Lfu'
+{}[container]
+id='ArrayConfig'
+capacity=10
+",
+                decorator
+            );
+            let value: toml::Value =
+                toml::from_str(config_str.as_str()).unwrap();
+            let config = DecoratorConfig::from_toml(&value).unwrap();
+            let container: DynBuildingBlock<u64, u64> = config.build();
+            assert_eq!(container.capacity(), 10);
+        }
+
+        let builder = DecoratorBuilder::<_, (), _, _>::new(
+            ArrayBuilder::<()>::new(2),
+            Lfu::<Counter>::new().with_aging(100),
+        );
+        test_config_builder(builder);
+    }
+
+    #[test]
+    fn test_ttl_decorator_config() {
+        let config_str = "
+id='DecoratorConfig'
+decorator.kind='Ttl'
+decorator.exponent=60.0
+[container]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = DecoratorConfig::from_toml(&value).unwrap();
+        let mut container: DynBuildingBlock<u64, u64> = config.build();
+        assert!(container.push(vec![(0, 0)]).is_empty());
+        assert_eq!(container.take(&0), Some((0, 0)));
+
+        let builder = DecoratorBuilder::<_, (), _, _>::new(
+            ArrayBuilder::<()>::new(2),
+            Ttl::new(Fifo::new(), Duration::from_secs(60)),
+        );
+        test_config_builder(builder);
+    }
+
+    #[test]
+    fn test_invalid_ttl_decorator_config() {
+        let config_str = "
+id='DecoratorConfig'
+decorator.kind='Ttl'
+decorator.exponent=-1.0
+[container]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        assert!(matches!(
+            DecoratorConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_lfu_decorator_config() {
+        let config_str = "
+id='DecoratorConfig'
+decorator.kind='Lfu'
+decorator.exponent=0
+[container]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        assert!(matches!(
+            DecoratorConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
 }
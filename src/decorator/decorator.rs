@@ -1,4 +1,4 @@
-use crate::decorator::DecorationFactory;
+use crate::decorator::{Decoration, DecorationFactory};
 use std::marker::PhantomData;
 
 //------------------------------------------------------------------------//
@@ -27,6 +27,19 @@ use std::marker::PhantomData;
 /// decoration cells may provide a specific implementation of values order,
 /// therefore dictating the eviction policy.
 ///
+/// Decorations may also
+/// [expire](utils/decorator/trait.Decoration.html#method.is_expired), e.g
+/// with a [`Ttl`](utils/decorator/struct.Ttl.html) decoration. Expired values
+/// are considered absent from the container: they are dropped instead of
+/// being returned when they are taken, popped, flushed, or accessed with
+/// [`Get`](trait.Get.html) and [`GetMut`](trait.GetMut.html) traits.
+/// [`contains()`](trait.BuildingBlock.html#tymethod.contains) looks at
+/// the decorated values with the decorated container
+/// [`contains_if()`](trait.BuildingBlock.html#tymethod.contains_if) method
+/// and does not report expired values.
+/// [`pop()`](trait.BuildingBlock.html#tymethod.pop) takes expired values
+/// out before evicting unexpired values.
+///
 /// ## Examples
 ///
 /// See [`decorator`](utils/decorator/index.html) module for examples.
@@ -52,6 +65,30 @@ where
         }
     }
 }
+/// Remove the decoration of a key/value pair unless the decoration expired.
+pub(super) fn unwrap_unexpired<K, V, D: Decoration<V>>(
+    (k, d): (K, D),
+) -> Option<(K, V)> {
+    if d.is_expired() {
+        None
+    } else {
+        Some((k, d.unwrap()))
+    }
+}
+
+/// Remove the decoration of an evicted key/value pair unless the
+/// decoration expired.
+pub(super) fn unwrap_evicted<K, V, D: Decoration<V>>(
+    (k, d): (K, D),
+) -> Option<(K, V)> {
+    if d.is_expired() {
+        None
+    } else {
+        d.evicted();
+        Some((k, d.unwrap()))
+    }
+}
+
 impl<C, V, F> Clone for Decorator<C, V, F>
 where
     F: DecorationFactory<V> + Clone,
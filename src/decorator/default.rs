@@ -40,6 +40,9 @@ impl<V: Ord> Decoration<V> for DefaultCell<V> {
     fn get_mut(&mut self) -> &mut V {
         &mut self.value
     }
+    fn peek(&self) -> &V {
+        &self.value
+    }
 }
 
 #[derive(Clone)]
@@ -0,0 +1,80 @@
+use super::get::DecoratorCell;
+use super::{Decoration, DecorationFactory};
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, Decorator, GetEntry};
+
+impl<K, V, C, F> GetEntry<K, V> for Decorator<C, V, F>
+where
+    K: Ord,
+    C: GetEntry<K, F::Item> + BuildingBlock<K, F::Item>,
+    F: DecorationFactory<V>,
+{
+    type Target = DecoratorCell<V, F::Item, C::Target>;
+
+    /// Look up the key in the decorated container. Expired values are
+    /// taken out of the container and dropped, and their entry is vacant.
+    /// Vacant entries are decorated and inserted with the [`Decorator`]
+    /// [`push()`](trait.BuildingBlock.html#method.push) method.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        let found = match self.container.entry(key) {
+            Entry::Occupied(e) => {
+                let (key, value) = e.into_parts();
+                Ok((key, value.unwrap()))
+            }
+            Entry::Vacant(e) => Err(e.into_key()),
+        };
+
+        let key = match found {
+            Ok((key, value)) if !value.is_expired() => {
+                return Entry::Occupied(OccupiedEntry::new(
+                    key,
+                    LifeTimeGuard::new(DecoratorCell::new(value)),
+                ));
+            }
+            Ok((key, value)) => {
+                // The cell must be released before taking its value out.
+                drop(value);
+                self.container.take(&key);
+                key
+            }
+            Err(key) => key,
+        };
+
+        Entry::Vacant(VacantEntry::new(
+            key,
+            Box::new(move |k, v| self.push(vec![(k, v)])),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decorator;
+    use crate::decorator::{Default, Ttl};
+    use crate::tests::test_entry;
+    use crate::utils::entry::Entry;
+    use crate::utils::timestamp::Clock;
+    use crate::{Array, BuildingBlock, GetEntry};
+    use std::time::Duration;
+
+    #[test]
+    fn entry() {
+        for i in [0usize, 10usize, 100usize] {
+            test_entry(Decorator::new(Array::new(i), Default {}));
+        }
+    }
+
+    #[test]
+    fn entry_expired() {
+        let ttl = Duration::from_secs(60);
+        let mut c =
+            Decorator::new(Array::new(2), Ttl::new(Default {}, ttl));
+        assert!(c.push(vec![(0u16, 0u32)]).is_empty());
+        assert!(matches!(c.entry(0u16), Entry::Occupied(_)));
+
+        Clock::advance(ttl);
+        assert!(matches!(c.entry(0u16), Entry::Vacant(_)));
+        assert_eq!(c.size(), 0);
+    }
+}
@@ -114,6 +114,9 @@ impl<V> Decoration<V> for FifoCell<V> {
     fn get_mut(&mut self) -> &mut V {
         &mut self.value
     }
+    fn peek(&self) -> &V {
+        &self.value
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,226 @@
+use crate::decorator::{Decoration, DecorationFactory};
+use std::cell::Cell;
+use std::cmp::{Ord, Ordering};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+
+/// Implementation of [`Decoration`](trait.Decoration.html)
+/// with a Greedy-Dual-Size-Frequency (Gdsf) eviction policy.
+///
+/// See [`Gdsf`](struct.Gdsf.html)
+#[derive(Debug)]
+pub struct GdsfCell<V> {
+    value: V,
+    /// Function computing the size of the value.
+    size_of: fn(&V) -> usize,
+    /// Size of the value.
+    size: Cell<f64>,
+    /// Whether the value was accessed mutably since its size was computed.
+    resized: Cell<bool>,
+    /// Number of accesses, including insertion.
+    count: Cell<u64>,
+    /// Inflation value at the time of the last access.
+    base: Cell<f64>,
+    /// Inflation value shared with the factory.
+    inflation: Arc<AtomicU64>,
+}
+
+/// Decoration implementation of Greedy-Dual-Size-Frequency ordering.
+///
+/// `Gdsf` wraps values into cells implementing Gdsf ordering policy.
+/// The purpose of this policy is to keep in the cache the small and
+/// frequently used elements while the large and rarely used ones are
+/// elected for eviction.
+///
+/// Each cell is assigned a priority `L + count / size` where `count` is
+/// the number of accesses to the value, including its insertion, `size` is
+/// the value size computed with a user provided function, and `L` is an
+/// inflation value at the time of the last access. The size of a value is
+/// computed again after the value is accessed mutably. Values with the lowest
+/// priority are evicted first. The inflation value is raised to the
+/// priority of every evicted value, such that the priority of values that
+/// are not accessed anymore eventually falls behind the priority of newly
+/// inserted values.
+///
+/// Clones of a [`Gdsf`] policy share the same inflation value.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Decorator};
+/// use byoc::utils::decorator::Gdsf;
+///
+/// let mut c = Decorator::new(Array::new(2),
+///                            Gdsf::new(|v: &Vec<u8>| v.len()));
+/// c.push(vec![("small", vec![0u8; 10]), ("large", vec![0u8; 1000])]);
+///
+/// // "large" is the largest element and none was accessed.
+/// assert_eq!(c.pop(1).pop().unwrap().0, "large");
+/// ```
+pub struct Gdsf<V> {
+    size: fn(&V) -> usize,
+    inflation: Arc<AtomicU64>,
+}
+
+impl<V> Clone for Gdsf<V> {
+    fn clone(&self) -> Self {
+        Gdsf {
+            size: self.size,
+            inflation: self.inflation.clone(),
+        }
+    }
+}
+
+impl<V> Gdsf<V> {
+    /// Construct a Gdsf references factory computing values size with
+    /// the `size` function.
+    ///
+    /// Sizes smaller than `1` are counted as `1`.
+    pub fn new(size: fn(&V) -> usize) -> Self {
+        Gdsf {
+            size,
+            inflation: Arc::new(AtomicU64::new(0f64.to_bits())),
+        }
+    }
+
+    /// Get the current inflation value of this [`Gdsf`] policy.
+    pub fn inflation(&self) -> f64 {
+        f64::from_bits(self.inflation.load(AtomicOrdering::Relaxed))
+    }
+}
+
+impl<V> DecorationFactory<V> for Gdsf<V> {
+    type Item = GdsfCell<V>;
+    fn wrap(&mut self, v: V) -> Self::Item {
+        let size = GdsfCell::size_of(self.size, &v);
+        GdsfCell {
+            value: v,
+            size_of: self.size,
+            size: Cell::new(size),
+            resized: Cell::new(false),
+            count: Cell::new(1),
+            base: Cell::new(self.inflation()),
+            inflation: self.inflation.clone(),
+        }
+    }
+}
+
+impl<V> GdsfCell<V> {
+    /// Get the priority of this cell.
+    pub fn priority(&self) -> f64 {
+        if self.resized.replace(false) {
+            self.size.set(Self::size_of(self.size_of, &self.value));
+        }
+        self.base.get() + self.count.get() as f64 / self.size.get()
+    }
+
+    /// Size of `value` counted in priorities.
+    fn size_of(size: fn(&V) -> usize, value: &V) -> f64 {
+        size(value).max(1) as f64
+    }
+
+    fn touch(&self) {
+        self.count.set(self.count.get().saturating_add(1));
+        self.base.set(f64::from_bits(
+            self.inflation.load(AtomicOrdering::Relaxed),
+        ));
+    }
+}
+
+impl<V> Ord for GdsfCell<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority().total_cmp(&self.priority())
+    }
+}
+
+impl<V> PartialOrd for GdsfCell<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> PartialEq for GdsfCell<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<V> Eq for GdsfCell<V> {}
+
+impl<V> Decoration<V> for GdsfCell<V> {
+    fn unwrap(self) -> V {
+        self.value
+    }
+    fn get(&self) -> &V {
+        self.touch();
+        &self.value
+    }
+    /// The size of the value is computed again on the next priority
+    /// lookup.
+    fn get_mut(&mut self) -> &mut V {
+        self.touch();
+        self.resized.set(true);
+        &mut self.value
+    }
+    fn peek(&self) -> &V {
+        &self.value
+    }
+
+    /// Raise the inflation value to the priority of this cell.
+    fn evicted(&self) {
+        // Priorities are positive. Therefore their bit representation
+        // follows the same order as their value.
+        self.inflation
+            .fetch_max(self.priority().to_bits(), AtomicOrdering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gdsf;
+    use crate::decorator::{Decoration, DecorationFactory};
+
+    #[test]
+    fn test_gdsf_ref() {
+        let mut gdsf = Gdsf::new(|v: &u32| *v as usize);
+        let small = gdsf.wrap(1u32);
+        let large = gdsf.wrap(100u32);
+        assert!(small < large); // large is evicted first.
+        for _ in 0..200 {
+            large.get();
+        }
+        assert!(small > large); // large is used often enough.
+    }
+
+    #[test]
+    fn test_gdsf_resize() {
+        let mut gdsf = Gdsf::new(|v: &u32| *v as usize);
+        let mut cell = gdsf.wrap(1u32);
+        assert_eq!(cell.priority(), 1.0);
+        *cell.get_mut() = 100u32;
+        assert_eq!(cell.priority(), 2.0 / 100.0);
+        *cell.get_mut() = 0u32;
+        assert_eq!(cell.priority(), 3.0);
+    }
+
+    #[test]
+    fn test_gdsf_inflation() {
+        let mut gdsf = Gdsf::new(|v: &u32| *v as usize);
+        let evicted = gdsf.wrap(1u32);
+        let old = gdsf.wrap(1u32);
+        assert_eq!(gdsf.inflation(), 0.0);
+        evicted.evicted();
+        assert_eq!(gdsf.inflation(), 1.0);
+
+        // New values rank before values inserted before the eviction.
+        let new = gdsf.wrap(1u32);
+        assert!(new < old);
+        assert_eq!(new.priority(), 2.0);
+
+        // Clones share the inflation value.
+        let mut clone = gdsf.clone();
+        assert_eq!(clone.inflation(), 1.0);
+        clone.wrap(2u32).evicted();
+        assert_eq!(gdsf.inflation(), 1.5);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::decorator::{Decoration, DecorationFactory};
 use crate::utils::get::LifeTimeGuard;
-use crate::{Decorator, Get, GetMut};
+use crate::{BuildingBlock, Decorator, Get, GetMut};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
@@ -22,6 +22,19 @@ where
     unused: PhantomData<V>,
 }
 
+impl<V, R, U> DecoratorCell<V, R, U>
+where
+    R: Decoration<V>,
+    U: Deref<Target = R>,
+{
+    pub(super) fn new(item: U) -> Self {
+        DecoratorCell {
+            item,
+            unused: PhantomData,
+        }
+    }
+}
+
 impl<V, R, U> Deref for DecoratorCell<V, R, U>
 where
     R: Decoration<V>,
@@ -46,11 +59,17 @@ where
 impl<K, V, F, C> Get<K, V> for Decorator<C, V, F>
 where
     F: DecorationFactory<V> + Clone + Send + Sync,
-    C: Get<K, F::Item>,
+    C: Get<K, F::Item> + BuildingBlock<K, F::Item>,
 {
     type Target = DecoratorCell<V, F::Item, C::Target>;
 
+    /// Expired values are taken out of the container and dropped.
     fn get(&mut self, key: &K) -> Option<LifeTimeGuard<Self::Target>> {
+        if self.container.get(key)?.is_expired() {
+            self.container.take(key);
+            return None;
+        }
+
         self.container.get(key).map(|x| {
             LifeTimeGuard::new(DecoratorCell {
                 item: x.unwrap(),
@@ -63,11 +82,17 @@ where
 impl<K, V, F, C> GetMut<K, V> for Decorator<C, V, F>
 where
     F: DecorationFactory<V> + Clone + Send + Sync,
-    C: GetMut<K, F::Item>,
+    C: GetMut<K, F::Item> + BuildingBlock<K, F::Item>,
 {
     type Target = DecoratorCell<V, F::Item, C::Target>;
 
+    /// Expired values are taken out of the container and dropped.
     fn get_mut(&mut self, key: &K) -> Option<LifeTimeGuard<Self::Target>> {
+        if self.container.get_mut(key)?.is_expired() {
+            self.container.take(key);
+            return None;
+        }
+
         self.container.get_mut(key).map(|x| {
             LifeTimeGuard::new(DecoratorCell {
                 item: x.unwrap(),
@@ -80,8 +105,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::Decorator;
-    use crate::decorator::Default;
+    use crate::decorator::{Default, Lfu};
     use crate::tests::{test_get, test_get_mut};
+    use crate::utils::timestamp::Counter;
     use crate::Array;
 
     #[test]
@@ -91,4 +117,15 @@ mod tests {
             test_get_mut(Decorator::new(Array::new(i), Default {}));
         }
     }
+
+    #[test]
+    fn get_lfu() {
+        for i in [0usize, 10usize, 100usize] {
+            test_get(Decorator::new(Array::new(i), Lfu::<Counter>::new()));
+            test_get_mut(Decorator::new(
+                Array::new(i),
+                Lfu::<Counter>::new(),
+            ));
+        }
+    }
 }
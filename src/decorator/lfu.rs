@@ -0,0 +1,294 @@
+use crate::decorator::{Decoration, DecorationFactory};
+use crate::utils::timestamp::Timestamp;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::cmp::{Ord, Ordering};
+
+/// Implementation of [`Decoration`](trait.Decoration.html)
+/// with a Least Frequently Used (Lfu) eviction policy.
+///
+/// See [`Lfu`](struct.Lfu.html)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LfuCell<V, T: Timestamp> {
+    value: V,
+    /// Number of accesses, halved every aging period.
+    count: Cell<u64>,
+    /// Aging period and the epoch from which periods are counted. The
+    /// epoch is shared by all the cells of a factory.
+    aging: Option<(u64, T)>,
+    /// Number of aging periods elapsed from the epoch to the last access.
+    period: Cell<u64>,
+}
+
+/// Decoration implementation of Least Frequently Used ordering.
+///
+/// `Lfu` wraps values into cells implementing Lfu ordering policy.
+/// The purpose of this policy is to keep in the cache the most frequently
+/// used elements while the least frequently used one are elected for
+/// eviction.
+///
+/// Lfu implementation keeps a counter of accesses in the cell wrapping the
+/// value. When the value is accessed the counter is incremented.
+///
+/// Plain frequency counters let elements that were frequently used long
+/// ago stay in the cache forever. Optionally, counters can be aged with
+/// [`with_aging()`](struct.Lfu.html#method.with_aging) such that they are
+/// halved every `period` of time. Counters are halved lazily, when their
+/// cell is accessed, for every period elapsed since the previous access.
+/// Cells are compared as if they were all aged up to the latest of their
+/// last accesses. As a result, the order of two cells only changes when
+/// they are accessed, and cells can sit in ordered containers such as
+/// [`BTree`](../../struct.BTree.html).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Decorator, Get};
+/// use byoc::utils::decorator::Lfu;
+/// use byoc::utils::timestamp::Counter;
+///
+/// let mut c = Decorator::new(Array::new(2), Lfu::<Counter>::new());
+/// c.push(vec![("first", 1), ("second", 2)]);
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+///
+/// // "second" is the least frequently used element.
+/// assert_eq!(c.pop(1).pop().unwrap().0, "second");
+/// ```
+pub struct Lfu<T: Timestamp> {
+    aging: Option<(u64, T)>,
+}
+
+impl<T: Timestamp> Clone for Lfu<T> {
+    fn clone(&self) -> Self {
+        Lfu { aging: self.aging }
+    }
+}
+
+impl<T: Timestamp> Lfu<T> {
+    /// Construct a Lfu references factory without aging.
+    pub fn new() -> Self {
+        Lfu { aging: None }
+    }
+
+    /// Halve access counters every `period` of time.
+    ///
+    /// The `period` is expressed in the unit of the
+    /// [`Timestamp`](../timestamp/trait.Timestamp.html)
+    /// [`diff()`](../timestamp/trait.Timestamp.html#tymethod.diff) method
+    /// and must be strictly greater than 0.
+    pub fn with_aging(mut self, period: u64) -> Self {
+        assert!(period > 0, "Lfu aging period must be greater than 0.");
+        self.aging = Some((period, T::now()));
+        self
+    }
+
+    /// Get the aging period of this [`Lfu`] policy, if any.
+    pub fn aging(&self) -> Option<u64> {
+        self.aging.map(|(period, _)| period)
+    }
+}
+
+impl<T: Timestamp> Default for Lfu<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, T: Timestamp> DecorationFactory<V> for Lfu<T> {
+    type Item = LfuCell<V, T>;
+    fn wrap(&mut self, v: V) -> Self::Item {
+        let period = self.aging.map(LfuCell::<V, T>::periods).unwrap_or(0);
+        LfuCell {
+            value: v,
+            count: Cell::new(0),
+            aging: self.aging,
+            period: Cell::new(period),
+        }
+    }
+}
+
+unsafe impl<T: Timestamp> Send for Lfu<T> {}
+unsafe impl<T: Timestamp> Sync for Lfu<T> {}
+
+/// Halve `count` `n` times.
+fn halve(count: u64, n: u64) -> u64 {
+    if n >= u64::BITS as u64 {
+        0
+    } else {
+        count >> n
+    }
+}
+
+/// Compare `count << shift` with `other` without overflowing.
+fn cmp_shifted(count: u64, shift: u64, other: u64) -> Ordering {
+    if count == 0 {
+        0.cmp(&other)
+    } else if shift >= count.leading_zeros() as u64 {
+        Ordering::Greater
+    } else {
+        (count << shift).cmp(&other)
+    }
+}
+
+impl<V, T: Timestamp> LfuCell<V, T> {
+    pub fn new(e: V) -> Self {
+        LfuCell {
+            value: e,
+            count: Cell::new(0),
+            aging: None,
+            period: Cell::new(0),
+        }
+    }
+
+    /// Number of aging periods elapsed since the epoch.
+    fn periods((period, epoch): (u64, T)) -> u64 {
+        T::now().diff(&epoch) as u64 / period
+    }
+
+    /// Get the access count of this cell, after aging.
+    pub fn count(&self) -> u64 {
+        match self.aging {
+            None => self.count.get(),
+            Some(aging) => halve(
+                self.count.get(),
+                Self::periods(aging) - self.period.get(),
+            ),
+        }
+    }
+
+    fn touch(&self) {
+        if let Some(aging) = self.aging {
+            let periods = Self::periods(aging);
+            self.count
+                .set(halve(self.count.get(), periods - self.period.get()));
+            self.period.set(periods);
+        }
+        self.count.set(self.count.get().saturating_add(1));
+    }
+}
+
+impl<V, T: Timestamp> Ord for LfuCell<V, T> {
+    /// Cells with a greater count come first. Aged cells are compared
+    /// with the count of the cell accessed first halved once per period
+    /// elapsed until the last access of the other cell, i.e `count *
+    /// 2^period` is compared, without rounding.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (count, other_count) = (self.count.get(), other.count.get());
+        match (self.aging, other.aging) {
+            (Some(_), Some(_)) => {
+                let (period, other_period) =
+                    (self.period.get(), other.period.get());
+                if period >= other_period {
+                    cmp_shifted(count, period - other_period, other_count)
+                        .reverse()
+                } else {
+                    cmp_shifted(other_count, other_period - period, count)
+                }
+            }
+            _ => other_count.cmp(&count),
+        }
+    }
+}
+
+impl<V, T: Timestamp> PartialOrd for LfuCell<V, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V, T: Timestamp> PartialEq for LfuCell<V, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<V, T: Timestamp> Eq for LfuCell<V, T> {}
+
+impl<V, T: Timestamp> Decoration<V> for LfuCell<V, T> {
+    fn unwrap(self) -> V {
+        self.value
+    }
+    fn get(&self) -> &V {
+        self.touch();
+        &self.value
+    }
+    fn get_mut(&mut self) -> &mut V {
+        self.touch();
+        &mut self.value
+    }
+    fn peek(&self) -> &V {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lfu, LfuCell};
+    use crate::decorator::{Decoration, DecorationFactory};
+    use crate::utils::timestamp::{Counter, Timestamp};
+
+    #[test]
+    fn test_lfu_ref() {
+        let lfu_0 = LfuCell::<u32, Counter>::new(999u32);
+        let lfu_1 = LfuCell::<u32, Counter>::new(666u32);
+        assert!(lfu_0 == lfu_1); // None of the cells was used.
+        lfu_0.get();
+        assert!(lfu_0 < lfu_1); // lfu_0 is the most frequently used.
+        lfu_1.get();
+        lfu_1.get();
+        assert!(lfu_0 > lfu_1); // lfu_1 is the most frequently used.
+    }
+
+    #[test]
+    fn test_lfu_aging() {
+        let mut lfu = Lfu::<Counter>::new().with_aging(100000);
+        let lfu_0 = lfu.wrap(0u32);
+        for _ in 0..8 {
+            lfu_0.get();
+        }
+        assert_eq!(lfu_0.count(), 8);
+
+        // Every call to `Counter::now()` is a time step.
+        for _ in 0..100000 {
+            Counter::now();
+        }
+        assert!(lfu_0.count() <= 4);
+
+        // Accessing the cell starts from the aged count.
+        lfu_0.get();
+        assert!(lfu_0.count() <= 5);
+    }
+
+    #[test]
+    fn test_lfu_aging_order() {
+        let mut lfu = Lfu::<Counter>::new().with_aging(100000);
+        let lfu_0 = lfu.wrap(0u32);
+        let lfu_1 = lfu.wrap(1u32);
+        for _ in 0..8 {
+            lfu_0.get();
+        }
+        for _ in 0..100000 {
+            Counter::now();
+        }
+        for _ in 0..5 {
+            lfu_1.get();
+        }
+
+        // lfu_0 count is halved before being compared with lfu_1 count.
+        assert!(lfu_1 < lfu_0);
+
+        // The order does not change while time passes.
+        for _ in 0..200000 {
+            Counter::now();
+        }
+        assert!(lfu_1 < lfu_0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lfu_aging_zero_period() {
+        Lfu::<Counter>::new().with_aging(0);
+    }
+}
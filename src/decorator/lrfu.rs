@@ -212,6 +212,9 @@ impl<V, T: Timestamp + Copy> Decoration<V> for LrfuCell<V, T> {
         self.touch();
         &mut self.value
     }
+    fn peek(&self) -> &V {
+        &self.value
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +121,9 @@ impl<V, T: Timestamp> Decoration<V> for LruCell<V, T> {
         self.timestamp.set(T::now());
         &mut self.value
     }
+    fn peek(&self) -> &V {
+        &self.value
+    }
 }
 
 #[cfg(test)]
@@ -11,6 +11,42 @@ pub trait Decoration<V>: Ord {
     fn unwrap(self) -> V;
     fn get(&self) -> &V;
     fn get_mut(&mut self) -> &mut V;
+
+    /// Look at the wrapped value without accessing it.
+    ///
+    /// Unlike [`get()`](trait.Decoration.html#tymethod.get), this does not
+    /// update the decoration state, e.g access counts or timestamps, such
+    /// that the decoration order is left unchanged.
+    fn peek(&self) -> &V;
+
+    /// Whether the wrapped value expired.
+    ///
+    /// Expired values are considered absent from the
+    /// [`Decorator`](../../struct.Decorator.html) container holding them
+    /// and are dropped instead of being returned. By default, decorations
+    /// never expire.
+    fn is_expired(&self) -> bool {
+        false
+    }
+
+    /// Whether values wrapped in this decoration may expire.
+    ///
+    /// Decorations overriding
+    /// [`is_expired()`](trait.Decoration.html#method.is_expired) must set
+    /// this to `true` such that the
+    /// [`Decorator`](../../struct.Decorator.html) container takes expired
+    /// values out before evicting values on
+    /// [`pop()`](../../trait.BuildingBlock.html#tymethod.pop).
+    const EXPIRES: bool = false;
+
+    /// Notify the decoration that its value is evicted.
+    ///
+    /// This is called on the values coming out of the
+    /// [`Decorator`](../../struct.Decorator.html) container `pop()`,
+    /// `push()` and `resize()` methods before they are unwrapped, but not
+    /// on values taken or flushed out of the container, nor on values
+    /// replaced by `push()`. By default, it does nothing.
+    fn evicted(&self) {}
 }
 
 /// Facility to wrap cache values into a [`Decoration`] cell.
@@ -23,13 +59,26 @@ pub trait DecorationFactory<V> {
 
 mod building_block;
 mod concurrent;
+mod entry;
+mod gdsf;
 pub(crate) mod get;
+mod lfu;
 mod lrfu;
+mod policy;
+mod random;
+mod resizable;
+mod try_building_block;
+pub use gdsf::Gdsf;
+pub use lfu::Lfu;
 pub use lrfu::Lrfu;
+pub use policy::Policy;
+pub use random::Random;
 mod lru;
 pub use lru::Lru;
 mod fifo;
 pub use fifo::Fifo;
+mod ttl;
+pub use ttl::Ttl;
 #[cfg(test)]
 mod default;
 #[cfg(test)]
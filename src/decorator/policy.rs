@@ -0,0 +1,177 @@
+use crate::decorator::{Decoration, DecorationFactory};
+use std::cell::RefCell;
+use std::cmp::{Ord, Ordering};
+use std::sync::Arc;
+
+type InsertFn<V, M> = Arc<dyn Fn(&V) -> M + Send + Sync>;
+type AccessFn<M> = Arc<dyn Fn(&mut M) + Send + Sync>;
+type ScoreFn<M, S> = Arc<dyn Fn(&M) -> S + Send + Sync>;
+
+/// Implementation of [`Decoration`](trait.Decoration.html)
+/// with a user defined eviction policy.
+///
+/// See [`Policy`](struct.Policy.html)
+pub struct PolicyCell<V, M, S> {
+    value: V,
+    /// Policy metadata attached to the value.
+    metadata: RefCell<M>,
+    /// Function updating the metadata on access.
+    on_access: AccessFn<M>,
+    /// Function computing the eviction score of the metadata.
+    score: ScoreFn<M, S>,
+}
+
+/// Decoration implementation of a user defined ordering.
+///
+/// `Policy` wraps values into cells carrying some metadata `M` managed
+/// with user provided closures. This is meant to prototype eviction
+/// policies without writing a new [`Decoration`](trait.Decoration.html)
+/// and [`DecorationFactory`](trait.DecorationFactory.html) pair.
+///
+/// * `on_insert` builds the metadata of a value when it is inserted,
+/// * `on_access` updates the metadata of a value every time it is
+///   accessed with [`Get`](../../trait.Get.html) or
+///   [`GetMut`](../../trait.GetMut.html) traits,
+/// * `score` computes the eviction score of a value from its metadata.
+///   Values with the greatest score are evicted first.
+///
+/// Closures are shared between the factory and the cells it creates such
+/// that the policy type does not depend on the closures type. Therefore,
+/// the [`Decorator`](../../struct.Decorator.html) container it builds can
+/// be stored in a [`DynBuildingBlock`](../../struct.DynBuildingBlock.html).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Decorator, Get};
+/// use byoc::utils::decorator::Policy;
+///
+/// // Least frequently used policy.
+/// let lfu = Policy::new(|_: &i32| 0u64,
+///                       |count: &mut u64| *count += 1,
+///                       |count: &u64| std::cmp::Reverse(*count));
+/// let mut c = Decorator::new(Array::new(2), lfu);
+/// c.push(vec![("first", 1), ("second", 2)]);
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+///
+/// // "second" is the least frequently used element.
+/// assert_eq!(c.pop(1).pop().unwrap().0, "second");
+/// ```
+pub struct Policy<V, M, S> {
+    on_insert: InsertFn<V, M>,
+    on_access: AccessFn<M>,
+    score: ScoreFn<M, S>,
+}
+
+impl<V, M, S> Clone for Policy<V, M, S> {
+    fn clone(&self) -> Self {
+        Policy {
+            on_insert: self.on_insert.clone(),
+            on_access: self.on_access.clone(),
+            score: self.score.clone(),
+        }
+    }
+}
+
+impl<V, M, S: Ord> Policy<V, M, S> {
+    /// Construct a policy references factory from the closures
+    /// initializing the metadata of inserted values, updating it on
+    /// access, and computing the eviction score of values.
+    pub fn new<I, A, F>(on_insert: I, on_access: A, score: F) -> Self
+    where
+        I: Fn(&V) -> M + Send + Sync + 'static,
+        A: Fn(&mut M) + Send + Sync + 'static,
+        F: Fn(&M) -> S + Send + Sync + 'static,
+    {
+        Policy {
+            on_insert: Arc::new(on_insert),
+            on_access: Arc::new(on_access),
+            score: Arc::new(score),
+        }
+    }
+}
+
+impl<V, M, S: Ord> DecorationFactory<V> for Policy<V, M, S> {
+    type Item = PolicyCell<V, M, S>;
+    fn wrap(&mut self, v: V) -> Self::Item {
+        PolicyCell {
+            metadata: RefCell::new((self.on_insert)(&v)),
+            value: v,
+            on_access: self.on_access.clone(),
+            score: self.score.clone(),
+        }
+    }
+}
+
+impl<V, M, S> PolicyCell<V, M, S> {
+    /// Get the eviction score of this cell.
+    pub fn score(&self) -> S {
+        (self.score)(&self.metadata.borrow())
+    }
+
+    fn touch(&self) {
+        (self.on_access)(&mut self.metadata.borrow_mut())
+    }
+}
+
+impl<V, M, S: Ord> Ord for PolicyCell<V, M, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score().cmp(&other.score())
+    }
+}
+
+impl<V, M, S: Ord> PartialOrd for PolicyCell<V, M, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V, M, S: Ord> PartialEq for PolicyCell<V, M, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<V, M, S: Ord> Eq for PolicyCell<V, M, S> {}
+
+impl<V, M, S: Ord> Decoration<V> for PolicyCell<V, M, S> {
+    fn unwrap(self) -> V {
+        self.value
+    }
+    fn get(&self) -> &V {
+        self.touch();
+        &self.value
+    }
+    fn get_mut(&mut self) -> &mut V {
+        self.touch();
+        &mut self.value
+    }
+    fn peek(&self) -> &V {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Policy;
+    use crate::decorator::{Decoration, DecorationFactory};
+
+    #[test]
+    fn test_policy_ref() {
+        // Largest values with the fewest accesses are evicted first.
+        let mut policy = Policy::new(
+            |v: &u32| (*v, 1u32),
+            |(_, count): &mut (u32, u32)| *count += 1,
+            |(size, count): &(u32, u32)| size / count,
+        );
+        let small = policy.wrap(10u32);
+        let large = policy.wrap(100u32);
+        assert!(small < large); // large is evicted first.
+        assert_eq!(large.score(), 100u32);
+        for _ in 0..19 {
+            large.get();
+        }
+        assert_eq!(large.score(), 5u32);
+        assert!(small > large); // large is used often enough.
+    }
+}
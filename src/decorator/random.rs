@@ -0,0 +1,128 @@
+use crate::decorator::{Decoration, DecorationFactory};
+use crate::utils::random::Rng;
+use std::cmp::{Ord, Ordering};
+
+/// Implementation of [`Decoration`](trait.Decoration.html)
+/// with a random eviction policy.
+///
+/// See [`Random`](struct.Random.html)
+#[derive(Debug, Clone)]
+pub struct RandomCell<V> {
+    value: V,
+    /// Random rank of the value.
+    rank: u64,
+}
+
+/// Decoration implementation of random ordering.
+///
+/// `Random` wraps values into cells assigned with a random rank on
+/// insertion. Cells are ordered by rank, therefore, the elected victims
+/// are random elements of the container. Accesses do not change the
+/// rank of a cell.
+///
+/// The random generator can be seeded with
+/// [`with_seed()`](struct.Random.html#method.with_seed) to obtain
+/// reproducible evictions.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Decorator};
+/// use byoc::utils::decorator::Random;
+///
+/// let mut c = Decorator::new(Array::new(2), Random::with_seed(42));
+/// c.push(vec![("first", 1), ("second", 2)]);
+/// assert_eq!(c.pop(1).len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Random {
+    rng: Rng,
+}
+
+impl Random {
+    /// Construct a Random references factory seeded with the current
+    /// time.
+    pub fn new() -> Self {
+        Random {
+            rng: Rng::from_time(),
+        }
+    }
+
+    /// Construct a Random references factory from a `seed`.
+    ///
+    /// Factories built with the same seed assign the same ranks to
+    /// inserted values.
+    pub fn with_seed(seed: u64) -> Self {
+        Random {
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> DecorationFactory<V> for Random {
+    type Item = RandomCell<V>;
+    fn wrap(&mut self, v: V) -> Self::Item {
+        RandomCell {
+            value: v,
+            rank: self.rng.next_u64(),
+        }
+    }
+}
+
+impl<V> Ord for RandomCell<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+impl<V> PartialOrd for RandomCell<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> PartialEq for RandomCell<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank
+    }
+}
+
+impl<V> Eq for RandomCell<V> {}
+
+impl<V> Decoration<V> for RandomCell<V> {
+    fn unwrap(self) -> V {
+        self.value
+    }
+    fn get(&self) -> &V {
+        &self.value
+    }
+    fn get_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+    fn peek(&self) -> &V {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Random;
+    use crate::decorator::{Decoration, DecorationFactory};
+
+    #[test]
+    fn test_random_ref() {
+        let mut a = Random::with_seed(42);
+        let mut b = Random::with_seed(42);
+        for i in 0..10u32 {
+            let (x, y) = (a.wrap(i), b.wrap(i));
+            assert!(x == y);
+            assert_eq!(*x.get(), i);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use super::decorator::unwrap_evicted;
+use super::DecorationFactory;
+use crate::Decorator;
+use crate::Resizable;
+
+impl<K, V, C, F> Resizable<K, V> for Decorator<C, V, F>
+where
+    C: Resizable<K, F::Item>,
+    F: DecorationFactory<V>,
+{
+    /// Change the capacity of the container wrapped in this [`Decorator`].
+    ///
+    /// This calls the decorated container
+    /// [`resize()`](trait.Resizable.html#tymethod.resize)
+    /// method and will remove the decoration from the victims before
+    /// returning them. Expired victims are dropped.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.container
+            .resize(new_capacity)
+            .into_iter()
+            .filter_map(unwrap_evicted)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decorator;
+    use crate::decorator::Default;
+    use crate::tests::test_resizable;
+    use crate::Array;
+
+    #[test]
+    fn resizable() {
+        for i in [0usize, 10usize, 100usize] {
+            test_resizable(Decorator::new(Array::new(i), Default {}));
+        }
+    }
+}
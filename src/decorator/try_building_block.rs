@@ -0,0 +1,127 @@
+use super::decorator::{unwrap_evicted, unwrap_unexpired};
+use super::{Decoration, DecorationFactory};
+use crate::Decorator;
+use crate::{Error, TryBuildingBlock};
+
+impl<K, V, C, F> TryBuildingBlock<K, V> for Decorator<C, V, F>
+where
+    K: Ord,
+    C: TryBuildingBlock<K, F::Item>,
+    F: DecorationFactory<V>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        self.container.try_capacity()
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        self.container.try_size()
+    }
+
+    /// Expired values are considered absent, as in
+    /// [`contains()`](trait.BuildingBlock.html#tymethod.contains).
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        Ok(self.container.try_contains(key)?
+            && self
+                .container
+                .contains_if(key, &|d: &F::Item| !d.is_expired()))
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        Ok(self.container.try_take(key)?.and_then(unwrap_unexpired))
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        Ok(self
+            .container
+            .try_take_multiple(keys)?
+            .into_iter()
+            .filter_map(unwrap_unexpired)
+            .collect())
+    }
+
+    /// Expired values are taken out first, as in
+    /// [`pop()`](trait.BuildingBlock.html#tymethod.pop).
+    fn try_pop(&mut self, n: usize) -> Result<Vec<(K, V)>, Error> {
+        let mut out = Vec::new();
+        let mut n = n;
+        if F::Item::EXPIRES {
+            let size = self.container.try_size()?;
+            let elements = self
+                .container
+                .try_flush()?
+                .into_iter()
+                .filter(|(_, d)| !d.is_expired())
+                .collect();
+            out.extend(
+                self.container
+                    .try_push(elements)?
+                    .into_iter()
+                    .filter_map(unwrap_evicted),
+            );
+            n = n.saturating_sub(
+                size.saturating_sub(self.container.try_size()?),
+            );
+        }
+        if n > 0 {
+            out.extend(
+                self.container
+                    .try_pop(n)?
+                    .into_iter()
+                    .filter_map(unwrap_evicted),
+            );
+        }
+        Ok(out)
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let factory = &mut self.factory;
+        let elements = elements
+            .into_iter()
+            .map(|(k, v)| (k, factory.wrap(v)))
+            .collect();
+        // Replaced values are returned while their key is still in the
+        // container. They are not evicted.
+        let mut out = Vec::new();
+        for (k, d) in self.container.try_push(elements)? {
+            if self.container.try_contains(&k)? {
+                out.extend(unwrap_unexpired((k, d)));
+            } else {
+                out.extend(unwrap_evicted((k, d)));
+            }
+        }
+        Ok(out)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        Ok(self
+            .container
+            .try_flush()?
+            .into_iter()
+            .filter_map(unwrap_unexpired)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decorator;
+    use crate::decorator::Default;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+
+    #[test]
+    fn try_building_block() {
+        for i in [0usize, 10usize, 100usize] {
+            test_try_building_block(
+                Decorator::new(Array::new(i), Default {}),
+                true,
+            );
+        }
+    }
+}
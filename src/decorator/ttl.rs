@@ -0,0 +1,236 @@
+use crate::decorator::{Decoration, DecorationFactory};
+use crate::utils::timestamp::{Clock, Timestamp};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::{Ord, Ordering};
+use std::time::Duration;
+
+//----------------------------------------------------------------------------//
+// Time to live expiration policy                                             //
+//----------------------------------------------------------------------------//
+
+/// Implementation of [`Decoration`](trait.Decoration.html) expiring after
+/// a fixed amount of time.
+///
+/// See [`Ttl`](struct.Ttl.html).
+#[derive(Debug, Clone)]
+pub struct TtlCell<D> {
+    cell: D,
+    /// Insertion time.
+    timestamp: Clock,
+    /// Time to live after insertion.
+    ttl: Duration,
+}
+
+/// Decoration implementation of values Time To Live (Ttl).
+///
+/// `Ttl` wraps values into cells expiring after a fixed amount of time
+/// has elapsed since they were wrapped, i.e inserted in a
+/// [`Decorator`](../../struct.Decorator.html) container. Expired values
+/// are considered absent from the container: they are never returned by
+/// [`get()`](../../trait.Get.html#tymethod.get),
+/// [`take()`](../../trait.BuildingBlock.html#tymethod.take),
+/// [`pop()`](../../trait.BuildingBlock.html#tymethod.pop) or
+/// [`flush()`](../../trait.BuildingBlock.html#tymethod.flush) and they are
+/// dropped instead.
+///
+/// Expiration does not depend on the eviction order of the container.
+/// `Ttl` cells wrap the cells of another
+/// [`DecorationFactory`](trait.DecorationFactory.html) that orders the
+/// values. The order of `Ttl` cells is the order of the wrapped cells and
+/// does not change when values expire, such that values can sit in
+/// ordered containers. When values are popped out of the container,
+/// expired values are taken out and dropped first. Values are then
+/// evicted in this order only if more room is needed.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Decorator};
+/// use byoc::utils::decorator::{Lru, Ttl};
+/// use byoc::utils::timestamp::Counter;
+/// use std::time::Duration;
+///
+/// let ttl = Ttl::new(Lru::<Counter>::new(), Duration::from_secs(60));
+/// let mut c = Decorator::new(Array::new(3), ttl);
+/// assert!(c.push(vec![("item1", 1u16), ("item2", 2u16)]).is_empty());
+/// assert!(c.take(&"item1").is_some());
+///
+/// // Values with no time to live expire right away.
+/// let ttl = Ttl::new(Lru::<Counter>::new(), Duration::ZERO);
+/// let mut c = Decorator::new(Array::new(3), ttl);
+/// assert!(c.push(vec![("item1", 1u16), ("item2", 2u16)]).is_empty());
+/// assert!(!c.contains(&"item1"));
+/// assert!(c.take(&"item2").is_none());
+/// ```
+pub struct Ttl<F> {
+    factory: F,
+    ttl: Duration,
+}
+
+impl<F: Clone> Clone for Ttl<F> {
+    fn clone(&self) -> Self {
+        Ttl {
+            factory: self.factory.clone(),
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl<F> Ttl<F> {
+    /// Wrap the cells of a [`DecorationFactory`] into cells expiring
+    /// `ttl` after they are created.
+    pub fn new(factory: F, ttl: Duration) -> Self {
+        Ttl { factory, ttl }
+    }
+
+    /// Get the time to live of the cells created by this factory.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+impl<V, F: DecorationFactory<V>> DecorationFactory<V> for Ttl<F> {
+    type Item = TtlCell<F::Item>;
+
+    fn wrap(&mut self, v: V) -> Self::Item {
+        TtlCell {
+            cell: self.factory.wrap(v),
+            timestamp: Clock::now(),
+            ttl: self.ttl,
+        }
+    }
+}
+
+unsafe impl<F: Send> Send for Ttl<F> {}
+unsafe impl<F: Sync> Sync for Ttl<F> {}
+
+impl<D> TtlCell<D> {
+    /// Time left before this cell expires.
+    fn remaining(&self) -> Duration {
+        let elapsed = Clock::now().diff(&self.timestamp) as u64;
+        self.ttl.saturating_sub(Duration::from_nanos(elapsed))
+    }
+
+    fn expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// Serialization format of a [`TtlCell`].
+///
+/// A [`Clock`] cannot be serialized since it is only meaningful in the
+/// process where it was created. Instead, the time left before the cell
+/// expires is serialized and the cell timestamp is reset when it is
+/// deserialized.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct TtlCellFormat<D> {
+    cell: D,
+    remaining: Duration,
+}
+
+#[cfg(feature = "serde")]
+impl<D: Serialize> Serialize for TtlCell<D> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        TtlCellFormat {
+            cell: &self.cell,
+            remaining: self.remaining(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D: Deserialize<'de>> Deserialize<'de> for TtlCell<D> {
+    fn deserialize<De: Deserializer<'de>>(
+        deserializer: De,
+    ) -> Result<Self, De::Error> {
+        let format = TtlCellFormat::<D>::deserialize(deserializer)?;
+        Ok(TtlCell {
+            cell: format.cell,
+            timestamp: Clock::now(),
+            ttl: format.remaining,
+        })
+    }
+}
+
+impl<D: Ord> Ord for TtlCell<D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cell.cmp(&other.cell)
+    }
+}
+
+impl<D: Ord> PartialOrd for TtlCell<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D: Ord> PartialEq for TtlCell<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<D: Ord> Eq for TtlCell<D> {}
+
+impl<V, D: Decoration<V>> Decoration<V> for TtlCell<D> {
+    const EXPIRES: bool = true;
+
+    fn unwrap(self) -> V {
+        self.cell.unwrap()
+    }
+    fn get(&self) -> &V {
+        self.cell.get()
+    }
+    fn get_mut(&mut self) -> &mut V {
+        self.cell.get_mut()
+    }
+    fn peek(&self) -> &V {
+        self.cell.peek()
+    }
+    fn is_expired(&self) -> bool {
+        self.expired()
+    }
+    fn evicted(&self) {
+        self.cell.evicted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ttl;
+    use crate::decorator::{Decoration, DecorationFactory, Fifo};
+    use crate::utils::timestamp::Clock;
+    use std::time::Duration;
+
+    #[test]
+    fn test_ttl_ref() {
+        let mut ttl = Ttl::new(Fifo::new(), Duration::from_secs(60));
+        let p0 = ttl.wrap("item0");
+        Clock::advance(Duration::from_secs(60));
+        let p1 = ttl.wrap("item1");
+        let p2 = ttl.wrap("item2");
+
+        assert!(p0.is_expired());
+        assert!(!p1.is_expired());
+        assert!(p0 > p1);
+        assert!(p1 > p2);
+
+        // Expiration does not change the wrapped cells order.
+        let mut long = Ttl::new(Fifo::new(), Duration::from_secs(60));
+        let p0 = long.wrap("item0");
+        let mut short =
+            Ttl::new(long.factory.clone(), Duration::from_secs(30));
+        let p1 = short.wrap("item1");
+        assert!(p0 > p1);
+        Clock::advance(Duration::from_secs(30));
+        assert!(p1.is_expired());
+        assert!(!p0.is_expired());
+        assert!(p0 > p1);
+    }
+}
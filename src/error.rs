@@ -0,0 +1,74 @@
+#[cfg(feature = "socket")]
+use crate::socket::SocketError;
+#[cfg(feature = "stream")]
+use crate::stream::IOError;
+
+/// Error type returned by fallible
+/// [`TryBuildingBlock`](trait.TryBuildingBlock.html) methods and by
+/// [`Resizable::try_resize()`](trait.Resizable.html#method.try_resize).
+///
+/// When one of these methods returns an error, the operation is aborted.
+/// The elements taken out of the container or passed to the container by
+/// the failing call may be lost.
+#[derive(Debug)]
+pub enum Error {
+    /// Failure to seek, read, write, (de)serialize or (de)compress
+    /// elements stored in a [`Stream`](utils/stream/trait.Stream.html).
+    #[cfg(feature = "stream")]
+    IO(IOError),
+    /// Failure to process a request on a remote container through a
+    /// [`SocketClient`](struct.SocketClient.html).
+    #[cfg(feature = "socket")]
+    Socket(SocketError),
+    /// Elements moved out of a container in the course of an operation
+    /// could not fit anywhere else.
+    Overflow,
+    /// The capacity of the container cannot be changed, e.g a
+    /// [`DynBuildingBlock`](struct.DynBuildingBlock.html) wrapping a
+    /// container that is not [`Resizable`](trait.Resizable.html).
+    NotResizable,
+}
+
+#[cfg(feature = "stream")]
+impl From<IOError> for Error {
+    fn from(e: IOError) -> Self {
+        Error::IO(e)
+    }
+}
+
+#[cfg(feature = "socket")]
+impl From<SocketError> for Error {
+    fn from(e: SocketError) -> Self {
+        Error::Socket(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "stream")]
+            Error::IO(e) => write!(f, "stream error: {}", e),
+            #[cfg(feature = "socket")]
+            Error::Socket(e) => write!(f, "socket error: {}", e),
+            Error::Overflow => write!(
+                f,
+                "elements moved out of a container could not fit anywhere"
+            ),
+            Error::NotResizable => {
+                write!(f, "the container capacity cannot be changed")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "stream")]
+            Error::IO(e) => Some(e),
+            #[cfg(feature = "socket")]
+            Error::Socket(e) => Some(e),
+            Error::Overflow | Error::NotResizable => None,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::EvictionListener;
+use crate::builder::Build;
+use std::marker::PhantomData;
+
+/// `EvictionListener` container builder.
+///
+/// This builder can be consumed later to wrap some containers into an
+/// [`EvictionListener`](../../struct.EvictionListener.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{Build, EvictionListenerBuild};
+/// use byoc::builder::{ArrayBuilder, EvictionListenerBuilder};
+///
+/// let array_builder = ArrayBuilder::new(2);
+/// let mut container =
+///     EvictionListenerBuilder::new(array_builder, |_: &u32, _: &u32| {})
+///         .build();
+/// container.push(vec![(1, 2)]);
+///
+/// // You can also chain calls:
+/// let mut container = ArrayBuilder::new(2)
+///     .with_eviction_listener(|_: &u32, _: &u32| {})
+///     .report_removals(true)
+///     .build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct EvictionListenerBuilder<C, B, F> {
+    pub(super) builder: B,
+    pub(super) listener: F,
+    pub(super) report_removals: bool,
+    unused: PhantomData<C>,
+}
+
+impl<C, B, F> EvictionListenerBuilder<C, B, F> {
+    pub fn new(builder: B, listener: F) -> Self {
+        EvictionListenerBuilder {
+            builder,
+            listener,
+            report_removals: false,
+            unused: PhantomData,
+        }
+    }
+
+    /// Set whether the built container also reports elements removed
+    /// with `take()`, `take_multiple()` and `flush()` to the listener.
+    ///
+    /// See
+    /// [`EvictionListener::report_removals()`](../../struct.EvictionListener.html#method.report_removals).
+    pub fn report_removals(mut self, report: bool) -> Self {
+        self.report_removals = report;
+        self
+    }
+}
+
+impl<C, B, F> Clone for EvictionListenerBuilder<C, B, F>
+where
+    B: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        EvictionListenerBuilder {
+            builder: self.builder.clone(),
+            listener: self.listener.clone(),
+            report_removals: self.report_removals,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<C, B, F> Build<EvictionListener<C, F>>
+    for EvictionListenerBuilder<C, B, F>
+where
+    B: Build<C>,
+{
+    fn build(self) -> EvictionListener<C, F> {
+        EvictionListener::new(self.builder.build(), self.listener)
+            .report_removals(self.report_removals)
+    }
+}
+
+/// Report the elements evicted from the container to a callback.
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{Build, Builder, EvictionListenerBuild};
+///
+/// let mut container = Builder::array(10000)
+///     .with_eviction_listener(|k: &u32, v: &u32| println!("{} {}", k, v))
+///     .build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub trait EvictionListenerBuild<C> {
+    /// Wrap a container builder into an
+    /// [eviction listener](../../struct.EvictionListener.html) building
+    /// block calling `listener` with every evicted element.
+    fn with_eviction_listener<F>(
+        self,
+        listener: F,
+    ) -> EvictionListenerBuilder<C, Self, F>
+    where
+        Self: Sized,
+    {
+        EvictionListenerBuilder::new(self, listener)
+    }
+}
+
+impl<C, B: Build<C>> EvictionListenerBuild<C> for B {}
@@ -0,0 +1,186 @@
+use super::EvictionListener;
+use crate::utils::SharedPtr;
+use crate::BuildingBlock;
+
+/// Iterator of the elements flushed out of an [`EvictionListener`]
+/// reporting them to the listener as they are iterated.
+pub struct EvictionListenerFlushIter<I, F> {
+    elements: I,
+    listener: Option<SharedPtr<F>>,
+}
+
+impl<K, V, I, F> Iterator for EvictionListenerFlushIter<I, F>
+where
+    I: Iterator<Item = (K, V)>,
+    F: FnMut(&K, &V),
+{
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (k, v) = self.elements.next()?;
+        if let Some(listener) = self.listener.as_mut() {
+            (*listener.as_mut())(&k, &v);
+        }
+        Some((k, v))
+    }
+}
+
+impl<K, V, C, F> BuildingBlock<K, V> for EvictionListener<C, F>
+where
+    C: BuildingBlock<K, V>,
+    F: FnMut(&K, &V),
+{
+    fn capacity(&self) -> usize {
+        self.container.capacity()
+    }
+    fn size(&self) -> usize {
+        self.container.size()
+    }
+    fn contains(&self, key: &K) -> bool {
+        self.container.contains(key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, predicate)
+    }
+
+    /// Take an element out of the wrapped container.
+    ///
+    /// The element is reported to the listener if removals reporting is
+    /// enabled.
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        let out = self.container.take(key);
+        if self.report_removals {
+            if let Some((k, v)) = out.as_ref() {
+                (*self.listener.as_mut())(k, v);
+            }
+        }
+        out
+    }
+
+    /// Take multiple elements out of the wrapped container.
+    ///
+    /// The elements are reported to the listener if removals reporting is
+    /// enabled.
+    fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
+        let out = self.container.take_multiple(keys);
+        if self.report_removals {
+            self.notify(out)
+        } else {
+            out
+        }
+    }
+
+    /// Pop elements from the wrapped container and report them to the
+    /// listener.
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        let out = self.container.pop(size);
+        self.notify(out)
+    }
+
+    /// Push elements in the wrapped container and report the elements
+    /// that did not fit to the listener.
+    fn push(&mut self, values: Vec<(K, V)>) -> Vec<(K, V)> {
+        let out = self.container.push(values);
+        self.notify(out)
+    }
+
+    type FlushIterator = EvictionListenerFlushIter<C::FlushIterator, F>;
+
+    /// Flush the wrapped container.
+    ///
+    /// If removals reporting is enabled, the flushed elements are
+    /// reported to the listener as the returned iterator yields them.
+    /// Elements that are not iterated are not reported.
+    fn flush(&mut self) -> Self::FlushIterator {
+        EvictionListenerFlushIter {
+            elements: self.container.flush(),
+            listener: if self.report_removals {
+                Some(self.listener.clone())
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvictionListener;
+    use crate::tests::{test_building_block, TestKey, TestValue};
+    use crate::{Array, BuildingBlock};
+
+    #[test]
+    fn building_block() {
+        let ignore = |_: &TestKey, _: &TestValue| {};
+        test_building_block(
+            EvictionListener::new(Array::new(0), ignore),
+            true,
+        );
+        test_building_block(
+            EvictionListener::new(Array::new(10), ignore),
+            true,
+        );
+        test_building_block(
+            EvictionListener::new(Array::new(100), ignore)
+                .report_removals(true),
+            true,
+        );
+    }
+
+    #[test]
+    fn evictions() {
+        let mut evicted = Vec::new();
+        let mut c =
+            EvictionListener::new(Array::new(2), |k: &u16, v: &u32| {
+                evicted.push((*k, *v))
+            });
+
+        assert!(c.push(vec![(1, 1), (2, 2)]).is_empty());
+        assert_eq!(c.push(vec![(3, 3)]), vec![(2, 2)]);
+        assert_eq!(c.pop(1), vec![(3, 3)]);
+        assert!(c.take(&1).is_some());
+        c.push(vec![(4, 4)]);
+        assert_eq!(c.flush().count(), 1);
+        drop(c);
+        assert_eq!(evicted, vec![(2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn removals() {
+        let mut removed = Vec::new();
+        let mut c =
+            EvictionListener::new(Array::new(3), |k: &u16, v: &u32| {
+                removed.push((*k, *v))
+            })
+            .report_removals(true);
+
+        assert!(c.push(vec![(1, 1), (2, 2), (3, 3)]).is_empty());
+        assert_eq!(c.take(&1), Some((1, 1)));
+        assert_eq!(c.take_multiple(&mut vec![2]), vec![(2, 2)]);
+        assert_eq!(c.flush().collect::<Vec<_>>(), vec![(3, 3)]);
+        drop(c);
+        assert_eq!(removed, vec![(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn lazy_flush() {
+        let mut removed = 0;
+        let mut c =
+            EvictionListener::new(Array::new(3), |_: &u16, _: &u32| {
+                removed += 1
+            })
+            .report_removals(true);
+
+        c.push(vec![(1, 1), (2, 2), (3, 3)]);
+        let mut flush = c.flush();
+        assert!(flush.next().is_some());
+        // Elements left in the iterator are not reported.
+        drop(flush);
+        drop(c);
+        assert_eq!(removed, 1);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::utils::SharedPtr;
+use crate::BuildingBlock;
+use std::sync::{RwLockReadGuard, RwLockWriteGuard};
+
+/// `BuildingBlock` wrapper reporting evicted elements to a callback.
+///
+/// This [`BuildingBlock`](trait.BuildingBlock.html) forwards its methods
+/// call to the [`BuildingBlock`](trait.BuildingBlock.html) it wraps and
+/// calls a listener `FnMut(&K, &V)` with every element evicted from the
+/// wrapped container, i.e every element returned by the
+/// [`push()`](trait.BuildingBlock.html#tymethod.push),
+/// [`pop()`](trait.BuildingBlock.html#tymethod.pop) and
+/// [`resize()`](trait.Resizable.html#tymethod.resize) methods. The
+/// evicted elements are still returned to the caller after the listener
+/// has been called on them.
+///
+/// This is useful to collect eviction metrics, write evicted elements back
+/// to a database or send invalidation messages without changing the code
+/// driving the container.
+///
+/// Elements removed on purpose with the
+/// [`take()`](trait.BuildingBlock.html#tymethod.take),
+/// [`take_multiple()`](trait.BuildingBlock.html#method.take_multiple) and
+/// [`flush()`](trait.BuildingBlock.html#tymethod.flush) methods are not
+/// reported unless enabled with
+/// [`report_removals()`](struct.EvictionListener.html#method.report_removals).
+///
+/// [`EvictionListener`] can also be built from a
+/// [builder pattern](builder/trait.EvictionListenerBuild.html).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, EvictionListener};
+///
+/// let mut evicted = Vec::new();
+/// let mut c = EvictionListener::new(Array::new(1), |k: &u32, v: &u32| {
+///     evicted.push((*k, *v))
+/// });
+///
+/// c.push(vec![(1, 10)]);
+/// // Pushing a second element evicts the first one.
+/// assert_eq!(c.push(vec![(2, 20)]), vec![(1, 10)]);
+/// // Taking an element out is not an eviction.
+/// assert!(c.take(&2).is_some());
+///
+/// drop(c);
+/// assert_eq!(evicted, vec![(1, 10)]);
+/// ```
+pub struct EvictionListener<C, F> {
+    pub(super) container: C,
+    pub(super) listener: SharedPtr<F>,
+    pub(super) report_removals: bool,
+}
+
+impl<C, F> EvictionListener<C, F> {
+    /// Wrap `container` into an [`EvictionListener`] calling `listener`
+    /// with every element evicted from `container`.
+    pub fn new(container: C, listener: F) -> Self {
+        EvictionListener {
+            container,
+            listener: SharedPtr::from(listener),
+            report_removals: false,
+        }
+    }
+
+    /// Set whether elements removed with
+    /// [`take()`](trait.BuildingBlock.html#tymethod.take),
+    /// [`take_multiple()`](trait.BuildingBlock.html#method.take_multiple)
+    /// and [`flush()`](trait.BuildingBlock.html#tymethod.flush) are also
+    /// reported to the listener.
+    pub fn report_removals(mut self, report: bool) -> Self {
+        self.report_removals = report;
+        self
+    }
+
+    /// Get shared access to the wrapped container.
+    pub fn container(&self) -> &C {
+        &self.container
+    }
+
+    /// Get exclusive access to the wrapped container.
+    pub fn container_mut(&mut self) -> &mut C {
+        &mut self.container
+    }
+
+    /// Get shared access to the listener.
+    ///
+    /// The listener is shared with the iterators returned by
+    /// [`flush()`](trait.BuildingBlock.html#tymethod.flush). This method
+    /// panics if one of them is calling the listener.
+    pub fn listener(&self) -> RwLockReadGuard<'_, F> {
+        self.listener.as_ref()
+    }
+
+    /// Get exclusive access to the listener.
+    ///
+    /// This method panics if an iterator returned by
+    /// [`flush()`](trait.BuildingBlock.html#tymethod.flush) is calling the
+    /// listener.
+    pub fn listener_mut(&mut self) -> RwLockWriteGuard<'_, F> {
+        self.listener.as_mut()
+    }
+
+    /// Call the listener on each of `elements` and return them.
+    pub(super) fn notify<K, V>(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)>
+    where
+        F: FnMut(&K, &V),
+    {
+        let mut listener = self.listener.as_mut();
+        for (k, v) in elements.iter() {
+            (*listener)(k, v);
+        }
+        drop(listener);
+        elements
+    }
+}
+
+impl<'a, K, V, C, F> From<EvictionListener<C, F>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a,
+    V: 'a,
+    C: 'a + BuildingBlock<K, V>,
+    F: 'a + FnMut(&K, &V),
+{
+    fn from(listener: EvictionListener<C, F>) -> Self {
+        crate::DynBuildingBlock::new(listener, false)
+    }
+}
@@ -0,0 +1,44 @@
+use super::EvictionListener;
+use crate::utils::get::LifeTimeGuard;
+use crate::{Get, GetMut};
+
+impl<K, V, C, F> Get<K, V> for EvictionListener<C, F>
+where
+    C: Get<K, V>,
+{
+    type Target = C::Target;
+
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.container.get(key)
+    }
+}
+
+impl<K, V, C, F> GetMut<K, V> for EvictionListener<C, F>
+where
+    C: GetMut<K, V>,
+{
+    type Target = C::Target;
+
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.container.get_mut(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvictionListener;
+    use crate::tests::{test_get, test_get_mut, TestKey, TestValue};
+    use crate::Array;
+
+    #[test]
+    fn get() {
+        let ignore = |_: &TestKey, _: &TestValue| {};
+        test_get(EvictionListener::new(Array::new(0), ignore));
+        test_get(EvictionListener::new(Array::new(10), ignore));
+        test_get_mut(EvictionListener::new(Array::new(0), ignore));
+        test_get_mut(EvictionListener::new(Array::new(10), ignore));
+    }
+}
@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+mod eviction_listener;
+pub use eviction_listener::EvictionListener;
+pub(crate) mod builder;
+mod building_block;
+mod get;
+mod resizable;
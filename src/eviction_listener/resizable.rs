@@ -0,0 +1,44 @@
+use super::EvictionListener;
+use crate::Resizable;
+
+impl<K, V, C, F> Resizable<K, V> for EvictionListener<C, F>
+where
+    C: Resizable<K, V>,
+    F: FnMut(&K, &V),
+{
+    /// Change the capacity of the container wrapped in this
+    /// [`EvictionListener`].
+    ///
+    /// The victims of the wrapped container are reported to the listener.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        let out = self.container.resize(new_capacity);
+        self.notify(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvictionListener;
+    use crate::tests::{test_resizable, TestKey, TestValue};
+    use crate::{Array, BuildingBlock, Resizable};
+
+    #[test]
+    fn resizable() {
+        let ignore = |_: &TestKey, _: &TestValue| {};
+        test_resizable(EvictionListener::new(Array::new(0), ignore));
+        test_resizable(EvictionListener::new(Array::new(100), ignore));
+    }
+
+    #[test]
+    fn resize_evictions() {
+        let mut evicted = 0;
+        let mut c =
+            EvictionListener::new(Array::new(2), |_: &u16, _: &u32| {
+                evicted += 1
+            });
+        c.push(vec![(1, 1), (2, 2)]);
+        assert_eq!(c.resize(1).len(), 1);
+        drop(c);
+        assert_eq!(evicted, 1);
+    }
+}
@@ -0,0 +1,328 @@
+use super::Exclusive;
+use crate::{BuildingBlock, Resizable};
+use std::collections::BTreeMap;
+
+/// Bounded list of the keys most recently evicted from one side of an
+/// [`Exclusive`] container.
+pub(super) struct Ghosts<K> {
+    capacity: usize,
+    tick: u64,
+    // Keys ordered from least to most recently evicted.
+    order: BTreeMap<u64, K>,
+    // Keys with their position in `order`.
+    keys: BTreeMap<K, u64>,
+}
+
+impl<K: Ord> Ghosts<K> {
+    fn new(capacity: usize) -> Self {
+        Ghosts {
+            capacity,
+            tick: 0,
+            order: BTreeMap::new(),
+            keys: BTreeMap::new(),
+        }
+    }
+
+    /// Remember a `key` as the most recently evicted key.
+    fn insert(&mut self, key: &K, clone: fn(&K) -> K) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.remove(key);
+        self.tick += 1;
+        self.order.insert(self.tick, clone(key));
+        self.keys.insert(clone(key), self.tick);
+        self.resize(self.capacity);
+    }
+
+    /// Forget a `key` and return whether it was remembered.
+    fn remove(&mut self, key: &K) -> bool {
+        match self.keys.remove(key) {
+            None => false,
+            Some(tick) => {
+                self.order.remove(&tick);
+                true
+            }
+        }
+    }
+
+    /// Change the maximum number of keys remembered and forget the oldest
+    /// keys that do not fit anymore.
+    fn resize(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.order.len() > capacity {
+            let (_, key) = self.order.pop_first().unwrap();
+            self.keys.remove(&key);
+        }
+    }
+}
+
+/// State of an [`Exclusive`] container which capacity is periodically
+/// re-split between its front and back containers.
+#[allow(clippy::type_complexity)]
+pub(super) struct AdaptiveSizing<K, V, L, R> {
+    // Number of elements to push between two re-splits.
+    period: usize,
+    // Number of elements pushed since the last re-split.
+    pushed: usize,
+    // Hits in the back container on keys recently evicted from the front.
+    front_hits: usize,
+    // Insertions of keys recently evicted from the back.
+    back_hits: usize,
+    front_ghosts: Ghosts<K>,
+    back_ghosts: Ghosts<K>,
+    clone_key: fn(&K) -> K,
+    resplit: fn(&mut L, &mut R, usize, usize) -> Vec<(K, V)>,
+}
+
+/// Set the capacity of the `front` and `back` containers and return the
+/// elements that fit in neither of them.
+///
+/// The container shrinking is resized first such that the elements moving
+/// from the front to the back container find room there. Containers are
+/// resized with their [`resize()`](trait.Resizable.html#tymethod.resize)
+/// method which may panic if a container cannot be resized.
+fn resplit<K, V, L, R>(
+    front: &mut L,
+    back: &mut R,
+    front_capacity: usize,
+    back_capacity: usize,
+) -> Vec<(K, V)>
+where
+    L: BuildingBlock<K, V> + Resizable<K, V>,
+    R: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    if front_capacity < front.capacity() {
+        let victims = front.resize(front_capacity);
+        let mut out = back.resize(back_capacity);
+        out.append(&mut back.push(victims));
+        out
+    } else {
+        let mut out = back.resize(back_capacity);
+        out.append(&mut front.resize(front_capacity));
+        out
+    }
+}
+
+impl<K: Ord, V, L, R> AdaptiveSizing<K, V, L, R>
+where
+    L: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Count the `elements` to insert which keys were recently evicted
+    /// from the back container.
+    pub(super) fn count_back_hits(&mut self, elements: &[(K, V)]) {
+        for (k, _) in elements.iter() {
+            if self.back_ghosts.remove(k) {
+                self.back_hits += 1;
+            }
+        }
+    }
+
+    /// Count a hit in the back container on a key recently evicted from
+    /// the front container.
+    pub(super) fn count_front_hit(&mut self, key: &K) {
+        if self.front_ghosts.remove(key) {
+            self.front_hits += 1;
+        }
+    }
+
+    /// Remember the keys of `elements` evicted from the front container.
+    pub(super) fn front_evicted(&mut self, elements: &[(K, V)]) {
+        for (k, _) in elements.iter() {
+            self.front_ghosts.insert(k, self.clone_key);
+        }
+    }
+
+    /// Remember the keys of `elements` evicted from the back container.
+    pub(super) fn back_evicted(&mut self, elements: &[(K, V)]) {
+        for (k, _) in elements.iter() {
+            self.back_ghosts.insert(k, self.clone_key);
+        }
+    }
+
+    /// Match the number of remembered keys with the containers capacity.
+    pub(super) fn resize_ghosts(&mut self, front: &L, back: &R) {
+        self.front_ghosts.resize(front.capacity());
+        self.back_ghosts.resize(back.capacity());
+    }
+
+    /// Account for `n` pushed elements and re-split the containers
+    /// capacity if a period elapsed.
+    ///
+    /// A sixteenth of the total capacity moves toward the container with
+    /// the most ghost hits. The front container capacity is kept non-zero
+    /// such that elements can be accessed there. The elements that fit in
+    /// neither container after the re-split are returned.
+    ///
+    /// This method panics if the containers cannot be resized, e.g they
+    /// are [`DynBuildingBlock`](struct.DynBuildingBlock.html) wrapping
+    /// containers that are not resizable.
+    pub(super) fn adapt(
+        &mut self,
+        front: &mut L,
+        back: &mut R,
+        n: usize,
+    ) -> Vec<(K, V)> {
+        self.pushed += n;
+        if self.pushed < self.period {
+            return Vec::new();
+        }
+        let (front_hits, back_hits) = (self.front_hits, self.back_hits);
+        self.pushed = 0;
+        self.front_hits = 0;
+        self.back_hits = 0;
+
+        let front_capacity = front.capacity();
+        let budget = front_capacity + back.capacity();
+        let step = (budget / 16).max(1);
+        let new_front_capacity = if front_hits > back_hits {
+            (front_capacity + step).min(budget)
+        } else if back_hits > front_hits {
+            front_capacity.saturating_sub(step).max(budget.min(1))
+        } else {
+            front_capacity
+        };
+        if new_front_capacity == front_capacity {
+            return Vec::new();
+        }
+
+        let out = (self.resplit)(
+            front,
+            back,
+            new_front_capacity,
+            budget - new_front_capacity,
+        );
+        self.resize_ghosts(front, back);
+        out
+    }
+}
+
+impl<K, V, L, R> Exclusive<K, V, L, R>
+where
+    K: Ord + Clone,
+    L: BuildingBlock<K, V> + Resizable<K, V>,
+    R: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    /// Share the total capacity of the container between the front and
+    /// the back containers and periodically re-split it.
+    ///
+    /// The keys recently evicted from each container are remembered in a
+    /// ghost list, without their value. Ghost lists hold as many keys as
+    /// the capacity of the matching container. A hit in the back container
+    /// on a key of the front ghost list would have been a hit in the front
+    /// container if the latter was larger. An insertion of a key of the
+    /// back ghost list would have been a hit if the back container was
+    /// larger.
+    ///
+    /// Every `period` elements inserted with
+    /// [`push()`](trait.BuildingBlock.html#method.push), a sixteenth of
+    /// the total capacity is moved from one container to the other,
+    /// toward the container with the most ghost hits since the last
+    /// re-split, using their [`Resizable`](trait.Resizable.html)
+    /// implementation. Elements that fit in neither container after a
+    /// re-split are returned along with the elements returned by
+    /// [`push()`](trait.BuildingBlock.html#method.push).
+    ///
+    /// `period` must be strictly greater than 0. Both containers must be
+    /// resizable, otherwise
+    /// [`push()`](trait.BuildingBlock.html#method.push) panics on the
+    /// first re-split.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use byoc::{Array, BuildingBlock, Exclusive, Get};
+    ///
+    /// let mut c = Exclusive::new(Array::new(16), Array::new(16))
+    ///     .with_adaptive_sizing(4);
+    /// let elements: Vec<(u32, u32)> = (0..32).map(|i| (i, i)).collect();
+    /// c.push(elements);
+    ///
+    /// // Large values were evicted to the back. Accessing them grows the
+    /// // front container.
+    /// for i in 16..32 {
+    ///     assert!(c.get(&i).is_some());
+    /// }
+    /// c.push(vec![(32, 32), (33, 33), (34, 34), (35, 35)]);
+    /// assert!(c.front().capacity() > 16);
+    /// assert_eq!(c.capacity(), 32);
+    /// ```
+    pub fn with_adaptive_sizing(mut self, period: usize) -> Self {
+        if period == 0 {
+            panic!("Exclusive adaptive sizing period cannot be 0.");
+        }
+        self.adaptive = Some(AdaptiveSizing {
+            period,
+            pushed: 0,
+            front_hits: 0,
+            back_hits: 0,
+            front_ghosts: Ghosts::new(self.front.capacity()),
+            back_ghosts: Ghosts::new(self.back.capacity()),
+            clone_key: K::clone,
+            resplit: resplit::<K, V, L, R>,
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exclusive;
+    use crate::tests::{test_building_block, test_resizable};
+    use crate::{Array, BuildingBlock, DynBuildingBlock, Get, Sequential};
+
+    #[test]
+    fn building_block() {
+        test_building_block(
+            Exclusive::new(Array::new(10), Array::new(100))
+                .with_adaptive_sizing(10),
+            true,
+        );
+        test_resizable(
+            Exclusive::new(Array::new(10), Array::new(100))
+                .with_adaptive_sizing(10),
+        );
+    }
+
+    #[test]
+    fn adaptive_sizing() {
+        let mut c = Exclusive::new(Array::new(4), Array::new(12))
+            .with_adaptive_sizing(4);
+        c.push((0u16..16u16).map(|i| (i, i)).collect());
+        assert_eq!(c.front().capacity(), 4);
+
+        // Hits at the back on elements recently evicted from the front
+        // grow the front.
+        for i in 12u16..16u16 {
+            assert!(c.get(&i).is_some());
+        }
+        let evicted = c.push((16u16..20u16).map(|i| (i, i)).collect());
+        assert_eq!(c.front().capacity(), 5);
+        assert_eq!(c.back().capacity(), 11);
+        // The back container evicts one more element when it shrinks.
+        assert_eq!(evicted.len(), 5);
+
+        // Insertions of elements recently evicted from the back grow the
+        // back.
+        c.push(evicted);
+        assert_eq!(c.front().capacity(), 4);
+        assert_eq!(c.back().capacity(), 12);
+        assert_eq!(c.capacity(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn adaptive_sizing_not_resizable() {
+        let back = DynBuildingBlock::from(Sequential::new(Array::new(12)));
+        let mut c =
+            Exclusive::new(Array::new(4), back).with_adaptive_sizing(4);
+        c.push((0u16..16u16).map(|i| (i, i)).collect());
+        for i in 12u16..16u16 {
+            assert!(c.get(&i).is_some());
+        }
+
+        // The back container cannot grow the front container.
+        c.push((16u16..20u16).map(|i| (i, i)).collect());
+    }
+}
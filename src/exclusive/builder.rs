@@ -1,6 +1,6 @@
 use crate::builder::Build;
 use crate::BuildingBlock;
-use crate::Exclusive;
+use crate::{Exclusive, WritePolicy};
 use std::marker::PhantomData;
 
 /// `Exclusive` container builder.
@@ -31,10 +31,19 @@ use std::marker::PhantomData;
 ///     .exclusive(BTreeBuilder::new(2))
 ///     .build();
 /// container.push(vec![(1, 2)]);
+///
+/// // The write policy of the container can also be set.
+/// use byoc::WritePolicy;
+/// let mut container = ArrayBuilder::new(2)
+///     .exclusive(BTreeBuilder::new(2))
+///     .with_write_policy(WritePolicy::WriteThrough)
+///     .build();
+/// container.push(vec![(1, 2)]);
 /// ```
 pub struct ExclusiveBuilder<L, LB, R, RB> {
     pub(super) lbuilder: LB,
     pub(super) rbuilder: RB,
+    pub(super) write_policy: WritePolicy,
     unused: PhantomData<(L, R)>,
 }
 
@@ -47,6 +56,7 @@ where
         ExclusiveBuilder {
             lbuilder: self.lbuilder.clone(),
             rbuilder: self.rbuilder.clone(),
+            write_policy: self.write_policy,
             unused: PhantomData,
         }
     }
@@ -57,9 +67,17 @@ impl<L, LB, R, RB> ExclusiveBuilder<L, LB, R, RB> {
         Self {
             lbuilder,
             rbuilder,
+            write_policy: WritePolicy::default(),
             unused: PhantomData,
         }
     }
+
+    /// Set the [`WritePolicy`](../../enum.WritePolicy.html) of the
+    /// [`Exclusive`](../../struct.Exclusive.html) container to build.
+    pub fn with_write_policy(mut self, write_policy: WritePolicy) -> Self {
+        self.write_policy = write_policy;
+        self
+    }
 }
 
 impl<K, V, L, LB, R, RB> Build<Exclusive<K, V, L, R>>
@@ -72,6 +90,7 @@ where
 {
     fn build(self) -> Exclusive<K, V, L, R> {
         Exclusive::new(self.lbuilder.build(), self.rbuilder.build())
+            .with_write_policy(self.write_policy)
     }
 }
 
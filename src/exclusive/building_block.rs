@@ -18,29 +18,50 @@ where
     /// Get the size currently occupied by elements in this [`BuildingBlock`].
     ///
     /// This is the sum of the sizes held in the two containers that this
-    /// [`Exclusive`] container is composed of.
+    /// [`Exclusive`] container is composed of, plus one for each element
+    /// that fit in neither container after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call.
     fn size(&self) -> usize {
-        self.front.size() + self.back.size()
+        self.front.size() + self.back.size() + self.overflow.len()
     }
 
     /// Check if container contains a matching key.
     ///
     /// This method will lookup the front container first.
     /// If the the key is not found, then only it is searched in the back
-    /// container.
+    /// container and in the elements that fit in neither container.
     fn contains(&self, key: &K) -> bool {
-        self.front.contains(key) || self.back.contains(key)
+        self.front.contains(key)
+            || self.back.contains(key)
+            || self.overflow.iter().any(|(k, _)| k == key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.front.contains_if(key, predicate)
+            || self.back.contains_if(key, predicate)
+            || self.overflow.iter().any(|(k, v)| k == key && predicate(v))
     }
 
     /// Take the matching key/value pair out of the container.
     ///
     /// This method will lookup the front container first.
     /// If the the key is not found, then only it is searched in the back
-    /// container.
+    /// container and in the elements that fit in neither container.
     fn take(&mut self, key: &K) -> Option<(K, V)> {
+        self.write_dirty();
         match self.front.take(key) {
-            Some(x) => Some(x),
-            None => self.back.take(key),
+            Some(x) => {
+                self.dirty.remove(key);
+                Some(x)
+            }
+            None => {
+                self.back.take(key).or_else(|| self.take_overflow(key))
+            }
         }
     }
 
@@ -51,12 +72,15 @@ where
     /// the input `keys`. If some keys are left in the input `keys` vector, they
     /// are attemptively taken out of the back container. Keys that were
     /// successfully taken out of the back container are also removed from the
-    /// input `keys`. Finally, all the key/value pairs that were found either
-    /// in the front or the back container are returned in a vector.
+    /// input `keys`. The remaining keys are looked up in the elements that
+    /// fit in neither container the same way. Finally, all the key/value
+    /// pairs that were found are returned in a vector.
     fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
+        self.write_dirty();
         keys.sort();
 
         let mut front = self.front.take_multiple(keys);
+        Self::forget_dirty(&mut self.dirty, &front);
 
         // Remove matches from keys before querying on the back.
         for (k, _) in front.iter() {
@@ -75,6 +99,13 @@ where
             }
         }
 
+        for k in std::mem::take(keys).into_iter() {
+            match self.take_overflow(&k) {
+                Some(x) => back.push(x),
+                None => keys.push(k),
+            }
+        }
+
         // Return final matches.
         front.append(&mut back);
         front
@@ -82,7 +113,11 @@ where
 
     /// Free up to `size` space from the container.
     ///
-    /// This function first attempts to free `size` space from the back
+    /// The elements that fit in neither container after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are popped
+    /// first, with a size of `1` each.
+    /// This function then attempts to free the remaining size from the back
     /// container with its own [`pop()`](trait.BuildingBlock.html#method.pop)
     /// method. If less than `size` space was successfully freed, then
     /// the remaining size to free is popped from the front container also with
@@ -92,12 +127,22 @@ where
     /// the returned vector will contain all the container values and
     /// the container will be left empty.
     fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        self.write_dirty();
+        let n = size.min(self.overflow.len());
+        let mut v: Vec<(K, V)> = self.overflow.drain(..n).collect();
+        let size = size - n;
+        if size == 0 {
+            return v;
+        }
+
         let old_size = self.back.size();
-        let mut v = self.back.pop(size);
+        v.append(&mut self.back.pop(size));
         let evicted_size = old_size - self.back.size();
 
         if evicted_size < size {
-            v.append(&mut self.front.pop(size - evicted_size));
+            let mut front = self.front.pop(size - evicted_size);
+            Self::forget_dirty(&mut self.dirty, &front);
+            v.append(&mut front);
         }
         v
     }
@@ -108,8 +153,51 @@ where
     /// its own [`push()`](trait.BuildingBlock.html#method.push) method and will
     /// push all returned elements to the back container, also using its own
     /// [`push()`](trait.BuildingBlock.html#method.push) method.
+    ///
+    /// If the container was built
+    /// [`with_adaptive_sizing()`](struct.Exclusive.html#method.with_adaptive_sizing),
+    /// the capacity of the front and back containers may be re-split
+    /// afterward. Elements that fit in neither container after the
+    /// re-split are also returned.
+    ///
+    /// Elements that fit in neither container after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are also
+    /// returned.
     fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
-        self.back.push(self.front.push(elements))
+        self.write_dirty();
+        let mut out = std::mem::take(&mut self.overflow);
+        let adaptive = match &mut self.adaptive {
+            None => {
+                let popped = self.front.push(elements);
+                Self::forget_evicted(
+                    &mut self.dirty,
+                    &self.front,
+                    &popped,
+                );
+                out.append(&mut self.back.push(popped));
+                return out;
+            }
+            Some(adaptive) => adaptive,
+        };
+
+        let n = elements.len();
+        adaptive.count_back_hits(&elements);
+        let popped = self.front.push(elements);
+        Self::forget_evicted(&mut self.dirty, &self.front, &popped);
+        adaptive.front_evicted(&popped);
+        out.append(&mut self.back.push(popped));
+        out.append(&mut adaptive.adapt(
+            &mut self.front,
+            &mut self.back,
+            n,
+        ));
+        adaptive.back_evicted(&out);
+
+        // A re-split may move dirty values out of the front container.
+        let front = &self.front;
+        self.dirty.retain(|k| front.contains(k));
+        out
     }
 
     type FlushIterator = std::iter::Chain<
@@ -124,12 +212,17 @@ where
     ///
     /// 1. This method flushes first the back container.
     ///
-    /// 2. Then elements from the front container are flushed and pushed in the
-    /// back container. Popping element are chained to the flushed elements in
-    /// step 1.
+    /// 2. Then the elements that fit in neither container after a
+    ///    [`get()`](trait.Get.html#tymethod.get) or
+    ///    [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are
+    ///    chained to the flushed elements.
+    ///
+    /// 3. Then elements from the front container are flushed and pushed in
+    ///    the back container. Popping element are chained to the flushed
+    ///    elements in step 1 and 2.
     ///
-    /// 3. Elements in the back are flushed and chained to elements from the two
-    /// former steps.
+    /// 4. Elements in the back are flushed and chained to elements from the
+    ///    former steps.
     ///
     ///
     /// This is conveniently composable with
@@ -139,8 +232,11 @@ where
     /// element.
     fn flush(&mut self) -> Self::FlushIterator {
         let back = self.back.flush();
+        let mut overflow = std::mem::take(&mut self.overflow);
         let front = self.front.flush().collect();
-        let front = self.back.push(front).into_iter();
+        self.dirty.clear();
+        overflow.append(&mut self.back.push(front));
+        let front = overflow.into_iter();
         let new_back = self.back.flush();
         back.chain(front.chain(new_back))
     }
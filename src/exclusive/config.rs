@@ -4,7 +4,7 @@ use crate::config::{
     IntoConfig,
 };
 use crate::objsafe::DynBuildingBlock;
-use crate::Exclusive;
+use crate::{Exclusive, WritePolicy};
 use serde::{Deserialize, Serialize};
 
 /// Configuration format for [`Exclusive`](../struct.Exclusive.html)
@@ -22,12 +22,20 @@ use serde::{Deserialize, Serialize};
 /// [`Array`](../struct.Array.html) container. See
 /// [`ArrayConfig`](struct.ArrayConfig.html) for details on Array configuration
 /// format.
+///
+/// The optional `write_policy` field sets the container
+/// [`WritePolicy`](../enum.WritePolicy.html). Its value can be either
+/// "WriteBack" or "WriteThrough". With the "WriteBack" policy, the optional
+/// `max_dirty` field sets the maximum number of dirty values held in the
+/// front tier, unbounded by default. These fields must come before the `front` and `back` tables.
 /// ```
 /// use byoc::{BuildingBlock, DynBuildingBlock};
 /// use byoc::config::{ConfigInstance, ConfigBuilder};
 ///
 /// let config_str = format!("
 /// id='ExclusiveConfig'
+/// write_policy='WriteBack'
+/// max_dirty=4
 /// [front]
 /// id='ArrayConfig'
 /// capacity=10
@@ -44,10 +52,34 @@ use serde::{Deserialize, Serialize};
 pub struct ExclusiveConfig {
     #[allow(dead_code)]
     id: String,
+    write_policy: Option<String>,
+    max_dirty: Option<usize>,
     front: toml::Value,
     back: toml::Value,
 }
 
+impl ExclusiveConfig {
+    fn write_policy(&self) -> Result<WritePolicy, ConfigError> {
+        match (self.write_policy.as_deref(), self.max_dirty) {
+            (None, None) => Ok(WritePolicy::default()),
+            (None, Some(max_dirty)) | (Some("WriteBack"), Some(max_dirty)) => {
+                Ok(WritePolicy::WriteBack { max_dirty })
+            }
+            (Some("WriteBack"), None) => Ok(WritePolicy::default()),
+            (Some("WriteThrough"), None) => Ok(WritePolicy::WriteThrough),
+            (Some("WriteThrough"), Some(_)) => {
+                Err(ConfigError::ConfigFormatError(String::from(
+                    "Invalid ExclusiveConfig: max_dirty field is only valid with WriteBack write_policy.",
+                )))
+            }
+            (Some(s), _) => Err(ConfigError::ConfigFormatError(format!(
+                "Invalid ExclusiveConfig write_policy: {}",
+                s
+            ))),
+        }
+    }
+}
+
 impl<L, LB, R, RB> IntoConfig<ExclusiveConfig>
     for ExclusiveBuilder<L, LB, R, RB>
 where
@@ -64,8 +96,20 @@ where
         let front = toml::de::from_str(left_config_str.as_ref()).unwrap();
         let back = toml::de::from_str(right_config_str.as_ref()).unwrap();
 
+        let (write_policy, max_dirty) = match self.write_policy {
+            p if p == WritePolicy::default() => (None, None),
+            WritePolicy::WriteThrough => {
+                (Some(String::from("WriteThrough")), None)
+            }
+            WritePolicy::WriteBack { max_dirty } => {
+                (Some(String::from("WriteBack")), Some(max_dirty))
+            }
+        };
+
         ExclusiveConfig {
             id: String::from(ExclusiveConfig::id()),
+            write_policy,
+            max_dirty,
             front,
             back,
         }
@@ -83,6 +127,7 @@ impl ConfigInstance for ExclusiveConfig {
             Err(e) => return Err(ConfigError::TomlFormatError(e)),
             Ok(cfg) => cfg,
         };
+        cfg.write_policy()?;
         match (
             GenericConfig::from_toml(&cfg.front),
             GenericConfig::from_toml(&cfg.back),
@@ -96,13 +141,38 @@ impl ConfigInstance for ExclusiveConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        DynBuildingBlock::new(
-            Exclusive::new(
-                GenericConfig::from_toml(&self.front).unwrap().build(),
-                GenericConfig::from_toml(&self.back).unwrap().build(),
+        let is_resizable = self.is_resizable();
+        let write_policy = self.write_policy().unwrap();
+        let front = GenericConfig::from_toml(&self.front).unwrap().build();
+        let back = GenericConfig::from_toml(&self.back).unwrap().build();
+        // Only the front container is accessed in place.
+        match front.into_get() {
+            Ok(front) => DynBuildingBlock::new_resizable_get(
+                Exclusive::new(front, back)
+                    .with_write_policy(write_policy),
+                false,
+                is_resizable,
             ),
-            false,
-        )
+            Err(front) => DynBuildingBlock::new_resizable(
+                Exclusive::new(front, back)
+                    .with_write_policy(write_policy),
+                false,
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.front)
+            .unwrap()
+            .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.front)
+            .unwrap()
+            .is_resizable()
+            && GenericConfig::from_toml(&self.back).unwrap().is_resizable()
     }
 }
 
@@ -113,7 +183,7 @@ mod tests {
     use crate::config::tests::test_config_builder;
     use crate::config::{ConfigError, ConfigInstance};
     use crate::objsafe::DynBuildingBlock;
-    use crate::BuildingBlock;
+    use crate::{BuildingBlock, WritePolicy};
 
     #[test]
     fn test_valid_exclusive_config() {
@@ -155,12 +225,62 @@ toto='titi'
         ));
     }
 
+    #[test]
+    fn test_write_policy_exclusive_config() {
+        let config_str = "id='ExclusiveConfig'
+write_policy='WriteBack'
+max_dirty=2
+[front]
+id='ArrayConfig'
+capacity=10
+[back]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = ExclusiveConfig::from_toml(&value).unwrap();
+        assert_eq!(
+            config.write_policy().unwrap(),
+            WritePolicy::WriteBack { max_dirty: 2 }
+        );
+
+        for write_policy in ["WriteThrough", "Write"] {
+            let config_str = format!(
+                "id='ExclusiveConfig'
+write_policy='{}'
+max_dirty=2
+[front]
+id='ArrayConfig'
+capacity=10
+[back]
+id='ArrayConfig'
+capacity=10
+",
+                write_policy
+            );
+            let value: toml::Value =
+                toml::from_str(config_str.as_str()).unwrap();
+            assert!(matches!(
+                ExclusiveConfig::from_toml(&value),
+                Err(ConfigError::ConfigFormatError(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_builder_as_config() {
         let builder = ExclusiveBuilder::new(
             ArrayBuilder::<()>::new(2),
             ArrayBuilder::<()>::new(2),
         );
-        test_config_builder(builder);
+        test_config_builder(builder.clone());
+        test_config_builder(
+            builder.clone().with_write_policy(WritePolicy::WriteThrough),
+        );
+        test_config_builder(
+            builder.with_write_policy(WritePolicy::WriteBack {
+                max_dirty: 4,
+            }),
+        );
     }
 }
@@ -0,0 +1,50 @@
+use super::get::ExclusiveMutCell;
+use super::Exclusive;
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, GetEntry, GetMut};
+
+impl<K, V, L, R> GetEntry<K, V> for Exclusive<K, V, L, R>
+where
+    K: Ord + Clone,
+    L: GetMut<K, V> + BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    type Target = ExclusiveMutCell<K, V, L>;
+
+    /// Occupied entries are looked up like with
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut), i.e they are
+    /// moved to the front container if needed, and the container
+    /// [`WritePolicy`](enum.WritePolicy.html) applies to them. Vacant
+    /// entries are inserted with the [`Exclusive`]
+    /// [`push()`](trait.BuildingBlock.html#method.push) method.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        match self.get_mut(&key).map(LifeTimeGuard::unwrap) {
+            Some(value) => Entry::Occupied(OccupiedEntry::new(
+                key,
+                LifeTimeGuard::new(value),
+            )),
+            None => Entry::Vacant(VacantEntry::new(
+                key,
+                Box::new(move |k, v| self.push(vec![(k, v)])),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exclusive;
+    use crate::tests::test_entry;
+    use crate::{Array, WritePolicy};
+
+    #[test]
+    fn entry() {
+        test_entry(Exclusive::new(Array::new(10), Array::new(0)));
+        test_entry(Exclusive::new(Array::new(10), Array::new(100)));
+        test_entry(
+            Exclusive::new(Array::new(10), Array::new(100))
+                .with_write_policy(WritePolicy::WriteThrough),
+        );
+    }
+}
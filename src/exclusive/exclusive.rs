@@ -1,4 +1,7 @@
+use super::adaptive::AdaptiveSizing;
+use super::WritePolicy;
 use crate::BuildingBlock;
+use std::collections::BTreeSet;
 use std::marker::PhantomData;
 
 /// Multilevel `BuildingBlock` without duplicates between levels.
@@ -45,7 +48,42 @@ use std::marker::PhantomData;
 /// When their associated methods are called, if the element is not found in
 /// the front, but is found in the back, it is moved from the front to the back.
 /// Theredore, if the target element is found, it is returned from the front
-/// container always.
+/// container always. Elements popped out of the front container to make
+/// room for it that do not fit in the back container either are kept
+/// aside. They are still found by
+/// [`contains()`](trait.BuildingBlock.html#tymethod.contains),
+/// [`take()`](trait.BuildingBlock.html#tymethod.take) and
+/// [`pop()`](trait.BuildingBlock.html#tymethod.pop), counted with a size of
+/// `1` by [`size()`](trait.BuildingBlock.html#tymethod.size), and
+/// returned by the next call to
+/// [`push()`](trait.BuildingBlock.html#tymethod.push),
+/// [`flush()`](trait.BuildingBlock.html#tymethod.flush) or
+/// [`sync()`](struct.Exclusive.html#method.sync).
+/// [`try_get()`](struct.Exclusive.html#method.try_get) and
+/// [`try_get_mut()`](struct.Exclusive.html#method.try_get_mut) instead
+/// report these elements with an [`Error`](enum.Error.html).
+///
+/// ## Write Policy
+///
+/// Values modified in the front container through
+/// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) are marked dirty.
+/// The container [`WritePolicy`](enum.WritePolicy.html) decides when these
+/// values are written, i.e moved, to the back container. Dirty values
+/// reach the back container when they are evicted from the front, when
+/// [`sync()`](struct.Exclusive.html#method.sync) is called, or when the
+/// front holds more dirty values than the policy allows. Values released
+/// by [`get_mut()`](trait.GetMut.html#tymethod.get_mut) are written on the
+/// next access to the container. By default, the number of dirty values is
+/// not bounded. A different policy can be set with
+/// [`with_write_policy()`](struct.Exclusive.html#method.with_write_policy).
+///
+/// ## Adaptive Sizing
+///
+/// By default, the front and back containers keep the capacity they were
+/// built with. When both containers are [`Resizable`](trait.Resizable.html),
+/// their total capacity can instead be periodically re-split between them
+/// according to ghost hits statistics with
+/// [`with_adaptive_sizing()`](struct.Exclusive.html#method.with_adaptive_sizing).
 ///
 /// ## Examples
 ///
@@ -89,6 +127,15 @@ where
 {
     pub(super) front: L,
     pub(super) back: R,
+    pub(super) policy: WritePolicy,
+    pub(super) dirty: BTreeSet<K>,
+    // `Clone::clone()` of keys, set by `get_mut()` which is the only way
+    // to make values dirty.
+    pub(super) clone_key: Option<fn(&K) -> K>,
+    // Elements that fit in neither container after a `get()` or
+    // `get_mut()` call.
+    pub(super) overflow: Vec<(K, V)>,
+    pub(super) adaptive: Option<AdaptiveSizing<K, V, L, R>>,
     pub(super) unused: PhantomData<(K, V)>,
 }
 
@@ -102,55 +149,207 @@ where
         Exclusive {
             front,
             back,
+            policy: WritePolicy::default(),
+            dirty: BTreeSet::new(),
+            clone_key: None,
+            overflow: Vec::new(),
+            adaptive: None,
             unused: PhantomData,
         }
     }
 
+    /// Set the policy deciding when values modified in the front container
+    /// are written to the back container.
+    pub fn with_write_policy(mut self, policy: WritePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the policy deciding when values modified in the front container
+    /// are written to the back container.
+    pub fn write_policy(&self) -> WritePolicy {
+        self.policy
+    }
+
     /// Get shared access to the front container of the cache.
     pub fn front(&self) -> &L {
         &self.front
     }
 
-    /// Get exclusive access to the front container of the cache.
-    pub fn front_mut(&mut self) -> &mut L {
-        &mut self.front
-    }
-
     /// Get shared access to the back container of the cache.
     pub fn back(&self) -> &R {
         &self.back
     }
+}
+
+impl<K, V, L, R> Exclusive<K, V, L, R>
+where
+    K: Ord,
+    L: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Get exclusive access to the front container of the cache.
+    ///
+    /// Dirty values are written according to the container
+    /// [`WritePolicy`](enum.WritePolicy.html) first.
+    pub fn front_mut(&mut self) -> &mut L {
+        self.write_dirty();
+        &mut self.front
+    }
 
     /// Get exclusive access to the back container of the cache.
+    ///
+    /// Dirty values are written according to the container
+    /// [`WritePolicy`](enum.WritePolicy.html) first.
     pub fn back_mut(&mut self) -> &mut R {
+        self.write_dirty();
         &mut self.back
     }
 
-    /// Move an element from the back container to the front container
-    /// and return whether the element was here at all.
-    pub(super) fn downgrade(&mut self, key: &K) -> bool {
+    /// Write the dirty values to the back container if the container
+    /// [`WritePolicy`](enum.WritePolicy.html) requires it.
+    ///
+    /// Values are marked dirty when the smart pointer returned by
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) is dropped, which
+    /// cannot access the container. This method is called on the next
+    /// access instead. Elements that fit in neither container are kept
+    /// aside like after a [`get()`](trait.Get.html#tymethod.get) call.
+    pub(super) fn write_dirty(&mut self) {
+        match self.clone_key {
+            Some(clone) if self.policy.must_sync(self.dirty.len()) => {
+                let mut out = self.write_back(clone);
+                self.overflow.append(&mut out);
+            }
+            _ => {}
+        }
+    }
+
+    /// Move the dirty values from the front container to the back
+    /// container and return the elements that fit in neither container.
+    ///
+    /// See [`sync()`](struct.Exclusive.html#method.sync).
+    fn write_back(&mut self, clone: fn(&K) -> K) -> Vec<(K, V)> {
+        let dirty = std::mem::take(&mut self.dirty);
+        let mut keys: Vec<K> = dirty.iter().map(clone).collect();
+        let values = self.front.take_multiple(&mut keys);
+        if values.is_empty() {
+            return Vec::new();
+        }
+
+        let popped = self.back.push(values);
+        if popped.is_empty() {
+            return Vec::new();
+        }
+
+        for (k, _) in popped.iter() {
+            if dirty.contains(k) {
+                self.dirty.insert(clone(k));
+            }
+        }
+        let popped = self.front.push(popped);
+        Self::forget_evicted(&mut self.dirty, &self.front, &popped);
+        popped
+    }
+
+    /// Forget the dirty state of the keys of `elements` taken out of the
+    /// front container.
+    pub(super) fn forget_dirty(
+        dirty: &mut BTreeSet<K>,
+        elements: &[(K, V)],
+    ) {
+        for (k, _) in elements.iter() {
+            dirty.remove(k);
+        }
+    }
+
+    /// Forget the dirty state of the keys of `elements` returned by the
+    /// front container [`push()`](trait.BuildingBlock.html#tymethod.push).
+    ///
+    /// Values replaced by the push are returned while their key stays in
+    /// the front container and remains dirty.
+    pub(super) fn forget_evicted(
+        dirty: &mut BTreeSet<K>,
+        front: &L,
+        elements: &[(K, V)],
+    ) {
+        for (k, _) in elements.iter() {
+            if dirty.contains(k) && !front.contains(k) {
+                dirty.remove(k);
+            }
+        }
+    }
+
+    /// Take the element with `key` out of the elements that fit in neither
+    /// container after a [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call.
+    pub(super) fn take_overflow(&mut self, key: &K) -> Option<(K, V)> {
+        let i = self.overflow.iter().position(|(k, _)| k == key)?;
+        Some(self.overflow.remove(i))
+    }
+
+    /// Move an element from the back container, or from the elements kept
+    /// aside, to the front container.
+    ///
+    /// If the element is not found, `None` is returned. Else, the number of
+    /// elements kept aside by the move is returned.
+    ///
+    /// If the front container pops elements to make room for the moved
+    /// element and these elements do not fit in the back container, they
+    /// are kept aside until the next call to
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push),
+    /// [`flush()`](trait.BuildingBlock.html#tymethod.flush) or
+    /// [`sync()`](struct.Exclusive.html#method.sync).
+    pub(super) fn downgrade(&mut self, key: &K) -> Option<usize> {
         // Lookup in the back stage of the cache.
-        let x = match self.back.take(key) {
-            // If element is not there, there's no downgrade possible.
-            None => return false,
-            Some(x) => x,
-        };
+        // If element is not there, there's no downgrade possible.
+        let x = self.back.take(key).or_else(|| self.take_overflow(key))?;
+
+        if let Some(adaptive) = &mut self.adaptive {
+            adaptive.count_front_hit(&x.0);
+        }
 
         // Insert element in the front stage of the cache.
         let popped = self.front.push(vec![x]);
         if popped.is_empty() {
-            return true;
+            return Some(0);
+        }
+        Self::forget_evicted(&mut self.dirty, &self.front, &popped);
+        if let Some(adaptive) = &mut self.adaptive {
+            adaptive.front_evicted(&popped);
         }
 
         // If Some elements were popped we try to insert them at the back.
-        let popped = self.back.push(popped);
-        if popped.is_empty() {
-            return true;
-        }
+        // Elements popping out of the back have nowhere to go and are kept
+        // aside.
+        let mut popped = self.back.push(popped);
+        let n = popped.len();
+        self.overflow.append(&mut popped);
+        Some(n)
+    }
+}
 
-        // If we have yet more elements popping out, we have nowhere to put them
-        // and we cannot roll back, so we have to abort.
-        panic!("Failure to move an element from the back container of the exclusive cache to the front. This happened because we tried to move an element from the back container to the front, then the front container popped elements to make room for the former element, and finally, the back container was not able to fit the popped elements.")
+impl<K, V, L, R> Exclusive<K, V, L, R>
+where
+    K: Ord + Clone,
+    L: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Write all the dirty values of the front container to the back
+    /// container.
+    ///
+    /// Dirty values are moved from the front container to the back
+    /// container. If the back container does not accept some of them, or
+    /// pops other elements to make room for them, the elements coming out
+    /// of the back container are pushed back to the front container.
+    /// Dirty values that could not be written remain dirty.
+    /// Elements that fit in neither container are returned, along with
+    /// the elements that fit in neither container after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call.
+    pub fn sync(&mut self) -> Vec<(K, V)> {
+        let mut out = std::mem::take(&mut self.overflow);
+        out.append(&mut self.write_back(K::clone));
+        out
     }
 }
 
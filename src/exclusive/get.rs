@@ -1,46 +1,191 @@
 use super::Exclusive;
 use crate::utils::get::LifeTimeGuard;
-use crate::{BuildingBlock, Get, GetMut};
+use crate::{BuildingBlock, Error, Get, GetMut};
+use std::collections::BTreeSet;
+use std::ops::{Deref, DerefMut};
+
+/// Read-write cell holding a value inside the front container of an
+/// [`Exclusive`](struct.Exclusive.html) container.
+///
+/// If the value inside an `ExclusiveMutCell` is modified via a call to
+/// `deref_mut()`, then it is marked dirty when the `ExclusiveMutCell` is
+/// destroyed. The container
+/// [`WritePolicy`](enum.WritePolicy.html) is applied on the next access to
+/// the container. If writing dirty values pops elements that fit in
+/// neither container, these elements are returned by the next call to
+/// [`push()`](trait.BuildingBlock.html#tymethod.push) or
+/// [`sync()`](struct.Exclusive.html#method.sync).
+pub struct ExclusiveMutCell<K, V, L>
+where
+    K: Ord + Clone,
+    L: GetMut<K, V>,
+{
+    value: L::Target,
+    key: K,
+    is_dirty: bool,
+    dirty: *mut BTreeSet<K>,
+}
+
+impl<K, V, L> Deref for ExclusiveMutCell<K, V, L>
+where
+    K: Ord + Clone,
+    L: GetMut<K, V>,
+{
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        self.value.deref()
+    }
+}
+
+impl<K, V, L> DerefMut for ExclusiveMutCell<K, V, L>
+where
+    K: Ord + Clone,
+    L: GetMut<K, V>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.is_dirty = true;
+        self.value.deref_mut()
+    }
+}
+
+impl<K, V, L> Drop for ExclusiveMutCell<K, V, L>
+where
+    K: Ord + Clone,
+    L: GetMut<K, V>,
+{
+    fn drop(&mut self) {
+        if self.is_dirty {
+            // SAFETY:
+            // This cell is wrapped in a `LifeTimeGuard` bound to the
+            // lifetime of the mutable borrow of the container that created
+            // it. The dirty set is a field of this container distinct from
+            // the front container holding the value, and nothing else
+            // accesses it while the cell is alive.
+            let dirty = unsafe { self.dirty.as_mut().unwrap() };
+            dirty.insert(self.key.clone());
+        }
+    }
+}
+
+impl<K, V, L, R> Exclusive<K, V, L, R>
+where
+    K: Ord,
+    L: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Move the element with `key` to the front container if needed and
+    /// return whether it was found, or an error if moving it left
+    /// elements out of both containers.
+    fn try_downgrade(&mut self, key: &K) -> Result<bool, Error> {
+        self.write_dirty();
+        if self.front.contains(key) {
+            return Ok(true);
+        }
+        match self.downgrade(key) {
+            None => Ok(false),
+            Some(0) => Ok(true),
+            Some(_) => Err(Error::Overflow),
+        }
+    }
+
+    /// Fallible variant of [`get()`](trait.Get.html#tymethod.get).
+    ///
+    /// If moving the element from the back container to the front
+    /// container pops elements that fit in neither container,
+    /// [`Error::Overflow`](enum.Error.html#variant.Overflow) is returned
+    /// instead of the element. The element is in the front container
+    /// nonetheless and the elements left out are kept aside like with
+    /// [`get()`](trait.Get.html#tymethod.get).
+    pub fn try_get(
+        &mut self,
+        key: &K,
+    ) -> Result<Option<LifeTimeGuard<'_, L::Target>>, Error>
+    where
+        L: Get<K, V>,
+    {
+        if self.try_downgrade(key)? {
+            Ok(self.front.get(key))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Fallible variant of
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut).
+    ///
+    /// See [`try_get()`](struct.Exclusive.html#method.try_get).
+    #[allow(clippy::type_complexity)]
+    pub fn try_get_mut(
+        &mut self,
+        key: &K,
+    ) -> Result<
+        Option<LifeTimeGuard<'_, <Self as GetMut<K, V>>::Target>>,
+        Error,
+    >
+    where
+        K: Clone,
+        L: GetMut<K, V>,
+    {
+        if self.try_downgrade(key)? {
+            Ok(self.get_mut(key))
+        } else {
+            Ok(None)
+        }
+    }
+}
 
 impl<K, V, L, R> Get<K, V> for Exclusive<K, V, L, R>
 where
+    K: Ord,
     L: Get<K, V> + BuildingBlock<K, V>,
     R: BuildingBlock<K, V>,
 {
     type Target = L::Target;
 
     fn get(&mut self, key: &K) -> Option<LifeTimeGuard<Self::Target>> {
+        self.write_dirty();
+
         // Lookup in the front stage of the cache.
         // If element is there return it.
         if self.front.contains(key) {
             return self.front.get(key);
         };
 
-        match self.downgrade(key) {
-            true => self.front.get(key),
-            false => None,
+        if self.downgrade(key).is_some() {
+            self.front.get(key)
+        } else {
+            None
         }
     }
 }
 
 impl<K, V, L, R> GetMut<K, V> for Exclusive<K, V, L, R>
 where
+    K: Ord + Clone,
     L: GetMut<K, V> + BuildingBlock<K, V>,
     R: BuildingBlock<K, V>,
 {
-    type Target = L::Target;
+    type Target = ExclusiveMutCell<K, V, L>;
 
     fn get_mut(&mut self, key: &K) -> Option<LifeTimeGuard<Self::Target>> {
-        // Lookup in the front stage of the cache.
-        // If element is there return it.
-        if self.front.contains(key) {
-            return self.front.get_mut(key);
-        };
+        self.write_dirty();
 
-        match self.downgrade(key) {
-            true => self.front.get_mut(key),
-            false => None,
+        // Lookup in the front stage of the cache.
+        // If element is not there, move it from the back.
+        if !self.front.contains(key) && self.downgrade(key).is_none() {
+            return None;
         }
+
+        self.clone_key = Some(K::clone);
+        let dirty: *mut BTreeSet<K> = &mut self.dirty;
+        self.front.get_mut(key).map(|value| {
+            LifeTimeGuard::new(ExclusiveMutCell {
+                value: value.unwrap(),
+                key: key.clone(),
+                is_dirty: false,
+                dirty,
+            })
+        })
     }
 }
 
@@ -48,7 +193,7 @@ where
 mod tests {
     use super::Exclusive;
     use crate::tests::{test_get, test_get_mut};
-    use crate::Array;
+    use crate::{Array, BuildingBlock, Error, Get, GetMut, WritePolicy};
 
     #[test]
     fn get() {
@@ -57,4 +202,191 @@ mod tests {
         test_get_mut(Exclusive::new(Array::new(10), Array::new(0)));
         test_get_mut(Exclusive::new(Array::new(10), Array::new(100)));
     }
+
+    #[test]
+    fn get_write_through() {
+        let policy = WritePolicy::WriteThrough;
+        test_get_mut(
+            Exclusive::new(Array::new(10), Array::new(100))
+                .with_write_policy(policy),
+        );
+
+        let mut c = Exclusive::new(Array::new(2), Array::new(2))
+            .with_write_policy(policy);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+
+        // Read-only access through get_mut() does not write the value.
+        assert_eq!(*c.get_mut(&0u16).unwrap(), 0u32);
+        assert!(c.front().contains(&0u16));
+
+        // Modified values are written to the back on the next access.
+        *c.get_mut(&0u16).unwrap() = 2u32;
+        assert!(c.front().contains(&0u16));
+        assert_eq!(c.back_mut().take(&0u16), Some((0u16, 2u32)));
+        assert!(!c.front().contains(&0u16));
+    }
+
+    #[test]
+    fn get_write_back() {
+        let policy = WritePolicy::WriteBack { max_dirty: 1 };
+        test_get_mut(
+            Exclusive::new(Array::new(10), Array::new(100))
+                .with_write_policy(policy),
+        );
+
+        let mut c = Exclusive::new(Array::new(3), Array::new(3))
+            .with_write_policy(policy);
+        c.push(vec![(0u16, 0u32), (1u16, 1u32), (2u16, 2u32)]);
+
+        // The first dirty value stays in the front.
+        *c.get_mut(&0u16).unwrap() = 3u32;
+        assert!(c.front().contains(&0u16));
+        assert_eq!(c.back().size(), 0);
+
+        // Dirty values are written when there are too many of them.
+        *c.get_mut(&1u16).unwrap() = 4u32;
+        assert!(c.push(Vec::new()).is_empty());
+        assert!(!c.front().contains(&0u16));
+        assert!(!c.front().contains(&1u16));
+        assert_eq!(c.back().size(), 2);
+
+        // Dirty values can also be written explicitly.
+        *c.get_mut(&2u16).unwrap() = 5u32;
+        assert!(c.front().contains(&2u16));
+        assert!(c.sync().is_empty());
+        assert!(!c.front().contains(&2u16));
+        assert_eq!(c.back().size(), 3);
+        assert_eq!(c.take(&2u16), Some((2u16, 5u32)));
+    }
+
+    #[test]
+    fn get_write_back_unbounded() {
+        let mut c = Exclusive::new(Array::new(100), Array::new(100));
+        c.push((0..100u16).map(|i| (i, i as u32)).collect());
+
+        // By default, dirty values are only written on sync().
+        for i in 0..100u16 {
+            *c.get_mut(&i).unwrap() += 1;
+        }
+        assert!(c.push(Vec::new()).is_empty());
+        assert_eq!(c.front().size(), 100);
+        assert_eq!(c.dirty.len(), 100);
+        assert!(c.sync().is_empty());
+        assert_eq!(c.back().size(), 100);
+    }
+
+    #[test]
+    fn sync_back_full() {
+        let mut c = Exclusive::new(Array::new(2), Array::new(1));
+        c.front_mut().push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        c.back_mut().push(vec![(2u16, 2u32)]);
+
+        // Elements popped out of the back to make room for dirty values
+        // are moved to the front.
+        *c.get_mut(&1u16).unwrap() = 3u32;
+        assert!(c.sync().is_empty());
+        assert_eq!(c.back_mut().take(&1u16), Some((1u16, 3u32)));
+        assert!(c.front().contains(&2u16));
+    }
+
+    #[test]
+    fn dirty_keys_leave_front() {
+        let mut c = Exclusive::new(Array::new(2), Array::new(10));
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+
+        *c.get_mut(&0u16).unwrap() = 5u32;
+        assert_eq!(c.dirty.len(), 1);
+        assert_eq!(c.take(&0u16), Some((0u16, 5u32)));
+        assert!(c.dirty.is_empty());
+
+        // Evicted dirty values are not dirty anymore.
+        *c.get_mut(&1u16).unwrap() = 6u32;
+        assert!(c.push(vec![(2u16, 2u32), (3u16, 3u32)]).is_empty());
+        assert!(!c.front().contains(&1u16));
+        assert!(c.dirty.is_empty());
+
+        *c.get_mut(&2u16).unwrap() = 7u32;
+        assert_eq!(c.flush().count(), 3);
+        assert!(c.dirty.is_empty());
+    }
+
+    #[test]
+    fn sync_overflow() {
+        let mut c = Exclusive::new(
+            Array::new(2),
+            Array::new(2)
+                .with_element_size(|(_, v): &(u16, u32)| *v as usize),
+        )
+        .with_write_policy(WritePolicy::WriteThrough);
+        c.front_mut().push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        c.back_mut().push(vec![(2u16, 1u32), (3u16, 1u32)]);
+
+        // The written value pops two elements out of the back that do
+        // not all fit in the front. The element left out is returned later.
+        *c.get_mut(&0u16).unwrap() = 2u32;
+        assert_eq!(c.sync().len(), 1);
+        assert!(c.sync().is_empty());
+        assert!(c.back().contains(&0u16));
+    }
+
+    #[test]
+    fn get_overflow() {
+        let mut c = Exclusive::new(
+            Array::new(1),
+            Array::new(2)
+                .with_element_size(|(_, v): &(u16, u32)| *v as usize),
+        );
+        c.front_mut().push(vec![(0u16, 2u32)]);
+        c.back_mut().push(vec![(1u16, 1u32), (2u16, 1u32)]);
+
+        // Moving the value to the front pops an element out of the front
+        // that pops another one out of the back. The element left out is
+        // returned later.
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+        assert!(c.contains(&2u16));
+        assert_eq!(c.size(), 4);
+        assert_eq!(c.push(Vec::new()), vec![(2u16, 1u32)]);
+        assert!(c.back().contains(&0u16));
+        assert!(c.front().contains(&1u16));
+    }
+
+    #[test]
+    fn get_overflow_kept() {
+        let new = || {
+            let mut c = Exclusive::new(
+                Array::new(1),
+                Array::new(2)
+                    .with_element_size(|(_, v): &(u16, u32)| *v as usize),
+            );
+            c.front_mut().push(vec![(0u16, 2u32)]);
+            c.back_mut().push(vec![(1u16, 1u32), (2u16, 1u32)]);
+            c
+        };
+
+        // Elements left out are still in the container.
+        let mut c = new();
+        assert!(c.get(&1u16).is_some());
+        let mut flushed: Vec<u16> = c.flush().map(|(k, _)| k).collect();
+        flushed.sort();
+        assert_eq!(flushed, vec![0u16, 1u16, 2u16]);
+
+        let mut c = new();
+        assert!(c.get(&1u16).is_some());
+        assert_eq!(c.take(&2u16), Some((2u16, 1u32)));
+        assert!(!c.contains(&2u16));
+        assert!(c.push(Vec::new()).is_empty());
+
+        // The elements left out are moved back to the front on access.
+        let mut c = new();
+        assert!(c.get(&1u16).is_some());
+        assert_eq!(*c.get(&2u16).unwrap(), 1u32);
+        assert!(c.front().contains(&2u16));
+
+        // Fallible accesses report the elements left out.
+        let mut c = new();
+        assert!(matches!(c.try_get(&1u16), Err(Error::Overflow)));
+        assert!(c.contains(&2u16));
+        assert_eq!(*c.try_get(&1u16).unwrap().unwrap(), 1u32);
+        assert!(c.try_get(&3u16).unwrap().is_none());
+    }
 }
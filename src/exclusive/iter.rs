@@ -0,0 +1,34 @@
+use super::Exclusive;
+use crate::{BuildingBlock, Iter};
+
+impl<K, V, L, R> Iter<K, V> for Exclusive<K, V, L, R>
+where
+    L: BuildingBlock<K, V> + Iter<K, V>,
+    R: BuildingBlock<K, V> + Iter<K, V>,
+{
+    type Iterator<'a>
+        = std::iter::Chain<L::Iterator<'a>, R::Iterator<'a>>
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of the front container then over the
+    /// elements of the back container.
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.front.iter().chain(self.back.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exclusive;
+    use crate::tests::test_iter;
+    use crate::Array;
+
+    #[test]
+    fn iter() {
+        test_iter(Exclusive::new(Array::new(0), Array::new(0)));
+        test_iter(Exclusive::new(Array::new(0), Array::new(10)));
+        test_iter(Exclusive::new(Array::new(10), Array::new(0)));
+        test_iter(Exclusive::new(Array::new(10), Array::new(100)));
+    }
+}
@@ -1,8 +1,16 @@
 #[allow(clippy::module_inception)]
 mod exclusive;
 pub use exclusive::Exclusive;
+mod adaptive;
 pub(crate) mod builder;
 mod building_block;
 #[cfg(feature = "config")]
 pub(crate) mod config;
+mod entry;
 mod get;
+mod iter;
+mod range;
+mod resizable;
+mod try_building_block;
+mod write_policy;
+pub use write_policy::WritePolicy;
@@ -0,0 +1,81 @@
+use super::Exclusive;
+use crate::{BuildingBlock, RangeGet, RangeTake};
+use std::ops::RangeBounds;
+
+impl<K, V, L, R> RangeGet<K, V> for Exclusive<K, V, L, R>
+where
+    K: Ord + Clone,
+    L: BuildingBlock<K, V> + RangeGet<K, V>,
+    R: BuildingBlock<K, V> + RangeGet<K, V>,
+{
+    /// Check whether the container contains at least one key within
+    /// `range`.
+    ///
+    /// This method will lookup the front container first.
+    /// If no key is found, then only the back container is searched.
+    fn contains_range<B: RangeBounds<K>>(&self, range: B) -> bool {
+        let bounds = (range.start_bound(), range.end_bound());
+        self.front.contains_range(bounds)
+            || self.back.contains_range(bounds)
+            || self.overflow.iter().any(|(k, _)| bounds.contains(k))
+    }
+
+    /// Get the keys of the container within `range` sorted in ascending
+    /// order.
+    ///
+    /// The keys found in the front and the back containers are merged
+    /// together.
+    fn keys_in_range<B: RangeBounds<K>>(&self, range: B) -> Vec<K> {
+        let bounds = (range.start_bound(), range.end_bound());
+        let mut keys = self.front.keys_in_range(bounds);
+        keys.append(&mut self.back.keys_in_range(bounds));
+        keys.extend(
+            self.overflow
+                .iter()
+                .filter(|(k, _)| bounds.contains(k))
+                .map(|(k, _)| k.clone()),
+        );
+        keys.sort();
+        keys
+    }
+}
+
+impl<K, V, L, R> RangeTake<K, V> for Exclusive<K, V, L, R>
+where
+    K: Ord,
+    L: BuildingBlock<K, V> + RangeTake<K, V>,
+    R: BuildingBlock<K, V> + RangeTake<K, V>,
+{
+    /// Take all the key/value pairs with a key within `range` out of the
+    /// container.
+    ///
+    /// Elements are taken out of the front container first, and then out
+    /// of the back container.
+    fn take_range<B: RangeBounds<K>>(&mut self, range: B) -> Vec<(K, V)> {
+        self.write_dirty();
+        let bounds = (range.start_bound(), range.end_bound());
+        let mut out = self.front.take_range(bounds);
+        out.append(&mut self.back.take_range(bounds));
+        let (mut taken, overflow) = std::mem::take(&mut self.overflow)
+            .into_iter()
+            .partition(|(k, _)| bounds.contains(k));
+        self.overflow = overflow;
+        out.append(&mut taken);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exclusive;
+    use crate::tests::test_range;
+    use crate::{Array, BTree};
+
+    #[test]
+    fn range() {
+        test_range(Exclusive::new(Array::new(0), Array::new(0)));
+        test_range(Exclusive::new(Array::new(0), Array::new(10)));
+        test_range(Exclusive::new(Array::new(10), Array::new(0)));
+        test_range(Exclusive::new(Array::new(10), BTree::new(100)));
+    }
+}
@@ -0,0 +1,97 @@
+use super::Exclusive;
+use crate::utils::size::split_capacity;
+use crate::{BuildingBlock, Error, Resizable};
+
+impl<K, V, L, R> Resizable<K, V> for Exclusive<K, V, L, R>
+where
+    K: Ord,
+    L: BuildingBlock<K, V> + Resizable<K, V>,
+    R: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    /// Change the [`Exclusive`] container capacity.
+    ///
+    /// The new capacity is split between the front and the back containers
+    /// proportionally to their current capacities. The back container is
+    /// resized first. Then the front container is resized and its victims
+    /// are pushed to the back container. Elements that do not fit in the back
+    /// container are returned.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.write_dirty();
+        let split = split_capacity(
+            &[self.front.capacity(), self.back.capacity()],
+            new_capacity,
+        );
+        let mut out = self.back.resize(split[1]);
+        let front = self.front.resize(split[0]);
+        Self::forget_dirty(&mut self.dirty, &front);
+        out.append(&mut self.back.push(front));
+        if let Some(adaptive) = &mut self.adaptive {
+            adaptive.resize_ghosts(&self.front, &self.back);
+        }
+        out
+    }
+
+    /// Change the [`Exclusive`] container capacity if both the front and
+    /// the back containers can be resized. Otherwise, the container is
+    /// left unchanged and an error is returned.
+    ///
+    /// The containers are resized with their
+    /// [`try_resize()`](trait.Resizable.html#method.try_resize) method.
+    /// If the back container is resized but the front container cannot
+    /// be, the back container is restored to its former capacity and its
+    /// victims are pushed back in it.
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.write_dirty();
+        let split = split_capacity(
+            &[self.front.capacity(), self.back.capacity()],
+            new_capacity,
+        );
+        let back_capacity = self.back.capacity();
+        let mut out = self.back.try_resize(split[1])?;
+        let front = match self.front.try_resize(split[0]) {
+            Ok(front) => front,
+            Err(e) => {
+                self.back.resize(back_capacity);
+                self.overflow.append(&mut self.back.push(out));
+                return Err(e);
+            }
+        };
+        Self::forget_dirty(&mut self.dirty, &front);
+        out.append(&mut self.back.push(front));
+        if let Some(adaptive) = &mut self.adaptive {
+            adaptive.resize_ghosts(&self.front, &self.back);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exclusive;
+    use crate::tests::test_resizable;
+    use crate::{
+        Array, BuildingBlock, DynBuildingBlock, Error, Resizable,
+        Sequential,
+    };
+
+    #[test]
+    fn resizable() {
+        test_resizable(Exclusive::new(Array::new(0), Array::new(0)));
+        test_resizable(Exclusive::new(Array::new(0), Array::new(10)));
+        test_resizable(Exclusive::new(Array::new(10), Array::new(0)));
+        test_resizable(Exclusive::new(Array::new(10), Array::new(100)));
+    }
+
+    #[test]
+    fn try_resize_not_resizable() {
+        let front = DynBuildingBlock::from(Sequential::new(Array::new(2)));
+        let mut c = Exclusive::new(front, Array::new(4));
+        c.push((0u16..6u16).map(|i| (i, i)).collect());
+        assert!(matches!(c.try_resize(2), Err(Error::NotResizable)));
+        assert_eq!(c.back().capacity(), 4);
+        assert_eq!(c.size(), 6);
+    }
+}
@@ -0,0 +1,161 @@
+use super::Exclusive;
+use crate::{Error, TryBuildingBlock};
+
+impl<K, V, L, R> TryBuildingBlock<K, V> for Exclusive<K, V, L, R>
+where
+    K: Ord,
+    L: TryBuildingBlock<K, V>,
+    R: TryBuildingBlock<K, V>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        Ok(self.front.try_capacity()? + self.back.try_capacity()?)
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        Ok(self.front.try_size()?
+            + self.back.try_size()?
+            + self.overflow.len())
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        Ok(self.front.try_contains(key)?
+            || self.back.try_contains(key)?
+            || self.overflow.iter().any(|(k, _)| k == key))
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        self.write_dirty();
+        match self.front.try_take(key)? {
+            Some(x) => {
+                self.dirty.remove(key);
+                Ok(Some(x))
+            }
+            None => match self.back.try_take(key)? {
+                Some(x) => Ok(Some(x)),
+                None => Ok(self.take_overflow(key)),
+            },
+        }
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.write_dirty();
+        keys.sort();
+
+        let mut front = self.front.try_take_multiple(keys)?;
+        Self::forget_dirty(&mut self.dirty, &front);
+        for (k, _) in front.iter() {
+            if let Ok(i) = keys.binary_search(k) {
+                keys.remove(i);
+            }
+        }
+
+        let mut back = self.back.try_take_multiple(keys)?;
+        for (k, _) in back.iter() {
+            if let Ok(i) = keys.binary_search(k) {
+                keys.remove(i);
+            }
+        }
+        for k in std::mem::take(keys).into_iter() {
+            match self.take_overflow(&k) {
+                Some(x) => back.push(x),
+                None => keys.push(k),
+            }
+        }
+
+        front.append(&mut back);
+        Ok(front)
+    }
+
+    fn try_pop(&mut self, size: usize) -> Result<Vec<(K, V)>, Error> {
+        self.write_dirty();
+        let n = size.min(self.overflow.len());
+        let mut v: Vec<(K, V)> = self.overflow.drain(..n).collect();
+        let size = size - n;
+        if size == 0 {
+            return Ok(v);
+        }
+
+        let old_size = self.back.try_size()?;
+        v.append(&mut self.back.try_pop(size)?);
+        let evicted_size = old_size - self.back.try_size()?;
+
+        if evicted_size < size {
+            let mut front = self.front.try_pop(size - evicted_size)?;
+            Self::forget_dirty(&mut self.dirty, &front);
+            v.append(&mut front);
+        }
+        Ok(v)
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.write_dirty();
+        let popped = self.front.try_push(elements)?;
+        for (k, _) in popped.iter() {
+            if self.dirty.contains(k) && !self.front.try_contains(k)? {
+                self.dirty.remove(k);
+            }
+        }
+        let mut popped = self.back.try_push(popped)?;
+        let mut out = std::mem::take(&mut self.overflow);
+        out.append(&mut popped);
+        Ok(out)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        let mut out = self.back.try_flush()?;
+        out.append(&mut self.overflow);
+        let front = self.front.try_flush()?;
+        self.dirty.clear();
+        out.append(&mut self.back.try_push(front)?);
+        out.append(&mut self.back.try_flush()?);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exclusive;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(
+            Exclusive::new(Array::new(0), Array::new(0)),
+            true,
+        );
+        test_try_building_block(
+            Exclusive::new(Array::new(0), Array::new(10)),
+            true,
+        );
+        test_try_building_block(
+            Exclusive::new(Array::new(10), Array::new(0)),
+            true,
+        );
+        test_try_building_block(
+            Exclusive::new(Array::new(10), Array::new(100)),
+            true,
+        );
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn try_building_block_stream() {
+        use crate::stream::ByteStream;
+        use crate::stream::VecStreamFactory;
+
+        test_try_building_block(
+            Exclusive::new(
+                Array::new(10),
+                ByteStream::new(VecStreamFactory {}, 100),
+            ),
+            true,
+        );
+    }
+}
@@ -0,0 +1,44 @@
+/// Policy deciding when values modified in the front container of an
+/// [`Exclusive`](struct.Exclusive.html) container are written to its back
+/// container.
+///
+/// A value is considered modified, or "dirty", when it was accessed with
+/// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) and dereferenced
+/// mutably. Since [`Exclusive`](struct.Exclusive.html) containers do not
+/// hold duplicates, writing a dirty value to the back container moves it
+/// out of the front container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WritePolicy {
+    /// Dirty values are tracked and written to the back container when
+    /// they are evicted from the front container, when
+    /// [`sync()`](struct.Exclusive.html#method.sync) is called or on the
+    /// next access to the container once more than `max_dirty` dirty
+    /// values are held in the front container.
+    WriteBack { max_dirty: usize },
+    /// Dirty values are written to the back container on the next access
+    /// to the container after the smart pointer returned by
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) is dropped.
+    WriteThrough,
+}
+
+impl Default for WritePolicy {
+    /// Write-back policy with no bound on the number of dirty values, i.e
+    /// dirty values are only written when evicted from the front container
+    /// or on [`sync()`](struct.Exclusive.html#method.sync).
+    fn default() -> Self {
+        WritePolicy::WriteBack {
+            max_dirty: usize::MAX,
+        }
+    }
+}
+
+impl WritePolicy {
+    /// Whether a container holding `num_dirty` dirty values in its front
+    /// container must write them to its back container.
+    pub(super) fn must_sync(&self, num_dirty: usize) -> bool {
+        match self {
+            WritePolicy::WriteBack { max_dirty } => num_dirty > *max_dirty,
+            WritePolicy::WriteThrough => num_dirty > 0,
+        }
+    }
+}
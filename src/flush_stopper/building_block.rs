@@ -14,6 +14,13 @@ where
     fn contains(&self, key: &K) -> bool {
         self.container.contains(key)
     }
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, predicate)
+    }
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         self.container.take(key)
     }
@@ -82,12 +82,33 @@ impl ConfigInstance for FlushStopperConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        DynBuildingBlock::new(
-            FlushStopper::new(
-                GenericConfig::from_toml(&self.container).unwrap().build(),
+        let is_resizable = self.is_resizable();
+        let container =
+            GenericConfig::from_toml(&self.container).unwrap().build();
+        match container.into_get() {
+            Ok(container) => DynBuildingBlock::new_resizable_get(
+                FlushStopper::new(container),
+                self.is_concurrent(),
+                is_resizable,
             ),
-            self.is_concurrent(),
-        )
+            Err(container) => DynBuildingBlock::new_resizable(
+                FlushStopper::new(container),
+                self.is_concurrent(),
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .is_resizable()
     }
 }
 
@@ -6,3 +6,5 @@ mod building_block;
 #[cfg(feature = "config")]
 pub(crate) mod config;
 mod get;
+mod resizable;
+mod try_building_block;
@@ -0,0 +1,11 @@
+use super::FlushStopper;
+use crate::Resizable;
+
+impl<K, V, C> Resizable<K, V> for FlushStopper<C>
+where
+    C: Resizable<K, V>,
+{
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.container.resize(new_capacity)
+    }
+}
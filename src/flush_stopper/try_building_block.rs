@@ -0,0 +1,47 @@
+use super::FlushStopper;
+use crate::{Error, TryBuildingBlock};
+
+impl<K, V, C> TryBuildingBlock<K, V> for FlushStopper<C>
+where
+    C: TryBuildingBlock<K, V>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        self.container.try_capacity()
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        self.container.try_size()
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        self.container.try_contains(key)
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        self.container.try_take(key)
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.container.try_take_multiple(keys)
+    }
+
+    fn try_pop(&mut self, n: usize) -> Result<Vec<(K, V)>, Error> {
+        self.container.try_pop(n)
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.container.try_push(elements)
+    }
+
+    /// This method does not flush the wrapped container and returns an
+    /// empty vector.
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        Ok(Vec::new())
+    }
+}
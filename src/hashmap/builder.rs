@@ -0,0 +1,50 @@
+use crate::builder::Build;
+use crate::HashMapContainer;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// `HashMapContainer` builder.
+///
+/// This builder can be consumed later to spawn an
+/// [`HashMapContainer`](../../struct.HashMapContainer.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::Build;
+/// use byoc::builder::HashMapBuilder;
+///
+/// let mut container = HashMapBuilder::new(2).build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct HashMapBuilder<K: Clone + Eq + Hash, V: Ord> {
+    pub(super) capacity: usize,
+    unused: PhantomData<(K, V)>,
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> HashMapBuilder<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        HashMapBuilder {
+            capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> Clone for HashMapBuilder<K, V> {
+    fn clone(&self) -> Self {
+        HashMapBuilder {
+            capacity: self.capacity,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> Build<HashMapContainer<K, V>>
+    for HashMapBuilder<K, V>
+{
+    fn build(self) -> HashMapContainer<K, V> {
+        HashMapContainer::new(self.capacity)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::builder::HashMapBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericKey, GenericValue, IntoConfig,
+};
+use crate::objsafe::DynBuildingBlock;
+use crate::HashMapContainer;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// Configuration format for [`HashMapContainer`](../struct.HashMapContainer.html)
+/// containers.
+///
+/// This configuration format is composed of two key/value fields that
+/// must be present:      
+/// - `id = "HashMapConfig"` and
+/// - `capacity = <int>`
+///
+/// The `id` field must be exactly "HashMapConfig" while the capacity
+/// will set the maximum number of key/value pairs that the container can
+/// hold.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id = 'HashMapConfig'
+/// capacity = 10
+/// ");
+/// let container: DynBuildingBlock<u64, u64> =
+///            ConfigBuilder::from_string(config_str.as_str())
+///            .unwrap()
+///            .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct HashMapConfig {
+    #[allow(dead_code)]
+    id: String,
+    capacity: usize,
+}
+
+impl HashMapConfig {
+    /// Build the [`HashMapContainer`] described by this configuration.
+    pub(crate) fn hashmap<K, V>(&self) -> HashMapContainer<K, V>
+    where
+        K: Clone + Eq + Hash,
+        V: Ord,
+    {
+        HashMapContainer::new(self.capacity)
+    }
+}
+
+impl ConfigInstance for HashMapConfig {
+    fn id() -> &'static str {
+        "HashMapConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        toml::from_str(&toml).map_err(|e| {
+            ConfigError::ConfigFormatError(format!(
+                "Invalid HashMapConfig: {}\n{:?}",
+                toml, e
+            ))
+        })
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        DynBuildingBlock::new_resizable_get(self.hashmap(), false, true)
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> IntoConfig<HashMapConfig>
+    for HashMapBuilder<K, V>
+{
+    fn as_config(&self) -> HashMapConfig {
+        HashMapConfig {
+            id: String::from(HashMapConfig::id()),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HashMapConfig;
+    use crate::builder::HashMapBuilder;
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_hashmap_config() {
+        let capacity = 1008;
+        let config_str =
+            format!("id='HashMapConfig'\ncapacity={}", capacity);
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = HashMapConfig::from_toml(&value).unwrap();
+        assert_eq!(config.capacity, capacity);
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_invalid_hashmap_config() {
+        let config_str = "id='HashMapConfig'\ncapacity='ten'".to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            HashMapConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder = HashMapBuilder::<(), ()>::new(2);
+        test_config_builder(builder);
+    }
+}
@@ -0,0 +1,42 @@
+use super::{HashMapCell, HashMapContainer};
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, GetEntry, GetMut};
+use std::hash::Hash;
+
+impl<K, V> GetEntry<K, V> for HashMapContainer<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Ord,
+{
+    type Target = HashMapCell<K, V>;
+
+    /// Look up the key once. Vacant entries are inserted with the
+    /// [`HashMapContainer`]
+    /// [`push()`](trait.BuildingBlock.html#method.push) method.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        match self.get_mut(&key).map(LifeTimeGuard::unwrap) {
+            Some(value) => Entry::Occupied(OccupiedEntry::new(
+                key,
+                LifeTimeGuard::new(value),
+            )),
+            None => Entry::Vacant(VacantEntry::new(
+                key,
+                Box::new(move |k, v| self.push(vec![(k, v)])),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HashMapContainer;
+    use crate::tests::test_entry;
+
+    #[test]
+    fn entry() {
+        test_entry(HashMapContainer::new(0));
+        test_entry(HashMapContainer::new(10));
+        test_entry(HashMapContainer::new(100));
+    }
+}
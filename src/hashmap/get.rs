@@ -0,0 +1,79 @@
+use super::HashMapContainer;
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, Get, GetMut};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// Cell representing a writable value inside a
+/// [`HashMapContainer`](struct.HashMapContainer.html).
+///
+/// This value inside this cell is taken out of the container and written
+/// back in it when the cell is dropped.
+pub struct HashMapCell<K: Clone + Eq + Hash, V: Ord> {
+    kv: Option<(K, V)>,
+    map: NonNull<HashMapContainer<K, V>>,
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> Deref for HashMapCell<K, V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        &self.kv.as_ref().unwrap().1
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> DerefMut for HashMapCell<K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.kv.as_mut().unwrap().1
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> Drop for HashMapCell<K, V> {
+    fn drop(&mut self) {
+        let map = unsafe { self.map.as_mut() };
+        let kv = self.kv.take().unwrap();
+        assert!(map.push(vec![kv]).pop().is_none());
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> GetMut<K, V>
+    for HashMapContainer<K, V>
+{
+    type Target = HashMapCell<K, V>;
+
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.take(key).map(|(key, value)| {
+            LifeTimeGuard::new(HashMapCell {
+                kv: Some((key, value)),
+                map: NonNull::new(self).unwrap(),
+            })
+        })
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Ord> Get<K, V> for HashMapContainer<K, V> {
+    type Target = HashMapCell<K, V>;
+
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.get_mut(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HashMapContainer;
+    use crate::tests::{test_get, test_get_mut};
+
+    #[test]
+    fn get() {
+        test_get(HashMapContainer::new(0));
+        test_get(HashMapContainer::new(10));
+        test_get(HashMapContainer::new(100));
+        test_get_mut(HashMapContainer::new(0));
+        test_get_mut(HashMapContainer::new(10));
+        test_get_mut(HashMapContainer::new(100));
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// In-memory container with hashed keys and ordered values.
+///
+/// [`HashMapContainer`] is a container where keys are kept in a hash map
+/// for constant time lookups and values are kept in an auxiliary binary
+/// tree for fast search of eviction candidates. When evicting candidates,
+/// key/value pairs with the greatest values are evicted first.
+/// Unlike [`BTree`](struct.BTree.html), keys only need to be hashable
+/// and do not need to be ordered nor copied. The eviction ordering is
+/// entirely defined by the values order and can therefore be changed by
+/// wrapping this container in a
+/// [`Decorator`](struct.Decorator.html).
+///
+/// Elements that go into a [`HashMapContainer`] are sized with a function
+/// `element_size()` set with the method
+/// [`with_element_size()`](struct.HashMapContainer.html#method.with_element_size)
+/// such that the sum of the sizes of [`HashMapContainer`] elements never
+/// exceed its set capacity. The default size for a key/value pair element is
+/// `1` and therefore the container capacity in this circumstance is the
+/// number of elements it can contain.
+///
+/// This container cannot contain duplicate keys. Values with the same
+/// order are evicted from the most recently inserted. Because
+/// [`HashMapContainer`] is an ordering container, it is not safe to store
+/// values for which the order may change through time.
+///
+/// See
+/// [`BuildingBlock` implementation](struct.HashMapContainer.html#impl-BuildingBlock)
+/// for more detail on how does the container operates.
+///
+/// Elements within the container can be accessed with the
+/// [`Get`](trait.Get.html) and [`GetMut`](trait.GetMut.html) traits.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::HashMapContainer;
+///
+/// // HashMapContainer with 3 elements capacity.
+/// let mut c = HashMapContainer::new(3);
+///
+/// // BuildingBlock as room for 2 elements and returns an empty vector.
+/// // No element is rejected.
+/// assert!(c.push(vec![("first", 1), ("second", 2)]).pop().is_none());
+///
+/// // Pushing pops out duplicates. If there is enough size for the rest, the
+/// // rest will fit.
+/// let out = c.push(vec![("first", 2), ("third", 3)]);
+/// assert_eq!(out.len(), 1);
+/// assert_eq!(out[0].0, "first");
+///
+/// // Overflowing pushes will pop enough room for new elements:
+/// let out = c.push(vec![("fourth", 4)]);
+/// assert_eq!(out.len(), 1);
+/// // Popped element is the greatest value already present before push.
+/// assert_eq!(out[0].0, "third");
+///
+/// // HashMapContainer pops elements in order of the highest values.
+/// let (key, value) = c.pop(1).pop().unwrap();
+/// assert_eq!(key, "fourth");
+/// // Matching values are popped from the most recently inserted.
+/// let (key, value) = c.pop(1).pop().unwrap();
+/// assert_eq!(key, "first");
+/// let (key, value) = c.pop(1).pop().unwrap();
+/// assert_eq!(key, "second");
+/// assert!(c.pop(1).is_empty());
+/// ```
+///
+/// [`HashMapContainer`] can also be built from a
+/// [builder pattern](builder/struct.Builder.html#method.hashmap) and a
+/// [configuration](config/struct.HashMapConfig.html).
+pub struct HashMapContainer<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Ord,
+{
+    // BuildingBlock capacity
+    pub(super) capacity: usize,
+    pub(super) total_size: usize,
+    pub(super) element_size: fn((&K, &V)) -> usize,
+    // Counter used to tell apart elements with matching values.
+    pub(super) insertions: u64,
+    // Ordering tree of references. Used for eviction.
+    pub(super) order: BTreeMap<(Rc<V>, u64), K>,
+    // Map of keys to references and their position in `order`.
+    pub(super) map: HashMap<K, (Rc<V>, u64)>,
+}
+
+impl<K, V> HashMapContainer<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Ord,
+{
+    /// Create a new [`HashMapContainer`] container with `size`
+    /// [`capacity`](struct.HashMapContainer.html#method.capacity).
+    ///
+    /// The meaning of this capacity depends on the `element_size` function
+    /// set with
+    /// [`with_element_size()`](struct.HashMapContainer.html#method.with_element_size).
+    /// The default is to set every elements size to `1usize` and therefore,
+    /// `size` stands for the maximum number of elements fitting in the
+    /// [`HashMapContainer`].
+    pub fn new(size: usize) -> Self {
+        HashMapContainer {
+            capacity: size,
+            total_size: 0,
+            element_size: |_| 1,
+            insertions: 0,
+            order: BTreeMap::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    /// Set how [`HashMapContainer`] elements size is computed.
+    ///
+    /// Whenever an element is inserted or removed from the
+    /// [`HashMapContainer`], its size is compared with the container
+    /// capacity and its remaining space to decide respectively, whether the
+    /// element can be inserted or how much space does it leaves in the
+    /// container.
+    /// This function decides how to compute each element size and therefore
+    /// it also decides of the meaning of the container
+    /// [`capacity`](struct.HashMapContainer.html#method.capacity).
+    pub fn with_element_size(
+        mut self,
+        element_size: fn((&K, &V)) -> usize,
+    ) -> Self {
+        if self.total_size > 0 {
+            panic!("It is not allowed to set a non empty HashMapContainer container element_size method.")
+        }
+        self.element_size = element_size;
+        self
+    }
+
+    pub(super) fn as_value(rc: Rc<V>) -> V {
+        Rc::try_unwrap(rc).map_err(|_| panic!("")).unwrap()
+    }
+
+    pub(super) fn insert_values_unchecked(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) {
+        for (key, value) in elements.into_iter() {
+            let value = Rc::new(value);
+            let id = self.insertions;
+            self.insertions = self.insertions.wrapping_add(1);
+            self.order.insert((Rc::clone(&value), id), key.clone());
+            self.map.insert(key, (value, id));
+        }
+    }
+}
+
+impl<'a, K, V> From<HashMapContainer<K, V>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Clone + Eq + Hash,
+    V: 'a + Ord,
+{
+    fn from(container: HashMapContainer<K, V>) -> Self {
+        crate::DynBuildingBlock::new_resizable_get(container, false, true)
+    }
+}
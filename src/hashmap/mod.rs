@@ -0,0 +1,11 @@
+#[allow(clippy::module_inception)]
+mod hashmap;
+pub use hashmap::HashMapContainer;
+mod building_block;
+mod entry;
+mod get;
+mod resizable;
+pub use get::HashMapCell;
+pub(crate) mod builder;
+#[cfg(feature = "config")]
+pub(crate) mod config;
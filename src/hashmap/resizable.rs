@@ -0,0 +1,35 @@
+use super::HashMapContainer;
+use crate::{BuildingBlock, Resizable};
+use std::hash::Hash;
+
+impl<K, V> Resizable<K, V> for HashMapContainer<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Ord,
+{
+    /// Change the [`HashMapContainer`] capacity.
+    ///
+    /// If the elements of the container do not fit in the new capacity,
+    /// the elements with the greatest values are popped out until they do.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity;
+        if self.total_size > new_capacity {
+            self.pop(self.total_size - new_capacity)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HashMapContainer;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        test_resizable(HashMapContainer::new(0));
+        test_resizable(HashMapContainer::new(10));
+        test_resizable(HashMapContainer::new(100));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::builder::Build;
+use crate::hierarchy::Boundary;
+use crate::Hierarchy;
+use std::marker::PhantomData;
+
+/// `Hierarchy` container builder.
+///
+/// This builder can be consumed later to stack containers into the levels
+/// of a [`Hierarchy`](../../struct.Hierarchy.html) container. It is created
+/// from a vector of builders building each level, starting from the first
+/// level.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{ArrayBuilder, Build, HierarchyBuilder};
+/// use byoc::utils::hierarchy::Boundary;
+///
+/// let mut container = HierarchyBuilder::new(vec![
+///     ArrayBuilder::new(2),
+///     ArrayBuilder::new(4),
+///     ArrayBuilder::new(8),
+/// ])
+/// .with_boundary(0, Boundary::Inclusive)
+/// .build();
+/// container.push(vec![(1, 2)]);
+/// assert_eq!(container.capacity(), 12);
+/// ```
+pub struct HierarchyBuilder<C, B> {
+    pub(super) builders: Vec<B>,
+    pub(super) boundaries: Vec<Boundary>,
+    unused: PhantomData<C>,
+}
+
+impl<C, B: Clone> Clone for HierarchyBuilder<C, B> {
+    fn clone(&self) -> Self {
+        HierarchyBuilder {
+            builders: self.builders.clone(),
+            boundaries: self.boundaries.clone(),
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<C, B> HierarchyBuilder<C, B> {
+    pub fn new(builders: Vec<B>) -> Self {
+        let n = builders.len();
+        HierarchyBuilder {
+            builders,
+            boundaries: vec![Boundary::default(); n.saturating_sub(1)],
+            unused: PhantomData,
+        }
+    }
+
+    /// Set the [`Boundary`](../../utils/hierarchy/enum.Boundary.html)
+    /// between the level `level` and the level `level + 1` of the
+    /// [`Hierarchy`](../../struct.Hierarchy.html) container to build.
+    pub fn with_boundary(
+        mut self,
+        level: usize,
+        boundary: Boundary,
+    ) -> Self {
+        if level >= self.boundaries.len() {
+            panic!(
+                "Invalid Hierarchy boundary {} with {} levels.",
+                level,
+                self.builders.len()
+            );
+        }
+        self.boundaries[level] = boundary;
+        self
+    }
+}
+
+impl<K, V, C, B> Build<Hierarchy<K, V, C>> for HierarchyBuilder<C, B>
+where
+    B: Build<C>,
+{
+    fn build(self) -> Hierarchy<K, V, C> {
+        let levels =
+            self.builders.into_iter().map(|b| b.build()).collect();
+        let mut hierarchy = Hierarchy::new(levels);
+        for (i, boundary) in self.boundaries.into_iter().enumerate() {
+            hierarchy = hierarchy.with_boundary(i, boundary);
+        }
+        hierarchy
+    }
+}
@@ -0,0 +1,239 @@
+use super::hierarchy::Levels;
+use super::Hierarchy;
+use crate::BuildingBlock;
+use std::collections::BTreeSet;
+
+impl<K, V, C> BuildingBlock<K, V> for Hierarchy<K, V, C>
+where
+    K: Ord + Clone,
+    V: Clone,
+    C: BuildingBlock<K, V>,
+{
+    /// Get the maximum "size" that elements in the container can fit.
+    ///
+    /// This is the sum of the capacities of the levels of this
+    /// [`Hierarchy`] container, excluding the levels which elements are
+    /// copies of the elements of the next level.
+    fn capacity(&self) -> usize {
+        (0..self.levels.len())
+            .filter(|i| self.is_counted(*i))
+            .map(|i| self.levels[i].capacity())
+            .sum()
+    }
+
+    /// Get the size currently occupied by elements in this [`BuildingBlock`].
+    ///
+    /// This is the sum of the sizes of the levels of this [`Hierarchy`]
+    /// container, excluding the levels which elements are copies of the
+    /// elements of the next level.
+    fn size(&self) -> usize {
+        (0..self.levels.len())
+            .filter(|i| self.is_counted(*i))
+            .map(|i| self.levels[i].size())
+            .sum()
+    }
+
+    /// Check if container contains a matching key.
+    ///
+    /// Levels are searched in order, starting from the first level.
+    fn contains(&self, key: &K) -> bool {
+        self.levels.iter().any(|l| l.contains(key))
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.levels.iter().any(|l| l.contains_if(key, predicate))
+    }
+
+    /// Take the matching key/value pair out of the container.
+    ///
+    /// The element is taken out of the first level where it is found. Its
+    /// copies in the following levels are also taken out and discarded.
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        for i in 0..self.levels.len() {
+            if let Some((k, v)) = self.levels[i].take(key) {
+                self.take_below(i, std::slice::from_ref(&k));
+                return Some((k, v));
+            }
+        }
+        None
+    }
+
+    /// Take multiple keys out of a container at once.
+    ///
+    /// Levels are searched in order, starting from the first level. Keys
+    /// found in a level are removed from the input `keys` and their copies
+    /// in the following levels are taken out and discarded. The remaining
+    /// keys are searched in the next level.
+    fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for i in 0..self.levels.len() {
+            if keys.is_empty() {
+                break;
+            }
+            keys.sort();
+            let found = self.levels[i].take_multiple(&mut keys.clone());
+            let found_keys: Vec<K> =
+                found.iter().map(|(k, _)| k.clone()).collect();
+            for k in found_keys.iter() {
+                if let Ok(j) = keys.binary_search(k) {
+                    keys.remove(j);
+                }
+            }
+            self.take_below(i, &found_keys);
+            out.extend(found);
+        }
+        out
+    }
+
+    /// Free up to `size` space from the container.
+    ///
+    /// Elements are popped from the last level first with its own
+    /// [`pop()`](trait.BuildingBlock.html#method.pop) method. If less than
+    /// `size` space was freed, the remaining size is popped from the
+    /// previous level and so on. Levels which elements are copies of the
+    /// elements of the next level are skipped. Instead, the copies of the
+    /// popped elements are taken out and their values replace the values of
+    /// the popped elements.
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        let mut freed = 0;
+        for i in (0..self.levels.len()).rev() {
+            if freed >= size {
+                break;
+            }
+            if !self.is_counted(i) {
+                continue;
+            }
+            let old_size = self.levels[i].size();
+            let popped = self.levels[i].pop(size - freed);
+            freed += old_size - self.levels[i].size();
+            self.stats[i].evictions += popped.len() as u64;
+            out.append(&mut self.invalidate_above(i, popped));
+        }
+        out
+    }
+
+    /// Insert key/value pairs in the container.
+    ///
+    /// Elements are pushed in the first level with its own
+    /// [`push()`](trait.BuildingBlock.html#method.push) method, and in the
+    /// following levels while boundaries are inclusive. Elements evicted
+    /// from a level are demoted to the next level. Elements evicted from
+    /// the last level are returned.
+    ///
+    /// Elements that fit in no level after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are also
+    /// returned.
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        let mut out = std::mem::take(&mut self.overflow);
+        out.append(&mut self.insert(0, elements));
+        out
+    }
+
+    type FlushIterator = std::vec::IntoIter<(K, V)>;
+
+    /// Empty the container and retrieve all of its elements.
+    ///
+    /// Levels are flushed in order, starting from the first level. The
+    /// copies of elements already flushed from a previous level are
+    /// discarded. Elements that fit in no level after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are
+    /// returned first.
+    fn flush(&mut self) -> Self::FlushIterator {
+        let mut keys = BTreeSet::new();
+        let mut out = std::mem::take(&mut self.overflow);
+        for level in self.levels.iter_mut() {
+            for (k, v) in level.flush() {
+                if !keys.contains(&k) {
+                    keys.insert(k.clone());
+                    out.push((k, v));
+                }
+            }
+        }
+        out.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hierarchy;
+    use crate::hierarchy::Boundary;
+    use crate::tests::test_building_block;
+    use crate::{Array, BuildingBlock};
+
+    #[test]
+    fn building_block() {
+        test_building_block(Hierarchy::new(vec![Array::new(10)]), true);
+        test_building_block(
+            Hierarchy::new(vec![Array::new(10), Array::new(100)]),
+            true,
+        );
+        test_building_block(
+            Hierarchy::new(vec![
+                Array::new(5),
+                Array::new(10),
+                Array::new(100),
+            ]),
+            true,
+        );
+        test_building_block(
+            Hierarchy::new(vec![
+                Array::new(5),
+                Array::new(10),
+                Array::new(100),
+            ])
+            .with_boundary(1, Boundary::Inclusive),
+            true,
+        );
+        test_building_block(
+            Hierarchy::new(vec![
+                Array::new(5),
+                Array::new(10),
+                Array::new(100),
+            ])
+            .with_boundary(0, Boundary::Inclusive)
+            .with_boundary(1, Boundary::Inclusive),
+            true,
+        );
+    }
+
+    #[test]
+    fn inclusive_boundary() {
+        let mut c = Hierarchy::new(vec![
+            Array::new(2),
+            Array::new(2),
+            Array::new(4),
+        ])
+        .with_boundary(0, Boundary::Inclusive);
+        assert_eq!(c.capacity(), 6);
+
+        assert!(c.push(vec![(0u16, 0u32), (1, 1)]).is_empty());
+        assert_eq!(c.size(), 2);
+        assert_eq!(c.levels()[0].size(), 2);
+        assert_eq!(c.levels()[1].size(), 2);
+
+        // Evicting from the second level also evicts the copy in the first
+        // level.
+        assert!(c.push(vec![(2, 2)]).is_empty());
+        assert_eq!(c.levels()[0].size(), 2);
+        assert_eq!(c.levels()[1].size(), 2);
+        assert_eq!(c.levels()[2].size(), 1);
+        assert_eq!(c.size(), 3);
+        assert_eq!(c.stats()[1].evictions(), 1);
+
+        // Copies are discarded when taking elements out.
+        assert!(c.levels()[2].contains(&1));
+        assert!(c.take(&2).is_some());
+        assert!(!c.contains(&2));
+        assert_eq!(c.size(), 2);
+
+        assert_eq!(c.flush().count(), 2);
+        assert_eq!(c.size(), 0);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::builder::HierarchyBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
+    IntoConfig,
+};
+use crate::hierarchy::Boundary;
+use crate::objsafe::DynBuildingBlock;
+use crate::Hierarchy;
+use serde::{Deserialize, Serialize};
+
+/// Configuration format for [`Hierarchy`](../struct.Hierarchy.html)
+/// containers.
+///
+/// This configuration format is composed of an `id` field where the
+/// `id` value must be "HierarchyConfig"; and of an
+/// [`array of tables`](https://toml.io/en/v1.0.0#array-of-tables)
+/// where each "levels" table is the configuration of a level of the
+/// hierarchy, starting from the first level.
+///
+/// The optional `boundaries` field is an array of the boundaries between
+/// consecutive levels. It must contain one less element than there are
+/// levels and its values can be either "Exclusive" or "Inclusive". When it
+/// is not set, all the boundaries are "Exclusive". This field must come
+/// before the `levels` tables.
+///
+/// For instance, below is a [`Hierarchy`](../struct.Hierarchy.html)
+/// container of three levels where each level is an
+/// [`Array`](../struct.Array.html) container and the first level is a copy
+/// of a part of the second level. See
+/// [`ArrayConfig`](struct.ArrayConfig.html) for details on Array
+/// configuration format.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='HierarchyConfig'
+/// boundaries=['Inclusive', 'Exclusive']
+/// [[levels]]
+/// id='ArrayConfig'
+/// capacity=10
+/// [[levels]]
+/// id='ArrayConfig'
+/// capacity=100
+/// [[levels]]
+/// id='ArrayConfig'
+/// capacity=1000
+/// ");
+/// let container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// assert_eq!(container.capacity(), 1100);
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct HierarchyConfig {
+    #[allow(dead_code)]
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boundaries: Option<Vec<String>>,
+    levels: toml::value::Array,
+}
+
+impl HierarchyConfig {
+    fn boundaries(&self) -> Result<Vec<Boundary>, ConfigError> {
+        let n = self.levels.len().saturating_sub(1);
+        let boundaries = match &self.boundaries {
+            None => return Ok(vec![Boundary::default(); n]),
+            Some(boundaries) => boundaries,
+        };
+        if boundaries.len() != n {
+            return Err(ConfigError::ConfigFormatError(format!(
+                "Invalid HierarchyConfig: {} boundaries for {} levels.",
+                boundaries.len(),
+                self.levels.len()
+            )));
+        }
+        boundaries
+            .iter()
+            .map(|b| match b.as_str() {
+                "Exclusive" => Ok(Boundary::Exclusive),
+                "Inclusive" => Ok(Boundary::Inclusive),
+                s => Err(ConfigError::ConfigFormatError(format!(
+                    "Invalid HierarchyConfig boundary: {}",
+                    s
+                ))),
+            })
+            .collect()
+    }
+
+    fn build_with<K, V, C>(
+        levels: Vec<C>,
+        boundaries: Vec<Boundary>,
+    ) -> Hierarchy<K, V, C> {
+        let mut hierarchy = Hierarchy::new(levels);
+        for (i, boundary) in boundaries.into_iter().enumerate() {
+            hierarchy = hierarchy.with_boundary(i, boundary);
+        }
+        hierarchy
+    }
+}
+
+impl<C, B> IntoConfig<HierarchyConfig> for HierarchyBuilder<C, B>
+where
+    B: IntoConfig<C>,
+    C: ConfigInstance,
+{
+    fn as_config(&self) -> HierarchyConfig {
+        let levels = self
+            .builders
+            .iter()
+            .map(|b| {
+                let config_str = b.as_config().to_toml_string();
+                toml::de::from_str(config_str.as_ref()).unwrap()
+            })
+            .collect();
+        let boundaries =
+            if self.boundaries.iter().all(|b| *b == Boundary::default()) {
+                None
+            } else {
+                Some(
+                    self.boundaries
+                        .iter()
+                        .map(|b| format!("{:?}", b))
+                        .collect(),
+                )
+            };
+
+        HierarchyConfig {
+            id: String::from(HierarchyConfig::id()),
+            boundaries,
+            levels,
+        }
+    }
+}
+
+impl ConfigInstance for HierarchyConfig {
+    fn id() -> &'static str {
+        "HierarchyConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        let cfg: HierarchyConfig = match toml::from_str(&toml) {
+            Err(e) => return Err(ConfigError::TomlFormatError(e)),
+            Ok(cfg) => cfg,
+        };
+        cfg.boundaries()?;
+        for toml in cfg.levels.iter() {
+            GenericConfig::from_toml(toml)?;
+        }
+        Ok(cfg)
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        let boundaries = self.boundaries().unwrap();
+        let levels = self
+            .levels
+            .into_iter()
+            .map(|cfg| GenericConfig::from_toml(&cfg).unwrap().build())
+            .collect();
+        match DynBuildingBlock::into_get_all(levels) {
+            Ok(levels) => DynBuildingBlock::new_get(
+                Self::build_with(levels, boundaries),
+                false,
+            ),
+            Err(levels) => DynBuildingBlock::new(
+                Self::build_with(levels, boundaries),
+                false,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        self.levels
+            .iter()
+            .map(|cfg| GenericConfig::from_toml(cfg).unwrap())
+            .all(|cfg| cfg.has_get_trait())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HierarchyConfig;
+    use crate::builder::{ArrayBuilder, HierarchyBuilder};
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::hierarchy::Boundary;
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_hierarchy_config() {
+        let config_str = "id='HierarchyConfig'
+boundaries=['Inclusive']
+[[levels]]
+id='ArrayConfig'
+capacity=10
+[[levels]]
+id='BTreeConfig'
+capacity=100
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        let config = HierarchyConfig::from_toml(&value).unwrap();
+        assert_eq!(
+            config.boundaries().unwrap(),
+            vec![Boundary::Inclusive]
+        );
+        let mut container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), 100);
+        assert!(container.push(vec![(1, 1)]).is_empty());
+        assert_eq!(container.size(), 1);
+    }
+
+    #[test]
+    fn test_invalid_hierarchy_config() {
+        for boundaries in ["['Inclusive', 'Exclusive']", "['Inclusve']"] {
+            let config_str = format!(
+                "id='HierarchyConfig'
+boundaries={}
+[[levels]]
+id='ArrayConfig'
+capacity=10
+[[levels]]
+id='ArrayConfig'
+capacity=10
+",
+                boundaries
+            );
+            let value: toml::Value =
+                toml::from_str(config_str.as_str()).unwrap();
+            assert!(matches!(
+                HierarchyConfig::from_toml(&value),
+                Err(ConfigError::ConfigFormatError(_))
+            ));
+        }
+
+        let config_str = "id='HierarchyConfig'
+[[levels]]
+id='ArrayConfig'
+capacity=10
+[[levels]]
+id='ArrayConfg'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(config_str).unwrap();
+        assert!(matches!(
+            HierarchyConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder = HierarchyBuilder::new(vec![
+            ArrayBuilder::<()>::new(2),
+            ArrayBuilder::<()>::new(4),
+            ArrayBuilder::<()>::new(8),
+        ]);
+        test_config_builder(builder.clone());
+        test_config_builder(builder.with_boundary(1, Boundary::Inclusive));
+    }
+}
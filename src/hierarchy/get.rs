@@ -0,0 +1,140 @@
+use super::hierarchy::Levels;
+use super::Hierarchy;
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, Get, GetMut};
+
+impl<K, V, C> Get<K, V> for Hierarchy<K, V, C>
+where
+    K: Ord + Clone,
+    V: Clone,
+    C: Get<K, V> + BuildingBlock<K, V>,
+{
+    type Target = C::Target;
+
+    /// Get a read-only smart pointer to a value inside the container.
+    ///
+    /// Levels are searched in order, starting from the first level, and
+    /// the hits and misses of each level are accounted. If the element is
+    /// found in a level other than the first level, it is promoted to the
+    /// first level. A smart pointer to the element in the first level is
+    /// returned.
+    ///
+    /// If promoting the element evicts elements out of the last level,
+    /// they are returned by the next call to
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push).
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let level = self.lookup(key)?;
+        self.promote(level, key);
+        self.levels[0].get(key)
+    }
+}
+
+impl<K, V, C> GetMut<K, V> for Hierarchy<K, V, C>
+where
+    K: Ord + Clone,
+    V: Clone,
+    C: GetMut<K, V> + BuildingBlock<K, V>,
+{
+    type Target = C::Target;
+
+    /// Get a smart pointer to a mutable value inside the container.
+    ///
+    /// Levels are searched in order, starting from the first level, and
+    /// the hits and misses of each level are accounted. If the element is
+    /// found in a level other than the first level, it is promoted to the
+    /// first level. An exclusive smart pointer to the element in the first
+    /// level is returned.
+    ///
+    /// If promoting the element evicts elements out of the last level,
+    /// they are returned by the next call to
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push).
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let level = self.lookup(key)?;
+        self.promote(level, key);
+        self.levels[0].get_mut(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hierarchy;
+    use crate::hierarchy::Boundary;
+    use crate::tests::{test_get, test_get_mut};
+    use crate::{Array, BuildingBlock, Get, GetMut};
+
+    #[test]
+    fn get() {
+        test_get(Hierarchy::new(vec![
+            Array::new(10),
+            Array::new(10),
+            Array::new(100),
+        ]));
+        test_get_mut(Hierarchy::new(vec![
+            Array::new(10),
+            Array::new(10),
+            Array::new(100),
+        ]));
+        test_get(
+            Hierarchy::new(vec![Array::new(10), Array::new(100)])
+                .with_boundary(0, Boundary::Inclusive),
+        );
+        test_get_mut(
+            Hierarchy::new(vec![Array::new(10), Array::new(100)])
+                .with_boundary(0, Boundary::Inclusive),
+        );
+    }
+
+    #[test]
+    fn stats() {
+        let mut c = Hierarchy::new(vec![
+            Array::new(1),
+            Array::new(1),
+            Array::new(2),
+        ]);
+        assert!(c.push(vec![(0u16, 0u32), (1, 1), (2, 2)]).is_empty());
+        assert!(c.levels()[2].contains(&2));
+
+        // Promotion from the last level.
+        assert_eq!(*c.get(&2).unwrap(), 2);
+        assert!(c.levels()[0].contains(&2));
+        assert_eq!(c.stats()[0].misses(), 1);
+        assert_eq!(c.stats()[1].misses(), 1);
+        assert_eq!(c.stats()[2].hits(), 1);
+        assert_eq!(c.stats()[2].promotions(), 1);
+        assert_eq!(c.size(), 3);
+
+        // Hit in the first level.
+        *c.get_mut(&2).unwrap() = 4;
+        assert_eq!(c.stats()[0].hits(), 1);
+        assert!(c.get(&3).is_none());
+        assert_eq!(c.stats()[2].misses(), 1);
+
+        // Modified values are demoted.
+        assert!(c.push(vec![(3, 3)]).is_empty());
+        assert_eq!(c.take(&2), Some((2, 4)));
+
+        c.reset_stats();
+        assert_eq!(c.stats()[2].hits(), 0);
+    }
+
+    #[test]
+    fn promote_overflow() {
+        let level =
+            Array::new(4).with_element_size(|(_, v): &(u16, usize)| *v);
+        let mut c = Hierarchy::new(vec![level.clone(), level]);
+        assert!(c.push(vec![(10, 2), (11, 2)]).is_empty());
+        assert!(c.push(vec![(1, 1), (2, 3)]).is_empty());
+
+        // Elements leaving the last level are returned by the next push.
+        assert_eq!(*c.get(&10).unwrap(), 2);
+        let out = c.push(vec![]);
+        assert!(!out.is_empty());
+        for (k, _) in out.iter() {
+            assert!(!c.contains(k));
+        }
+        assert!(c.push(vec![]).is_empty());
+    }
+}
@@ -0,0 +1,384 @@
+use crate::BuildingBlock;
+
+/// Relation between the elements of two consecutive levels of a
+/// [`Hierarchy`] container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// An element is held in at most one of the two levels. Elements move
+    /// from one level to the other when they are promoted or demoted.
+    Exclusive,
+    /// Every element of the upper level has a copy in the lower level.
+    /// Copies are refreshed with the upper level value when the latter is
+    /// demoted.
+    Inclusive,
+}
+
+impl Default for Boundary {
+    /// The default boundary is [`Boundary::Exclusive`].
+    fn default() -> Self {
+        Boundary::Exclusive
+    }
+}
+
+/// Statistics of one level of a [`Hierarchy`] container.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LevelStats {
+    pub(super) hits: u64,
+    pub(super) misses: u64,
+    pub(super) promotions: u64,
+    pub(super) evictions: u64,
+}
+
+impl LevelStats {
+    /// Number of lookups that found their key in this level.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of lookups that reached this level and did not find their
+    /// key there.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Number of elements found in this level and promoted to the first
+    /// level.
+    pub fn promotions(&self) -> u64 {
+        self.promotions
+    }
+
+    /// Number of elements evicted out of this level toward the next level,
+    /// or out of the container for the last level.
+    pub fn evictions(&self) -> u64 {
+        self.evictions
+    }
+}
+
+/// Multilevel `BuildingBlock` made of an arbitrary number of levels.
+///
+/// [`Hierarchy`] generalizes the two levels
+/// [`Exclusive`](struct.Exclusive.html) and
+/// [`Inclusive`](struct.Inclusive.html) containers to a vector of levels
+/// where each level serves as a cache to the next one. Levels of different
+/// types can be stacked by using
+/// [`DynBuildingBlock`](struct.DynBuildingBlock.html) levels.
+///
+/// Each boundary between two consecutive levels is either
+/// [`Exclusive`](utils/hierarchy/enum.Boundary.html#variant.Exclusive),
+/// the default, or
+/// [`Inclusive`](utils/hierarchy/enum.Boundary.html#variant.Inclusive)
+/// and can be set with
+/// [`with_boundary()`](struct.Hierarchy.html#method.with_boundary).
+/// The capacity and size of the container are the sums of the capacities
+/// and sizes of its levels, excluding the levels which elements are copied
+/// in the next level.
+///
+/// ## [`BuildingBlock`](trait.BuildingBlock.html) Implementation
+///
+/// Insertions happen in the first level, and also in the following levels
+/// as long as the boundaries in between are inclusive. Elements evicted
+/// from a level are demoted to the next level. Elements evicted from the
+/// last level leave the container and are returned. Whenever an element
+/// leaves a level, its copies in the levels above it are also taken out
+/// and the value of the uppermost copy, the freshest, continues its way.
+///
+/// Evictions with [`pop()`](trait.BuildingBlock.html#tymethod.pop) happen
+/// from the last level first and move toward the first level until enough
+/// size has been freed.
+///
+/// ## [`Get`](trait.Get.html) Implementation
+///
+/// Elements found with [`Get`](trait.Get.html) and
+/// [`GetMut`](trait.GetMut.html) traits in a level other than the first
+/// level are promoted to the first level before being returned. Hits and
+/// misses of each level are accounted in [`LevelStats`] available with
+/// [`stats()`](struct.Hierarchy.html#method.stats).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Get, Hierarchy};
+/// use byoc::utils::hierarchy::Boundary;
+///
+/// let mut c = Hierarchy::new(vec![
+///     Array::new(1),
+///     Array::new(2),
+///     Array::new(4),
+/// ])
+/// .with_boundary(0, Boundary::Inclusive);
+///
+/// // The first level is a copy of a part of the second level.
+/// assert_eq!(c.capacity(), 6);
+/// assert!(c.push(vec![("first", 1), ("second", 2)]).is_empty());
+/// assert!(c.levels()[0].contains(&"first"));
+/// assert!(c.levels()[1].contains(&"first"));
+/// assert!(c.levels()[1].contains(&"second"));
+///
+/// // Accessing an element promotes it to the first level.
+/// assert_eq!(*c.get(&"second").unwrap(), 2);
+/// assert!(c.levels()[0].contains(&"second"));
+/// assert_eq!(c.stats()[1].hits(), 1);
+/// ```
+///
+/// [`Hierarchy`] can also be built from a
+/// [builder pattern](builder/struct.HierarchyBuilder.html) and a
+/// [configuration](config/configs/struct.HierarchyConfig.html).
+pub struct Hierarchy<K, V, C> {
+    pub(super) levels: Vec<C>,
+    pub(super) boundaries: Vec<Boundary>,
+    pub(super) stats: Vec<LevelStats>,
+    // Elements that fit in no level after a `get()` or `get_mut()` call.
+    pub(super) overflow: Vec<(K, V)>,
+}
+
+impl<K, V, C> Hierarchy<K, V, C> {
+    /// Build a hierarchy of `levels` ordered from the first level, where
+    /// elements are inserted, to the last level. All the boundaries
+    /// between levels are exclusive.
+    pub fn new(levels: Vec<C>) -> Self {
+        let n = levels.len();
+        Hierarchy {
+            levels,
+            boundaries: vec![Boundary::default(); n.saturating_sub(1)],
+            stats: vec![LevelStats::default(); n],
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Set the `boundary` between the level `level` and the level
+    /// `level + 1`.
+    ///
+    /// This must be called before inserting elements and `level + 1` must
+    /// be a valid level index.
+    pub fn with_boundary(
+        mut self,
+        level: usize,
+        boundary: Boundary,
+    ) -> Self {
+        if level >= self.boundaries.len() {
+            panic!(
+                "Invalid Hierarchy boundary {} with {} levels.",
+                level,
+                self.levels.len()
+            );
+        }
+        self.boundaries[level] = boundary;
+        self
+    }
+
+    /// Get the boundaries between consecutive levels.
+    pub fn boundaries(&self) -> &[Boundary] {
+        &self.boundaries
+    }
+
+    /// Get shared access to the levels of the container.
+    pub fn levels(&self) -> &[C] {
+        &self.levels
+    }
+
+    /// Get exclusive access to the levels of the container.
+    pub fn levels_mut(&mut self) -> &mut [C] {
+        &mut self.levels
+    }
+
+    /// Get the statistics of each level of the container.
+    pub fn stats(&self) -> &[LevelStats] {
+        &self.stats
+    }
+
+    /// Reset the statistics of each level of the container.
+    pub fn reset_stats(&mut self) {
+        for s in self.stats.iter_mut() {
+            *s = LevelStats::default();
+        }
+    }
+
+    /// Whether the elements of a level are counted in the container size,
+    /// i.e whether they are not copies of elements of the next level.
+    pub(super) fn is_counted(&self, level: usize) -> bool {
+        level == self.boundaries.len()
+            || self.boundaries[level] == Boundary::Exclusive
+    }
+}
+
+/// Movements of elements between the levels of a [`Hierarchy`].
+///
+/// These methods are in a trait such that the keys and values types are
+/// bound by the levels implementation of
+/// [`BuildingBlock`](trait.BuildingBlock.html).
+pub(super) trait Levels<K, V> {
+    fn lookup(&mut self, key: &K) -> Option<usize>;
+    fn take_below(&mut self, level: usize, keys: &[K]);
+    fn invalidate_above(
+        &mut self,
+        level: usize,
+        elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)>;
+    fn insert(
+        &mut self,
+        level: usize,
+        elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)>;
+    fn demote(
+        &mut self,
+        level: usize,
+        elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)>;
+    fn promote(&mut self, level: usize, key: &K);
+}
+
+impl<K, V, C> Levels<K, V> for Hierarchy<K, V, C>
+where
+    K: Ord + Clone,
+    V: Clone,
+    C: BuildingBlock<K, V>,
+{
+    /// Find the first level holding `key` and account for the hit and
+    /// misses on the way.
+    fn lookup(&mut self, key: &K) -> Option<usize> {
+        for (i, level) in self.levels.iter().enumerate() {
+            if level.contains(key) {
+                self.stats[i].hits += 1;
+                return Some(i);
+            }
+            self.stats[i].misses += 1;
+        }
+        None
+    }
+
+    /// Take out the copies of `keys` in the levels following `level`
+    /// through inclusive boundaries.
+    fn take_below(&mut self, level: usize, keys: &[K]) {
+        let mut i = level;
+        while i < self.boundaries.len()
+            && self.boundaries[i] == Boundary::Inclusive
+        {
+            i += 1;
+            drop(self.levels[i].take_multiple(&mut keys.to_vec()));
+        }
+    }
+
+    /// Take out the copies of `elements` leaving `level` in the levels
+    /// above it through inclusive boundaries. Elements values are replaced
+    /// by the values of the uppermost copies.
+    fn invalidate_above(
+        &mut self,
+        level: usize,
+        mut elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)> {
+        elements.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut i = level;
+        while i > 0
+            && self.boundaries[i - 1] == Boundary::Inclusive
+            && !elements.is_empty()
+        {
+            i -= 1;
+            let mut keys: Vec<K> =
+                elements.iter().map(|(k, _)| k.clone()).collect();
+            for (k, v) in self.levels[i].take_multiple(&mut keys) {
+                if let Ok(j) =
+                    elements.binary_search_by(|(e, _)| e.cmp(&k))
+                {
+                    elements[j].1 = v;
+                }
+            }
+        }
+        elements
+    }
+
+    /// Insert `elements` in `level` and return the elements leaving the
+    /// container.
+    fn insert(
+        &mut self,
+        level: usize,
+        mut elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)> {
+        if elements.is_empty() || level == self.levels.len() {
+            return elements;
+        }
+
+        // Copies are inserted in the next level first. Elements that did
+        // not stay there are not inserted in this level either.
+        let mut out = Vec::new();
+        if !self.is_counted(level) {
+            out = self.insert(level + 1, elements.clone());
+            let next = &self.levels[level + 1];
+            elements.retain(|(k, _)| next.contains(k));
+        }
+
+        let popped = self.levels[level].push(elements);
+        let popped = self.invalidate_above(level, popped);
+        out.append(&mut self.demote(level, popped));
+        out
+    }
+
+    /// Move `elements` evicted from `level` to the next level and return
+    /// the elements leaving the container.
+    fn demote(
+        &mut self,
+        level: usize,
+        elements: Vec<(K, V)>,
+    ) -> Vec<(K, V)> {
+        if elements.is_empty() {
+            return elements;
+        }
+        self.stats[level].evictions += elements.len() as u64;
+        let next = level + 1;
+        if next == self.levels.len() {
+            return elements;
+        }
+        if self.is_counted(level) {
+            return self.insert(next, elements);
+        }
+
+        // Replace the copies in the next level with the evicted values.
+        let mut keys: Vec<K> =
+            elements.iter().map(|(k, _)| k.clone()).collect();
+        let mut copies: Vec<K> = self.levels[next]
+            .take_multiple(&mut keys)
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect();
+        copies.sort();
+        let (copied, missing): (Vec<_>, Vec<_>) = elements
+            .into_iter()
+            .partition(|(k, _)| copies.binary_search(k).is_ok());
+
+        let mut out = self.insert(next, missing);
+        let popped = self.levels[next].push(copied);
+        let popped = self.invalidate_above(next, popped);
+        out.append(&mut self.demote(next, popped));
+        out
+    }
+
+    /// Move the element matching `key` from `level` to the first level.
+    ///
+    /// If elements come out of the container in the process, they are
+    /// kept aside and returned by the next call to
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push).
+    fn promote(&mut self, level: usize, key: &K) {
+        if level == 0 {
+            return;
+        }
+        let element = match self.levels[level].take(key) {
+            None => return,
+            Some(e) => e,
+        };
+        self.take_below(level, std::slice::from_ref(&element.0));
+        self.stats[level].promotions += 1;
+        let mut popped = self.insert(0, vec![element]);
+        self.overflow.append(&mut popped);
+    }
+}
+
+impl<'a, K, V, C> From<Hierarchy<K, V, C>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Ord + Clone,
+    V: 'a + Clone,
+    C: 'a + BuildingBlock<K, V>,
+{
+    fn from(hierarchy: Hierarchy<K, V, C>) -> Self {
+        crate::DynBuildingBlock::new(hierarchy, false)
+    }
+}
@@ -0,0 +1,8 @@
+#[allow(clippy::module_inception)]
+mod hierarchy;
+pub use hierarchy::{Boundary, Hierarchy, LevelStats};
+pub(crate) mod builder;
+mod building_block;
+#[cfg(feature = "config")]
+pub(crate) mod config;
+mod get;
@@ -36,6 +36,24 @@ where
         self.front.contains(key) || self.back.contains(key)
     }
 
+    /// Check if container contains a matching key which value satisfies
+    /// `predicate`.
+    ///
+    /// The value of the `front` container is looked at when the key is
+    /// found there since it is the most recent value. Otherwise, the value
+    /// of the `back` container is looked at.
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        if self.front.contains(key) {
+            self.front.contains_if(key, &|c| predicate(c))
+        } else {
+            self.back.contains_if(key, &|c| predicate(c))
+        }
+    }
+
     /// Take the matching key/value pair out of the container.
     ///
     /// If the key to search is in the container, it will necessarily be in the
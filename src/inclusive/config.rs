@@ -63,14 +63,23 @@ impl ConfigInstance for InclusiveConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        DynBuildingBlock::new(
+        let is_resizable = self.is_resizable();
+        DynBuildingBlock::new_resizable(
             Inclusive::new(
                 GenericConfig::from_toml(&self.front).unwrap().build(),
                 GenericConfig::from_toml(&self.back).unwrap().build(),
             ),
             false,
+            is_resizable,
         )
     }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.front)
+            .unwrap()
+            .is_resizable()
+            && GenericConfig::from_toml(&self.back).unwrap().is_resizable()
+    }
 }
 
 #[cfg(test)]
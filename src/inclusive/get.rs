@@ -23,7 +23,7 @@ where
     };
 
     let popped: Vec<(K, InclusiveCell<V>)> = to
-        .push(vec![(key.clone(), c.clone())])
+        .push(vec![(key.clone(), c.clone_to())])
         .into_iter()
         .filter_map(
             |(k, c)| if c.is_updated() { Some((k, c)) } else { None },
@@ -38,15 +38,24 @@ impl<V> InclusiveCell<V> {
     pub(super) fn is_cloned(&self) -> bool {
         self.cloned.get()
     }
+
+    /// Flag this value as cloned and return the clone to move in another
+    /// container.
+    pub(super) fn clone_to(&self) -> Self
+    where
+        V: Clone,
+    {
+        self.cloned.set(true);
+        self.clone()
+    }
 }
 
 impl<V: Clone> Clone for InclusiveCell<V> {
     fn clone(&self) -> Self {
-        self.cloned.set(true);
         InclusiveCell {
             value: self.value.clone(),
             updated: self.updated,
-            cloned: Cell::new(true),
+            cloned: Cell::new(self.cloned.get()),
         }
     }
 }
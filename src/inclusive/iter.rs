@@ -0,0 +1,98 @@
+use super::inclusive::InclusiveCell;
+use super::Inclusive;
+use crate::{BuildingBlock, Iter};
+
+impl<K, V, L, R> Iter<K, V> for Inclusive<K, V, L, R>
+where
+    K: Clone,
+    V: Clone,
+    L: BuildingBlock<K, InclusiveCell<V>> + Iter<K, InclusiveCell<V>>,
+    R: BuildingBlock<K, InclusiveCell<V>> + Iter<K, InclusiveCell<V>>,
+{
+    #[allow(clippy::type_complexity)]
+    type Iterator<'a>
+        = std::iter::Chain<
+        std::iter::Map<
+            L::Iterator<'a>,
+            fn((K, InclusiveCell<V>)) -> (K, V),
+        >,
+        std::iter::FilterMap<
+            R::Iterator<'a>,
+            fn((K, InclusiveCell<V>)) -> Option<(K, V)>,
+        >,
+    >
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of the container.
+    ///
+    /// Elements of the `front` container are iterated first since they are
+    /// the most up to date copies. Then, the elements of the `back` container
+    /// that do not have a copy in the `front` container are iterated.
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.front
+            .iter()
+            .map(unwrap_cell as fn((K, InclusiveCell<V>)) -> (K, V))
+            .chain(self.back.iter().filter_map(
+                unwrap_not_cloned_cell
+                    as fn((K, InclusiveCell<V>)) -> Option<(K, V)>,
+            ))
+    }
+}
+
+/// Remove the [`InclusiveCell`] wrapping an element value.
+fn unwrap_cell<K, V>((k, c): (K, InclusiveCell<V>)) -> (K, V) {
+    (k, c.unwrap())
+}
+
+/// Remove the [`InclusiveCell`] wrapping an element value if the value
+/// does not have a copy in the `front` of the container.
+fn unwrap_not_cloned_cell<K, V>(
+    (k, c): (K, InclusiveCell<V>),
+) -> Option<(K, V)> {
+    if c.is_cloned() {
+        None
+    } else {
+        Some((k, c.unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Inclusive;
+    use crate::tests::test_iter;
+    use crate::{Array, BuildingBlock, GetMut, Iter};
+
+    #[test]
+    fn iter() {
+        test_iter(Inclusive::new(Array::new(0), Array::new(0)));
+        test_iter(Inclusive::new(Array::new(0), Array::new(10)));
+        test_iter(Inclusive::new(Array::new(10), Array::new(100)));
+    }
+
+    #[test]
+    fn iter_updated_front() {
+        let mut c = Inclusive::new(Array::new(5), Array::new(10));
+        assert!(c
+            .push((0u16..10u16).map(|i| (i, i)).collect())
+            .is_empty());
+
+        // Clone and update elements in the front.
+        for i in 0u16..5u16 {
+            *c.get_mut(&i).unwrap() += 10;
+        }
+
+        // Iteration yields the updated values once and leaves the container
+        // untouched.
+        for _ in 0..2 {
+            let mut elements: Vec<(u16, u16)> = c.iter().collect();
+            elements.sort();
+            assert_eq!(elements.len(), 10);
+            for (i, (k, v)) in elements.into_iter().enumerate() {
+                assert_eq!(k, i as u16);
+                assert_eq!(v, if k < 5 { k + 10 } else { k });
+            }
+        }
+        assert_eq!(c.size(), 10);
+    }
+}
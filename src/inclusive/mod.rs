@@ -3,6 +3,9 @@ mod inclusive;
 pub use inclusive::{Inclusive, InclusiveCell};
 mod building_block;
 mod get;
+mod iter;
+mod resizable;
+mod try_building_block;
 pub use get::InclusiveGetCell;
 pub(crate) mod builder;
 #[cfg(feature = "config")]
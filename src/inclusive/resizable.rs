@@ -0,0 +1,96 @@
+use super::inclusive::InclusiveCell;
+use super::Inclusive;
+use crate::{BuildingBlock, Resizable};
+
+impl<K, V, L, R> Resizable<K, V> for Inclusive<K, V, L, R>
+where
+    K: Clone,
+    V: Clone,
+    L: BuildingBlock<K, InclusiveCell<V>> + Resizable<K, InclusiveCell<V>>,
+    R: BuildingBlock<K, InclusiveCell<V>> + Resizable<K, InclusiveCell<V>>,
+{
+    /// Change the [`Inclusive`] container capacity.
+    ///
+    /// The capacity of an [`Inclusive`] container is the capacity of its
+    /// `back` container. The `front` container is only shrunk if its
+    /// capacity would exceed the new `back` container capacity.
+    /// In that case, its victims are written back to the `back` container
+    /// if they have been updated.
+    ///
+    /// Then, the `back` container is resized. Its victims are handled in
+    /// the same way as with the
+    /// [`pop()`](struct.Inclusive.html#method.pop) method, i.e. if they have
+    /// a clone in the front, the clone is taken out of the front and
+    /// returned instead.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        // Shrink the front if needed. Front victims have a matching copy in
+        // the back flagged as cloned that must be replaced with a fresh
+        // cell holding the most up to date value.
+        if self.front.capacity() > new_capacity {
+            let front: Vec<(K, InclusiveCell<V>)> = self
+                .front
+                .resize(new_capacity)
+                .into_iter()
+                .map(|(k, c)| (k, InclusiveCell::new(c.unwrap())))
+                .collect();
+            let mut keys: Vec<K> =
+                front.iter().map(|(k, _)| k.clone()).collect();
+            self.back.take_multiple(&mut keys);
+            assert_eq!(self.back.push(front).len(), 0);
+        }
+
+        // Resize the back and remove victims from the front too.
+        self.back
+            .resize(new_capacity)
+            .into_iter()
+            .map(|(k, c)| {
+                if c.is_cloned() {
+                    self.front
+                        .take(&k)
+                        .map(|(k, c)| (k, c.unwrap()))
+                        .unwrap()
+                } else {
+                    (k, c.unwrap())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Inclusive;
+    use crate::tests::test_resizable;
+    use crate::{Array, BuildingBlock, GetMut, Resizable};
+
+    #[test]
+    fn resizable() {
+        test_resizable(Inclusive::new(Array::new(0), Array::new(0)));
+        test_resizable(Inclusive::new(Array::new(0), Array::new(10)));
+        test_resizable(Inclusive::new(Array::new(10), Array::new(100)));
+    }
+
+    #[test]
+    fn resize_updated_front() {
+        let mut c = Inclusive::new(Array::new(5), Array::new(10));
+        assert!(c
+            .push((0u16..10u16).map(|i| (i, i)).collect())
+            .is_empty());
+
+        // Clone and update elements in the front.
+        for i in 0u16..5u16 {
+            *c.get_mut(&i).unwrap() += 10;
+        }
+
+        // Shrinking evicts the front clones and keeps updated values.
+        let mut out = c.resize(2);
+        assert_eq!(c.capacity(), 2);
+        assert_eq!(c.size(), 2);
+        out.append(&mut c.flush().collect());
+        out.sort();
+        for (i, (k, v)) in out.into_iter().enumerate() {
+            assert_eq!(k, i as u16);
+            assert_eq!(v, if k < 5 { k + 10 } else { k });
+        }
+    }
+}
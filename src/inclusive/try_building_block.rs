@@ -0,0 +1,142 @@
+use super::inclusive::InclusiveCell;
+use super::Inclusive;
+use crate::{Error, TryBuildingBlock};
+
+impl<K, V, L, R> Inclusive<K, V, L, R>
+where
+    K: Clone,
+    V: Clone,
+    L: TryBuildingBlock<K, InclusiveCell<V>>,
+    R: TryBuildingBlock<K, InclusiveCell<V>>,
+{
+    /// Replace elements removed from the back container with their
+    /// freshest copy taken out of the front container, if any.
+    fn try_take_front_clones(
+        &mut self,
+        back: Vec<(K, InclusiveCell<V>)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let mut front_keys: Vec<K> =
+            back.iter()
+                .filter_map(|(k, c)| {
+                    if c.is_cloned() {
+                        Some(k.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+        let front = self.front.try_take_multiple(&mut front_keys)?;
+
+        Ok(back
+            .into_iter()
+            .filter(|(_, c)| !c.is_cloned())
+            .chain(front)
+            .map(|(k, c)| (k, c.unwrap()))
+            .collect())
+    }
+}
+
+impl<K, V, L, R> TryBuildingBlock<K, V> for Inclusive<K, V, L, R>
+where
+    K: Clone,
+    V: Clone,
+    L: TryBuildingBlock<K, InclusiveCell<V>>,
+    R: TryBuildingBlock<K, InclusiveCell<V>>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        self.back.try_capacity()
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        self.back.try_size()
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        Ok(
+            self.front.try_contains(key)?
+                || self.back.try_contains(key)?,
+        )
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        match self.back.try_take(key)? {
+            None => Ok(None),
+            Some(kc) => Ok(self.try_take_front_clones(vec![kc])?.pop()),
+        }
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let back = self.back.try_take_multiple(keys)?;
+        self.try_take_front_clones(back)
+    }
+
+    fn try_pop(&mut self, size: usize) -> Result<Vec<(K, V)>, Error> {
+        let back = self.back.try_pop(size)?;
+        self.try_take_front_clones(back)
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let elements = elements
+            .into_iter()
+            .map(|(k, v)| (k, InclusiveCell::new(v)))
+            .collect();
+        let back = self.back.try_push(elements)?;
+        self.try_take_front_clones(back)
+    }
+
+    /// Empty the container and retrieve all of its elements.
+    ///
+    /// Unlike [`flush()`](struct.Inclusive.html#method.flush), updated
+    /// elements of the `front` container that cannot be written back to the
+    /// `back` container are returned with the flushed elements.
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        let front: Vec<(K, InclusiveCell<V>)> = self
+            .front
+            .try_flush()?
+            .into_iter()
+            .filter_map(|(k, c)| {
+                if c.is_updated() {
+                    Some((k, InclusiveCell::new(c.unwrap())))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let mut keys: Vec<K> =
+            front.iter().map(|(k, _)| k.clone()).collect();
+        self.back.try_take_multiple(&mut keys)?;
+        let mut out = self.back.try_push(front)?;
+        out.append(&mut self.back.try_flush()?);
+        Ok(out.into_iter().map(|(k, c)| (k, c.unwrap())).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Inclusive;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(
+            Inclusive::new(Array::new(0), Array::new(0)),
+            true,
+        );
+        test_try_building_block(
+            Inclusive::new(Array::new(0), Array::new(10)),
+            true,
+        );
+        test_try_building_block(
+            Inclusive::new(Array::new(10), Array::new(100)),
+            true,
+        );
+    }
+}
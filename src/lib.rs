@@ -7,12 +7,20 @@
 mod traits;
 pub use traits::BuildingBlock;
 pub use traits::Concurrent;
-pub use traits::{Get, GetMut};
+pub use traits::Iter;
+pub use traits::Resizable;
+pub use traits::TryBuildingBlock;
+pub use traits::{RangeGet, RangeTake};
+mod error;
+pub use error::Error;
+pub use traits::{Get, GetEntry, GetMut};
 
 //-------------------------------------------------------------------------
 // Containers
 //-------------------------------------------------------------------------
 
+mod arc;
+pub use arc::ArcCache;
 mod array;
 pub use array::Array;
 mod associative;
@@ -21,10 +29,22 @@ mod batch;
 pub use batch::Batch;
 mod btree;
 pub use btree::BTree;
+mod clock;
+pub use clock::ClockCache;
+mod clock_pro;
+pub use clock_pro::ClockProCache;
+mod eviction_listener;
+pub use eviction_listener::EvictionListener;
 mod exclusive;
-pub use exclusive::Exclusive;
+pub use exclusive::{Exclusive, WritePolicy};
+mod hashmap;
+pub use hashmap::HashMapContainer;
+mod hierarchy;
+pub use hierarchy::Hierarchy;
 mod inclusive;
 pub use inclusive::Inclusive;
+mod loader;
+pub use loader::Loader;
 mod profiler;
 pub use profiler::Profiler;
 mod flush_stopper;
@@ -33,8 +53,14 @@ mod decorator;
 pub use decorator::decorator::Decorator;
 mod sequential;
 pub use sequential::Sequential;
+mod slru;
+pub use slru::Slru;
+mod tinylfu;
+pub use tinylfu::TinyLfuAdmission;
 mod objsafe;
-pub use objsafe::{DynBuildingBlock, DynConcurrent};
+pub use objsafe::{
+    DynBuildingBlock, DynConcurrent, DynGet, DynGetBuildingBlock, DynGetMut,
+};
 #[cfg(feature = "compression")]
 mod compression;
 #[cfg(feature = "compression")]
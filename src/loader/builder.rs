@@ -0,0 +1,89 @@
+use super::Loader;
+use crate::builder::Build;
+use std::marker::PhantomData;
+
+/// `Loader` container builder.
+///
+/// This builder can be consumed later to wrap some containers into a
+/// [`Loader`](../../struct.Loader.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{Build, LoaderBuild};
+/// use byoc::builder::{ArrayBuilder, LoaderBuilder};
+///
+/// let array_builder = ArrayBuilder::new(2);
+/// let mut container =
+///     LoaderBuilder::new(array_builder, |k: &u32| Some(*k)).build();
+/// assert_eq!(*container.get_or_load(&1).unwrap(), 1);
+///
+/// // You can also chain calls:
+/// let mut container = ArrayBuilder::new(2)
+///     .with_loader(|k: &u32| Some(*k))
+///     .build();
+/// assert_eq!(*container.get_or_load(&1).unwrap(), 1);
+/// ```
+pub struct LoaderBuilder<C, B, L> {
+    pub(super) builder: B,
+    pub(super) loader: L,
+    unused: PhantomData<C>,
+}
+
+impl<C, B, L> LoaderBuilder<C, B, L> {
+    pub fn new(builder: B, loader: L) -> Self {
+        LoaderBuilder {
+            builder,
+            loader,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<C, B, L> Clone for LoaderBuilder<C, B, L>
+where
+    B: Clone,
+    L: Clone,
+{
+    fn clone(&self) -> Self {
+        LoaderBuilder {
+            builder: self.builder.clone(),
+            loader: self.loader.clone(),
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<C, B, L> Build<Loader<C, L>> for LoaderBuilder<C, B, L>
+where
+    B: Build<C>,
+{
+    fn build(self) -> Loader<C, L> {
+        Loader::new(self.builder.build(), self.loader)
+    }
+}
+
+/// Load missing elements of the container from a source.
+///
+/// ```
+/// use byoc::builder::{Build, Builder, LoaderBuild};
+///
+/// let mut container = Builder::array(10000)
+///     .with_loader(|k: &u32| Some(*k))
+///     .build();
+/// assert_eq!(*container.get_or_load(&1).unwrap(), 1);
+/// ```
+pub trait LoaderBuild<C> {
+    /// Wrap a container builder into a
+    /// [loader](../../struct.Loader.html) building block
+    /// fetching missing elements from `loader`.
+    fn with_loader<L>(self, loader: L) -> LoaderBuilder<C, Self, L>
+    where
+        Self: Sized,
+    {
+        LoaderBuilder::new(self, loader)
+    }
+}
+
+impl<C, B: Build<C>> LoaderBuild<C> for B {}
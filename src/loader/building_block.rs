@@ -0,0 +1,71 @@
+use super::Loader;
+use crate::BuildingBlock;
+
+/// The methods of this implementation call the matching method of the
+/// container wrapped in this [`Loader`] container. Nothing is loaded from
+/// the [`Loader`] source.
+impl<K, V, C, L> BuildingBlock<K, V> for Loader<C, L>
+where
+    C: BuildingBlock<K, V>,
+{
+    fn capacity(&self) -> usize {
+        self.container.capacity()
+    }
+
+    fn size(&self) -> usize {
+        self.container.size()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.container.contains(key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, predicate)
+    }
+
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        self.container.take(key)
+    }
+
+    fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
+        self.container.take_multiple(keys)
+    }
+
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        self.container.pop(size)
+    }
+
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        self.container.push(elements)
+    }
+
+    type FlushIterator = C::FlushIterator;
+    fn flush(&mut self) -> Self::FlushIterator {
+        self.container.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Loader;
+    use crate::tests::{test_building_block, TestKey, TestValue};
+    use crate::Array;
+
+    #[test]
+    fn building_block() {
+        for i in [0usize, 10usize, 100usize] {
+            test_building_block(
+                Loader::new(
+                    Array::new(i),
+                    |_: &TestKey| None::<TestValue>,
+                ),
+                true,
+            );
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::config::{
+    ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
+};
+use crate::objsafe::DynBuildingBlock;
+use crate::utils::loader::Backend;
+use crate::Loader;
+use serde::{Deserialize, Serialize};
+
+/// Configuration format for [`Loader`](../struct.Loader.html)
+/// containers.
+///
+/// This configuration format is composed of an `id` field where the
+/// `id` value must be "LoaderConfig", the configuration in toml format of
+/// the container to wrap, and the configuration in toml format of the
+/// [`Backend`](../utils/loader/struct.Backend.html) container where missing
+/// elements are loaded from and where evicted elements are written back.
+///
+/// Containers built from a configuration do not expose the
+/// [`get_or_load()`](../struct.Loader.html#method.get_or_load) method.
+/// Their [`BuildingBlock`](../trait.BuildingBlock.html) methods are the
+/// methods of the wrapped container.
+///
+/// Below is an example of the configuration of a
+/// [`Loader`](../struct.Loader.html) wrapping an
+/// [`Array`](../struct.Array.html) container and loading elements from a
+/// [`BTree`](../struct.BTree.html) container.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='LoaderConfig'
+/// [container]
+/// id='ArrayConfig'
+/// capacity=10
+/// [backend]
+/// id='BTreeConfig'
+/// capacity=100
+/// ");
+///
+/// let container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct LoaderConfig {
+    #[allow(dead_code)]
+    id: String,
+    container: toml::Value,
+    backend: toml::Value,
+}
+
+impl ConfigInstance for LoaderConfig {
+    fn id() -> &'static str {
+        "LoaderConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        let cfg: LoaderConfig = match toml::from_str(&toml) {
+            Err(e) => return Err(ConfigError::TomlFormatError(e)),
+            Ok(cfg) => cfg,
+        };
+        GenericConfig::from_toml(&cfg.container)?;
+        match GenericConfig::from_toml(&cfg.backend) {
+            Ok(_) => Ok(cfg),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        let is_resizable = self.is_resizable();
+        let container =
+            GenericConfig::from_toml(&self.container).unwrap().build();
+        let backend = Backend::new(
+            GenericConfig::from_toml(&self.backend)
+                .unwrap()
+                .build::<K, V>(),
+        );
+        match container.into_get() {
+            Ok(container) => DynBuildingBlock::new_resizable_get(
+                Loader::new(container, backend),
+                false,
+                is_resizable,
+            ),
+            Err(container) => DynBuildingBlock::new_resizable(
+                Loader::new(container, backend),
+                false,
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .is_resizable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoaderConfig;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_loader_config() {
+        let array_capacity = 10;
+        let config_str = format!(
+            "
+id='LoaderConfig'
+[container]
+id='ArrayConfig'
+capacity={}
+[backend]
+id='BTreeConfig'
+capacity=100
+",
+            array_capacity
+        );
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = LoaderConfig::from_toml(&value).unwrap();
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), array_capacity);
+    }
+
+    #[test]
+    fn test_invalid_loader_config() {
+        let config_str = "
+id='LoaderConfig'
+[container]
+id='ArrayConfig'
+capacity=10
+[backend]
+id='ArrayConfig'
+capacity='ten'
+"
+        .to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            LoaderConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+}
@@ -0,0 +1,155 @@
+use super::{Load, Loader};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, Get, GetMut};
+
+impl<C, L> Loader<C, L> {
+    /// Get a read-only smart pointer to the value matching `key`.
+    ///
+    /// If the key is not in the wrapped container, it is loaded from the
+    /// [`Loader`] source and pushed in the container first. `None` is
+    /// returned if the source does not have the key, or if the container
+    /// does not accept the loaded element.
+    pub fn get_or_load<K, V>(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, C::Target>>
+    where
+        K: Clone + PartialEq,
+        C: BuildingBlock<K, V> + Get<K, V>,
+        L: Load<K, V>,
+    {
+        if self.load(key) {
+            self.container.get(key)
+        } else {
+            None
+        }
+    }
+
+    /// Get a smart pointer to a mutable value matching `key`.
+    ///
+    /// See [`get_or_load()`](struct.Loader.html#method.get_or_load).
+    pub fn get_mut_or_load<K, V>(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, <C as GetMut<K, V>>::Target>>
+    where
+        K: Clone + PartialEq,
+        C: BuildingBlock<K, V> + GetMut<K, V>,
+        L: Load<K, V>,
+    {
+        if self.load(key) {
+            self.container.get_mut(key)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, V, C, L> Get<K, V> for Loader<C, L>
+where
+    K: Clone + PartialEq,
+    C: BuildingBlock<K, V> + Get<K, V>,
+    L: Load<K, V>,
+{
+    type Target = C::Target;
+
+    /// See [`get_or_load()`](struct.Loader.html#method.get_or_load).
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.get_or_load(key)
+    }
+}
+
+impl<K, V, C, L> GetMut<K, V> for Loader<C, L>
+where
+    K: Clone + PartialEq,
+    C: BuildingBlock<K, V> + GetMut<K, V>,
+    L: Load<K, V>,
+{
+    type Target = C::Target;
+
+    /// See [`get_mut_or_load()`](struct.Loader.html#method.get_mut_or_load).
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.get_mut_or_load(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Loader;
+    use crate::tests::{test_get, test_get_mut, TestKey, TestValue};
+    use crate::utils::loader::Backend;
+    use crate::{Array, BuildingBlock, GetMut};
+
+    #[test]
+    fn get() {
+        let none = |_: &TestKey| None::<TestValue>;
+        test_get(Loader::new(Array::new(10), none));
+        test_get_mut(Loader::new(Array::new(10), none));
+        test_get(Loader::new(
+            Array::new(10),
+            Backend::new(Array::new(10)),
+        ));
+        test_get_mut(Loader::new(
+            Array::new(10),
+            Backend::new(Array::new(10)),
+        ));
+    }
+
+    #[test]
+    fn get_or_load() {
+        let mut loads = 0usize;
+        let mut evicted = Vec::new();
+        let mut c = Loader::new(Array::new(2), |k: &TestKey| {
+            loads += 1;
+            if *k < 10 {
+                Some(*k as TestValue)
+            } else {
+                None
+            }
+        })
+        .with_sink(|mut e| evicted.append(&mut e));
+
+        // Missing keys are loaded once.
+        assert_eq!(*c.get_or_load(&0).unwrap(), 0);
+        assert_eq!(*c.get_or_load(&0).unwrap(), 0);
+        assert_eq!(*c.get_or_load(&1).unwrap(), 1);
+        // Keys missing from the source are not loaded.
+        assert!(c.get_or_load(&10).is_none());
+        assert!(!c.contains(&10));
+        // Loaded values can be modified.
+        *c.get_mut_or_load(&1).unwrap() = 11;
+        // Loading in a full container evicts elements to the sink.
+        assert_eq!(*c.get_mut_or_load(&2).unwrap(), 2);
+        assert_eq!(c.size(), 2);
+        drop(c);
+
+        assert_eq!(loads, 4);
+        assert_eq!(evicted, vec![(1, 11)]);
+    }
+
+    #[test]
+    fn backend() {
+        let mut backend = Array::new(10);
+        backend.push((0..10).map(|i| (i, i)).collect());
+        let mut c = Loader::new(Array::new(5), Backend::new(backend));
+
+        for i in 0..10 {
+            *c.get_mut(&i).unwrap() += 1;
+        }
+
+        // Every element is either in the container or in the backend.
+        assert_eq!(c.size(), 5);
+        assert_eq!(c.loader().container().size(), 5);
+        let mut elements: Vec<(u16, u16)> = c.flush().collect();
+        elements
+            .append(&mut c.loader_mut().container_mut().flush().collect());
+        elements.sort();
+        for (i, (k, v)) in elements.into_iter().enumerate() {
+            assert_eq!(k, i as u16);
+            assert_eq!(v, k + 1);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::Load;
+use crate::BuildingBlock;
+
+/// A backing [`BuildingBlock`](../../trait.BuildingBlock.html) used as the
+/// source of a [`Loader`](../../struct.Loader.html) container.
+///
+/// Elements are loaded by
+/// [taking](../../trait.BuildingBlock.html#tymethod.take) them out of the
+/// backing container, and elements evicted from the
+/// [`Loader`](../../struct.Loader.html) container are
+/// [pushed](../../trait.BuildingBlock.html#tymethod.push) back into it.
+/// Evicted elements that do not fit in the backing container are dropped.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Loader};
+/// use byoc::utils::loader::Backend;
+///
+/// let mut backend = Array::new(10);
+/// backend.push(vec![(1, 1), (2, 2)]);
+///
+/// let mut c = Loader::new(Array::new(1), Backend::new(backend));
+/// assert_eq!(*c.get_or_load(&1).unwrap(), 1);
+/// // Loading the second key evicts the first one back into the backend.
+/// assert_eq!(*c.get_or_load(&2).unwrap(), 2);
+/// assert!(!c.contains(&1));
+/// assert!(c.loader().container().contains(&1));
+/// ```
+pub struct Backend<C> {
+    container: C,
+}
+
+impl<C> Backend<C> {
+    /// Use `container` as a backing container.
+    pub fn new(container: C) -> Self {
+        Backend { container }
+    }
+
+    /// Get shared access to the backing container.
+    pub fn container(&self) -> &C {
+        &self.container
+    }
+
+    /// Get exclusive access to the backing container.
+    pub fn container_mut(&mut self) -> &mut C {
+        &mut self.container
+    }
+}
+
+impl<K, V, C> Load<K, V> for Backend<C>
+where
+    C: BuildingBlock<K, V>,
+{
+    fn load(&mut self, key: &K) -> Option<V> {
+        self.container.take(key).map(|(_, v)| v)
+    }
+
+    fn sink(&mut self, elements: Vec<(K, V)>) {
+        self.container.push(elements);
+    }
+}
+
+/// A [`Load`] source with a custom sink for evicted elements.
+///
+/// This structure is created with
+/// [`Loader::with_sink()`](../../struct.Loader.html#method.with_sink).
+/// Elements are loaded from the wrapped [`Load`] source and evicted elements
+/// are handed to a closure `FnMut(Vec<(K, V)>)`.
+pub struct WithSink<L, S> {
+    pub(super) loader: L,
+    pub(super) sink: S,
+}
+
+impl<K, V, L, S> Load<K, V> for WithSink<L, S>
+where
+    L: Load<K, V>,
+    S: FnMut(Vec<(K, V)>),
+{
+    fn load(&mut self, key: &K) -> Option<V> {
+        self.loader.load(key)
+    }
+
+    fn sink(&mut self, elements: Vec<(K, V)>) {
+        (self.sink)(elements)
+    }
+}
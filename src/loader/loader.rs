@@ -0,0 +1,135 @@
+use super::{Load, WithSink};
+use crate::BuildingBlock;
+
+/// Read-through `BuildingBlock` loading missing elements from a source.
+///
+/// This [`BuildingBlock`](trait.BuildingBlock.html) wraps a container and a
+/// source of elements implementing the
+/// [`Load`](utils/loader/trait.Load.html) trait, such as a closure
+/// `FnMut(&K) -> Option<V>` or a backing container wrapped in a
+/// [`Backend`](utils/loader/struct.Backend.html).
+///
+/// The [`get_or_load()`](struct.Loader.html#method.get_or_load) and
+/// [`get_mut_or_load()`](struct.Loader.html#method.get_mut_or_load) methods
+/// look up a key in the wrapped container. On a miss, the matching value is
+/// fetched from the source and pushed in the container before being
+/// accessed. The elements evicted from the container to make room for the
+/// loaded element are routed to the source
+/// [`sink()`](utils/loader/trait.Load.html#method.sink), which drops them by
+/// default. A custom sink can be set with
+/// [`with_sink()`](struct.Loader.html#method.with_sink).
+///
+/// [`Get`](trait.Get.html) and [`GetMut`](trait.GetMut.html) traits
+/// are implemented with the same read-through behavior while
+/// [`BuildingBlock`](trait.BuildingBlock.html) methods are forwarded to the
+/// wrapped container and never load anything.
+///
+/// [`Loader`] can also be built from a
+/// [builder pattern](builder/trait.LoaderBuild.html) and a
+/// [configuration](config/struct.LoaderConfig.html).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Loader};
+///
+/// let mut evicted = Vec::new();
+/// let mut c = Loader::new(Array::new(1), |k: &u32| Some(k * 10))
+///     .with_sink(|mut e| evicted.append(&mut e));
+///
+/// // Missing keys are loaded on access.
+/// assert_eq!(*c.get_or_load(&1).unwrap(), 10);
+/// assert!(c.contains(&1));
+///
+/// // Elements evicted to make room for loaded elements go to the sink.
+/// assert_eq!(*c.get_or_load(&2).unwrap(), 20);
+/// drop(c);
+/// assert_eq!(evicted, vec![(1, 10)]);
+/// ```
+pub struct Loader<C, L> {
+    pub(super) container: C,
+    pub(super) loader: L,
+}
+
+impl<C, L> Loader<C, L> {
+    /// Wrap `container` into a [`Loader`] fetching missing elements from
+    /// `loader`.
+    pub fn new(container: C, loader: L) -> Self {
+        Loader { container, loader }
+    }
+
+    /// Route the elements evicted from the container when an element is
+    /// loaded to `sink` instead of the loader
+    /// [`sink()`](utils/loader/trait.Load.html#method.sink) method.
+    pub fn with_sink<S>(self, sink: S) -> Loader<C, WithSink<L, S>> {
+        Loader {
+            container: self.container,
+            loader: WithSink {
+                loader: self.loader,
+                sink,
+            },
+        }
+    }
+
+    /// Get shared access to the wrapped container.
+    pub fn container(&self) -> &C {
+        &self.container
+    }
+
+    /// Get exclusive access to the wrapped container.
+    pub fn container_mut(&mut self) -> &mut C {
+        &mut self.container
+    }
+
+    /// Get shared access to the source of loaded elements.
+    pub fn loader(&self) -> &L {
+        &self.loader
+    }
+
+    /// Get exclusive access to the source of loaded elements.
+    pub fn loader_mut(&mut self) -> &mut L {
+        &mut self.loader
+    }
+
+    /// Load `key` in the container if it is not there already and
+    /// return whether the key is in the container after the call.
+    ///
+    /// The loaded element may not be inserted if the container does not
+    /// accept it. In that case, it is routed to the sink with the other
+    /// evicted elements.
+    pub(super) fn load<K, V>(&mut self, key: &K) -> bool
+    where
+        K: Clone + PartialEq,
+        C: BuildingBlock<K, V>,
+        L: Load<K, V>,
+    {
+        if self.container.contains(key) {
+            return true;
+        }
+
+        let value = match self.loader.load(key) {
+            None => return false,
+            Some(v) => v,
+        };
+
+        let evicted = self.container.push(vec![(key.clone(), value)]);
+        let loaded = evicted.iter().all(|(k, _)| k != key);
+        if !evicted.is_empty() {
+            self.loader.sink(evicted);
+        }
+        loaded
+    }
+}
+
+impl<'a, K, V, C, L> From<Loader<C, L>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a,
+    V: 'a,
+    C: 'a + BuildingBlock<K, V>,
+    L: 'a,
+{
+    fn from(loader: Loader<C, L>) -> Self {
+        crate::DynBuildingBlock::new(loader, false)
+    }
+}
@@ -0,0 +1,45 @@
+/// Source of the elements loaded into a
+/// [`Loader`](../../struct.Loader.html) container.
+///
+/// When a key is not found in the container wrapped in a
+/// [`Loader`](../../struct.Loader.html), its value is fetched with the
+/// [`load()`](trait.Load.html#tymethod.load) method and inserted in the
+/// container. The elements evicted from the container to make room for
+/// the loaded element are handed to the
+/// [`sink()`](trait.Load.html#method.sink) method.
+///
+/// This trait is implemented for closures `FnMut(&K) -> Option<V>` and for
+/// [`Backend`] containers.
+pub trait Load<K, V> {
+    /// Fetch the value matching `key` from the source, if any.
+    fn load(&mut self, key: &K) -> Option<V>;
+
+    /// Handle the elements evicted from the container when a loaded
+    /// element is inserted.
+    ///
+    /// The default implementation drops the evicted elements.
+    fn sink(&mut self, elements: Vec<(K, V)>) {
+        drop(elements)
+    }
+}
+
+impl<K, V, F> Load<K, V> for F
+where
+    F: FnMut(&K) -> Option<V>,
+{
+    fn load(&mut self, key: &K) -> Option<V> {
+        self(key)
+    }
+}
+
+mod load;
+pub use load::{Backend, WithSink};
+#[allow(clippy::module_inception)]
+mod loader;
+pub use loader::Loader;
+pub(crate) mod builder;
+mod building_block;
+#[cfg(feature = "config")]
+pub(crate) mod config;
+mod get;
+mod resizable;
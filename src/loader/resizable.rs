@@ -0,0 +1,29 @@
+use super::Loader;
+use crate::Resizable;
+
+impl<K, V, C, L> Resizable<K, V> for Loader<C, L>
+where
+    C: Resizable<K, V>,
+{
+    /// Change the capacity of the container wrapped in this [`Loader`].
+    ///
+    /// The victims of the wrapped container are returned and are not routed
+    /// to the [`Loader`] sink.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.container.resize(new_capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Loader;
+    use crate::tests::{test_resizable, TestKey, TestValue};
+    use crate::Array;
+
+    #[test]
+    fn resizable() {
+        let none = |_: &TestKey| None::<TestValue>;
+        test_resizable(Loader::new(Array::new(0), none));
+        test_resizable(Loader::new(Array::new(100), none));
+    }
+}
@@ -1,16 +1,51 @@
-use super::DynConcurrent;
-use crate::BuildingBlock;
+use super::{DynConcurrent, DynGet, DynGetBuildingBlock, DynGetMut};
+use crate::{BuildingBlock, Error, Resizable};
 use std::marker::PhantomData;
 
+// Object safe building block carrying optional capabilities.
+trait DynBuildingBlockObject<'a, K, V>:
+    BuildingBlock<K, V, FlushIterator = Box<dyn Iterator<Item = (K, V)> + 'a>>
+{
+    // Whether the container implements the `Resizable` trait.
+    fn is_resizable(&self) -> bool;
+    // Resize the container if it implements the `Resizable` trait.
+    fn resize(&mut self, new_capacity: usize) -> Option<Vec<(K, V)>>;
+    // Whether the container implements the `Get` and `GetMut` traits.
+    fn has_get_trait(&self) -> bool;
+    // Get the container as a `DynGet` object if it implements `Get`.
+    fn as_get(&mut self) -> Option<&mut (dyn DynGet<'a, K, V> + 'a)>;
+    // Get the container as a `DynGetMut` object if it implements `GetMut`.
+    fn as_get_mut(
+        &mut self,
+    ) -> Option<&mut (dyn DynGetMut<'a, K, V> + 'a)>;
+}
+
+// Upcast functions stored in `DynIterBuildingBlock` when the container
+// implements `Get` and `GetMut` traits.
+fn as_get<'a, K, V, C: 'a + DynGet<'a, K, V>>(
+    c: &mut C,
+) -> &mut (dyn DynGet<'a, K, V> + 'a) {
+    c
+}
+
+fn as_get_mut<'a, K, V, C: 'a + DynGetMut<'a, K, V>>(
+    c: &mut C,
+) -> &mut (dyn DynGetMut<'a, K, V> + 'a) {
+    c
+}
+
 // Intermediate struct to make the associated type of BuildingBlock object safe.
-#[repr(transparent)]
-struct DynIterBuildingBlock<'a, C> {
+#[allow(clippy::type_complexity)]
+struct DynIterBuildingBlock<'a, K, V, C> {
     container: C,
+    resize: Option<fn(&mut C, usize) -> Vec<(K, V)>>,
+    get: Option<fn(&mut C) -> &mut (dyn DynGet<'a, K, V> + 'a)>,
+    get_mut: Option<fn(&mut C) -> &mut (dyn DynGetMut<'a, K, V> + 'a)>,
     unused: PhantomData<&'a bool>,
 }
 
 impl<'a, K, V, C, F: 'a> BuildingBlock<K, V>
-    for DynIterBuildingBlock<'a, C>
+    for DynIterBuildingBlock<'a, K, V, C>
 where
     F: Iterator<Item = (K, V)>,
     C: BuildingBlock<K, V, FlushIterator = F>,
@@ -25,6 +60,13 @@ where
     fn contains(&self, key: &K) -> bool {
         self.container.contains(key)
     }
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, predicate)
+    }
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         self.container.take(key)
     }
@@ -39,6 +81,32 @@ where
     }
 }
 
+impl<'a, K, V, C, F: 'a> DynBuildingBlockObject<'a, K, V>
+    for DynIterBuildingBlock<'a, K, V, C>
+where
+    F: Iterator<Item = (K, V)>,
+    C: BuildingBlock<K, V, FlushIterator = F>,
+{
+    fn is_resizable(&self) -> bool {
+        self.resize.is_some()
+    }
+    fn resize(&mut self, new_capacity: usize) -> Option<Vec<(K, V)>> {
+        self.resize
+            .map(|resize| resize(&mut self.container, new_capacity))
+    }
+    fn has_get_trait(&self) -> bool {
+        self.get.is_some()
+    }
+    fn as_get(&mut self) -> Option<&mut (dyn DynGet<'a, K, V> + 'a)> {
+        self.get.map(|get| get(&mut self.container))
+    }
+    fn as_get_mut(
+        &mut self,
+    ) -> Option<&mut (dyn DynGetMut<'a, K, V> + 'a)> {
+        self.get_mut.map(|get_mut| get_mut(&mut self.container))
+    }
+}
+
 /// A pseudo-object-safe [`BuildingBlock`] wrapper.
 ///
 /// This object encapsulate a boxed container that yields boxed associated types
@@ -53,10 +121,37 @@ where
 /// know whether it is safe to convert this object into a [`DynConcurrent`]
 /// object.
 ///
+/// If the wrapped container implements the [`Resizable`] trait, the
+/// [`DynBuildingBlock`] can also be resized. This is the case of
+/// [`DynBuildingBlock`] built from a [configuration](config/index.html)
+/// which containers can all be resized, i.e do not include a
+/// [`SocketClient`](struct.SocketClient.html), or converted
+/// from containers that do not wrap other containers, such as
+/// [`Array`](struct.Array.html). Whether the [`DynBuildingBlock`] can be
+/// resized is checked with the
+/// [`is_resizable()`](struct.DynBuildingBlock.html#method.is_resizable)
+/// method.
+///
+/// Similarly, if the wrapped container implements the
+/// [`Get`](../trait.Get.html) and [`GetMut`](../trait.GetMut.html) traits,
+/// the values of the [`DynBuildingBlock`] can be accessed in place. This is
+/// the case of [`DynBuildingBlock`] built from a
+/// [configuration](config/index.html) which containers all implement these
+/// traits, or converted from containers that do not wrap other containers.
+/// This is checked with the
+/// [`has_get_trait()`](struct.DynBuildingBlock.html#method.has_get_trait)
+/// method. The [`DynBuildingBlock`] can then be made into a
+/// [`DynGetBuildingBlock`] implementing these traits with its
+/// [`into_get()`](struct.DynBuildingBlock.html#method.into_get) method, or
+/// be converted into [`DynGet`] and [`DynGetMut`] trait objects with
+/// [`as_get()`](struct.DynBuildingBlock.html#method.as_get) and
+/// [`as_get_mut()`](struct.DynBuildingBlock.html#method.as_get_mut)
+/// methods.
+///
 /// ## Examples
 ///
 /// ```
-/// use byoc::{BuildingBlock, DynBuildingBlock, Array, Sequential};
+/// use byoc::{BuildingBlock, DynBuildingBlock, Array, Get, Sequential};
 ///
 /// // Creation of a boxed array with boxed associated types.
 /// let dyn_array = DynBuildingBlock::from(Array::<(u64,u64)>::new(10));
@@ -68,20 +163,20 @@ where
 /// // Creation of a boxed concurrent array.
 /// let dyn_sequential = DynBuildingBlock::from(Sequential::new(Array::<(u64,u64)>::new(10)));
 /// assert!(dyn_sequential.into_concurrent().is_ok());
+///
+/// // Values of the boxed array can be accessed in place.
+/// let mut dyn_array = DynBuildingBlock::from(Array::new(10));
+/// dyn_array.push(vec![(1u64, 2u64)]);
+/// assert!(dyn_array.has_get_trait());
+/// let mut dyn_array = dyn_array.into_get().unwrap_or_else(|_| panic!());
+/// assert_eq!(*dyn_array.get(&1).unwrap(), 2);
 /// ```
-#[allow(clippy::type_complexity)]
 pub struct DynBuildingBlock<'a, K, V> {
-    building_block: Box<
-        dyn BuildingBlock<
-                K,
-                V,
-                FlushIterator = Box<dyn Iterator<Item = (K, V)> + 'a>,
-            > + 'a,
-    >,
+    building_block: Box<dyn DynBuildingBlockObject<'a, K, V> + 'a>,
     has_concurrent_trait: bool,
 }
 
-impl<'a, K, V> DynBuildingBlock<'a, K, V> {
+impl<'a, K: 'a, V: 'a> DynBuildingBlock<'a, K, V> {
     /// Create a [`DynBuildingBlock`] from a container, specifying whether it
     /// should be possible to clone the box pointer to use it concurrently.
     pub(crate) fn new<
@@ -94,17 +189,173 @@ impl<'a, K, V> DynBuildingBlock<'a, K, V> {
         Self {
             building_block: Box::new(DynIterBuildingBlock {
                 container,
+                resize: None,
+                get: None,
+                get_mut: None,
+                unused: PhantomData,
+            }),
+            has_concurrent_trait,
+        }
+    }
+
+    /// Create a [`DynBuildingBlock`] from a [`Resizable`] container,
+    /// specifying whether it should be possible to clone the box pointer to
+    /// use it concurrently and whether it is safe to call the container
+    /// [`Resizable`] method.
+    ///
+    /// The latter is not the case when the container wraps other
+    /// [`DynBuildingBlock`] that cannot be resized.
+    pub(crate) fn new_resizable<
+        F: Iterator<Item = (K, V)> + 'a,
+        C: 'a + BuildingBlock<K, V, FlushIterator = F> + Resizable<K, V>,
+    >(
+        container: C,
+        has_concurrent_trait: bool,
+        is_resizable: bool,
+    ) -> Self {
+        Self {
+            building_block: Box::new(DynIterBuildingBlock {
+                container,
+                resize: is_resizable
+                    .then_some(|c: &mut C, n: usize| c.resize(n)),
+                get: None,
+                get_mut: None,
+                unused: PhantomData,
+            }),
+            has_concurrent_trait,
+        }
+    }
+
+    /// Create a [`DynBuildingBlock`] from a container implementing
+    /// [`Get`](../trait.Get.html) and [`GetMut`](../trait.GetMut.html)
+    /// traits, specifying whether it should be possible to clone the box
+    /// pointer to use it concurrently.
+    pub(crate) fn new_get<
+        F: Iterator<Item = (K, V)> + 'a,
+        C: 'a
+            + BuildingBlock<K, V, FlushIterator = F>
+            + DynGet<'a, K, V>
+            + DynGetMut<'a, K, V>,
+    >(
+        container: C,
+        has_concurrent_trait: bool,
+    ) -> Self {
+        Self {
+            building_block: Box::new(DynIterBuildingBlock {
+                container,
+                resize: None,
+                get: Some(as_get::<K, V, C>),
+                get_mut: Some(as_get_mut::<K, V, C>),
                 unused: PhantomData,
             }),
             has_concurrent_trait,
         }
     }
 
-    // /// Returns whether the object can be succefully made into a
-    // /// [`DynConcurrent`] object.
-    // pub(crate) fn has_concurrent_trait(&self) -> bool {
-    //     self.has_concurrent_trait
-    // }
+    /// Create a [`DynBuildingBlock`] from a [`Resizable`] container
+    /// implementing [`Get`](../trait.Get.html) and
+    /// [`GetMut`](../trait.GetMut.html) traits. See
+    /// [`new_resizable()`](struct.DynBuildingBlock.html#method.new_resizable)
+    /// and [`new_get()`](struct.DynBuildingBlock.html#method.new_get).
+    pub(crate) fn new_resizable_get<
+        F: Iterator<Item = (K, V)> + 'a,
+        C: 'a
+            + BuildingBlock<K, V, FlushIterator = F>
+            + Resizable<K, V>
+            + DynGet<'a, K, V>
+            + DynGetMut<'a, K, V>,
+    >(
+        container: C,
+        has_concurrent_trait: bool,
+        is_resizable: bool,
+    ) -> Self {
+        Self {
+            building_block: Box::new(DynIterBuildingBlock {
+                container,
+                resize: is_resizable
+                    .then_some(|c: &mut C, n: usize| c.resize(n)),
+                get: Some(as_get::<K, V, C>),
+                get_mut: Some(as_get_mut::<K, V, C>),
+                unused: PhantomData,
+            }),
+            has_concurrent_trait,
+        }
+    }
+
+    /// Returns whether the wrapped container implements the [`Resizable`]
+    /// trait, i.e whether it is safe to call
+    /// [`resize()`](struct.DynBuildingBlock.html#method.resize) on this
+    /// [`DynBuildingBlock`].
+    pub fn is_resizable(&self) -> bool {
+        self.building_block.is_resizable()
+    }
+
+    /// Returns whether the wrapped container implements the
+    /// [`Get`](../trait.Get.html) and [`GetMut`](../trait.GetMut.html)
+    /// traits, i.e whether this [`DynBuildingBlock`] can be made into a
+    /// [`DynGetBuildingBlock`] with
+    /// [`into_get()`](struct.DynBuildingBlock.html#method.into_get).
+    pub fn has_get_trait(&self) -> bool {
+        self.building_block.has_get_trait()
+    }
+
+    /// Get this [`BuildingBlock`](../trait.BuildingBlock.html) as a
+    /// [`DynGet`] object.
+    ///
+    /// If the wrapped container does not support the trait, `None` is
+    /// returned.
+    pub fn as_get(&mut self) -> Option<&mut (dyn DynGet<'a, K, V> + 'a)> {
+        self.building_block.as_get()
+    }
+
+    /// Get this [`BuildingBlock`](../trait.BuildingBlock.html) as a
+    /// [`DynGetMut`] object.
+    ///
+    /// If the wrapped container does not support the trait, `None` is
+    /// returned.
+    pub fn as_get_mut(
+        &mut self,
+    ) -> Option<&mut (dyn DynGetMut<'a, K, V> + 'a)> {
+        self.building_block.as_get_mut()
+    }
+
+    /// Returns whether the object can be succefully made into a
+    /// [`DynConcurrent`] object.
+    pub(super) fn has_concurrent_trait(&self) -> bool {
+        self.has_concurrent_trait
+    }
+
+    /// Make this [`BuildingBlock`](../trait.BuildingBlock.html) into a
+    /// [`BuildingBlock`](../trait.BuildingBlock.html) implementing
+    /// [`Get`](../trait.Get.html) and [`GetMut`](../trait.GetMut.html)
+    /// traits.
+    ///
+    /// If the wrapped container does not support these traits, an error is
+    /// returned.
+    pub fn into_get(self) -> Result<DynGetBuildingBlock<'a, K, V>, Self> {
+        if self.has_get_trait() {
+            Ok(DynGetBuildingBlock::new(self))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Make all the `containers` into
+    /// [`DynGetBuildingBlock`] if they all support the
+    /// [`Get`](../trait.Get.html) and [`GetMut`](../trait.GetMut.html)
+    /// traits. Otherwise, the containers are returned as an error.
+    pub(crate) fn into_get_all(
+        containers: Vec<Self>,
+    ) -> Result<Vec<DynGetBuildingBlock<'a, K, V>>, Vec<Self>> {
+        if containers.iter().all(|c| c.has_get_trait()) {
+            Ok(containers
+                .into_iter()
+                .map(DynGetBuildingBlock::new)
+                .collect())
+        } else {
+            Err(containers)
+        }
+    }
 
     /// Make this [`BuildingBlock`](../trait.BuildingBlock.html) into an
     /// [`Concurrent`](../traits/trait.Concurrent.html)
@@ -130,6 +381,13 @@ impl<'a, K: 'a, V: 'a> BuildingBlock<K, V> for DynBuildingBlock<'a, K, V> {
     fn contains(&self, key: &K) -> bool {
         self.building_block.contains(key)
     }
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.building_block.contains_if(key, predicate)
+    }
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         self.building_block.take(key)
     }
@@ -148,3 +406,97 @@ impl<'a, K: 'a, V: 'a> BuildingBlock<K, V> for DynBuildingBlock<'a, K, V> {
         self.building_block.flush()
     }
 }
+
+impl<'a, K: 'a, V: 'a> Resizable<K, V> for DynBuildingBlock<'a, K, V> {
+    /// Change the capacity of the wrapped container.
+    ///
+    /// ## Panics
+    ///
+    /// This method panics if the wrapped container does not implement the
+    /// [`Resizable`] trait. See
+    /// [`is_resizable()`](struct.DynBuildingBlock.html#method.is_resizable)
+    /// and
+    /// [`try_resize()`](struct.DynBuildingBlock.html#method.try_resize).
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.building_block
+            .resize(new_capacity)
+            .expect("DynBuildingBlock container is not resizable.")
+    }
+
+    /// Change the capacity of the wrapped container if it implements the
+    /// [`Resizable`] trait. Otherwise, [`Error::NotResizable`] is
+    /// returned.
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.building_block
+            .resize(new_capacity)
+            .ok_or(Error::NotResizable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynBuildingBlock;
+    use crate::tests::{
+        test_get, test_get_mut, test_resizable, TestKey, TestValue,
+    };
+    use crate::{
+        Array, BuildingBlock, Error, Exclusive, Resizable, Sequential,
+    };
+
+    #[test]
+    fn resizable() {
+        let c = DynBuildingBlock::from(Array::new(10));
+        assert!(c.is_resizable());
+        test_resizable(c);
+
+        let c = DynBuildingBlock::from(Sequential::new(Array::<(
+            TestKey,
+            TestValue,
+        )>::new(
+            10
+        )));
+        assert!(!c.is_resizable());
+        let mut c = c;
+        assert!(matches!(c.try_resize(20), Err(Error::NotResizable)));
+        assert_eq!(c.capacity(), 10);
+    }
+
+    #[test]
+    fn get() {
+        let mut c = DynBuildingBlock::from(Array::new(10));
+        assert!(c.has_get_trait());
+        assert!(c.as_get().is_some());
+        assert!(c.as_get_mut().is_some());
+        test_get(c.into_get().ok().unwrap());
+        test_get_mut(
+            DynBuildingBlock::from(Array::new(10))
+                .into_get()
+                .ok()
+                .unwrap(),
+        );
+
+        // Containers built from `DynGetBuildingBlock` support the traits
+        // too.
+        test_get(Exclusive::new(
+            DynBuildingBlock::from(Array::new(10))
+                .into_get()
+                .ok()
+                .unwrap(),
+            DynBuildingBlock::from(Array::new(10)),
+        ));
+
+        let mut c = DynBuildingBlock::from(Sequential::new(Array::<(
+            TestKey,
+            TestValue,
+        )>::new(
+            10
+        )));
+        assert!(!c.has_get_trait());
+        assert!(c.as_get().is_none());
+        assert!(c.push(vec![(1, 1)]).is_empty());
+        assert!(c.into_get().is_err());
+    }
+}
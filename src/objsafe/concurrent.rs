@@ -1,5 +1,6 @@
+use crate::utils::get::LifeTimeGuard;
 use crate::utils::lock::RWLock;
-use crate::{BuildingBlock, Concurrent};
+use crate::{BuildingBlock, Concurrent, Error, Get, GetMut, Resizable};
 
 /// A wrapper around a [`DynBuildingBlock`](struct.DynBuildingBlock.html) that
 /// provides the `Concurrent` trait.
@@ -71,6 +72,14 @@ where
     fn contains(&self, key: &K) -> bool {
         unsafe { self.building_block.as_ref().unwrap() }.contains(key)
     }
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        unsafe { self.building_block.as_ref().unwrap() }
+            .contains_if(key, predicate)
+    }
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         unsafe { self.building_block.as_mut().unwrap() }.take(key)
     }
@@ -90,3 +99,44 @@ where
         unsafe { self.building_block.as_mut().unwrap() }.flush()
     }
 }
+
+impl<K, V, C> Resizable<K, V> for DynConcurrent<C>
+where
+    C: Resizable<K, V>,
+{
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        unsafe { self.building_block.as_mut().unwrap() }
+            .resize(new_capacity)
+    }
+
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        unsafe { self.building_block.as_mut().unwrap() }
+            .try_resize(new_capacity)
+    }
+}
+
+impl<K, V, C> Get<K, V> for DynConcurrent<C>
+where
+    C: Get<K, V>,
+{
+    type Target = C::Target;
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        unsafe { self.building_block.as_mut().unwrap() }.get(key)
+    }
+}
+
+impl<K, V, C> GetMut<K, V> for DynConcurrent<C>
+where
+    C: GetMut<K, V>,
+{
+    type Target = C::Target;
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        unsafe { self.building_block.as_mut().unwrap() }.get_mut(key)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::utils::get::LifeTimeGuard;
+use crate::{Get, GetMut};
+use std::ops::{Deref, DerefMut};
+
+/// Boxed read-only cell returned by [`DynGet`] trait objects.
+///
+/// The cell wraps the cell returned by the container
+/// [`Get`](../../trait.Get.html) implementation such that containers with
+/// different cell types can hide behind the same trait object.
+pub struct DynGetCell<'a, V> {
+    value: Box<dyn Deref<Target = V> + 'a>,
+}
+
+impl<'a, V> Deref for DynGetCell<'a, V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        self.value.deref()
+    }
+}
+
+/// Boxed read-write cell returned by [`DynGetMut`] trait objects.
+///
+/// The cell wraps the cell returned by the container
+/// [`GetMut`](../../trait.GetMut.html) implementation such that containers
+/// with different cell types can hide behind the same trait object.
+pub struct DynGetMutCell<'a, V> {
+    value: Box<dyn DerefMut<Target = V> + 'a>,
+}
+
+impl<'a, V> Deref for DynGetMutCell<'a, V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        self.value.deref()
+    }
+}
+
+impl<'a, V> DerefMut for DynGetMutCell<'a, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value.deref_mut()
+    }
+}
+
+/// Object safe variant of the [`Get`](trait.Get.html) trait.
+///
+/// This trait is implemented for all the containers implementing
+/// [`Get`](trait.Get.html). The cells they return are boxed into a
+/// [`DynGetCell`](utils/get/struct.DynGetCell.html) such that the trait
+/// does not have an associated type and can be used as a trait object.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, DynGet};
+///
+/// let mut array = Array::new(1);
+/// array.push(vec![(1, 2)]);
+/// let c: &mut dyn DynGet<u32, u32> = &mut array;
+/// assert_eq!(*c.dyn_get(&1).unwrap(), 2);
+/// ```
+pub trait DynGet<'a, K, V> {
+    /// Get a read-only smart pointer to a value inside the container.
+    fn dyn_get(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, DynGetCell<'a, V>>>;
+}
+
+impl<'a, K, V, C> DynGet<'a, K, V> for C
+where
+    C: Get<K, V>,
+    C::Target: 'a,
+{
+    fn dyn_get(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, DynGetCell<'a, V>>> {
+        self.get(key).map(|t| {
+            t.map(|value| DynGetCell {
+                value: Box::new(value),
+            })
+        })
+    }
+}
+
+/// Object safe variant of the [`GetMut`](trait.GetMut.html) trait.
+///
+/// This trait is implemented for all the containers implementing
+/// [`GetMut`](trait.GetMut.html). The cells they return are boxed into a
+/// [`DynGetMutCell`](utils/get/struct.DynGetMutCell.html) such that the
+/// trait does not have an associated type and can be used as a trait
+/// object.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, DynGetMut};
+///
+/// let mut array = Array::new(1);
+/// array.push(vec![(1, 2)]);
+/// let c: &mut dyn DynGetMut<u32, u32> = &mut array;
+/// *c.dyn_get_mut(&1).unwrap() = 3;
+/// assert_eq!(*c.dyn_get_mut(&1).unwrap(), 3);
+/// ```
+pub trait DynGetMut<'a, K, V> {
+    /// Get a smart pointer to a mutable value inside the container.
+    fn dyn_get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, DynGetMutCell<'a, V>>>;
+}
+
+impl<'a, K, V, C> DynGetMut<'a, K, V> for C
+where
+    C: GetMut<K, V>,
+    C::Target: 'a,
+{
+    fn dyn_get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, DynGetMutCell<'a, V>>> {
+        self.get_mut(key).map(|t| {
+            t.map(|value| DynGetMutCell {
+                value: Box::new(value),
+            })
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use super::{DynBuildingBlock, DynConcurrent, DynGetCell, DynGetMutCell};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, Error, Get, GetMut, Resizable};
+
+/// A [`DynBuildingBlock`](struct.DynBuildingBlock.html) which values can be
+/// accessed in place.
+///
+/// This object can only be constructed from a
+/// [`DynBuildingBlock`](struct.DynBuildingBlock.html) which wrapped
+/// container implements the [`Get`] and [`GetMut`] traits, using the latter
+/// [`into_get()`](struct.DynBuildingBlock.html#method.into_get) method.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock, Array, Get, Sequential};
+///
+/// let mut dyn_array = DynBuildingBlock::from(Array::new(10))
+///     .into_get()
+///     .unwrap_or_else(|_| panic!("Array implements Get."));
+/// dyn_array.push(vec![(1u64, 2u64)]);
+/// assert_eq!(*dyn_array.get(&1).unwrap(), 2);
+///
+/// // Sequential does not implement Get.
+/// let dyn_sequential =
+///     DynBuildingBlock::from(Sequential::new(Array::<(u64,u64)>::new(10)));
+/// assert!(dyn_sequential.into_get().is_err());
+/// ```
+pub struct DynGetBuildingBlock<'a, K, V> {
+    building_block: DynBuildingBlock<'a, K, V>,
+}
+
+impl<'a, K: 'a, V: 'a> DynGetBuildingBlock<'a, K, V> {
+    pub(super) fn new(building_block: DynBuildingBlock<'a, K, V>) -> Self {
+        Self { building_block }
+    }
+
+    /// Get back the [`DynBuildingBlock`](struct.DynBuildingBlock.html)
+    /// this object was made from.
+    pub fn into_inner(self) -> DynBuildingBlock<'a, K, V> {
+        self.building_block
+    }
+
+    /// Returns whether the wrapped container implements the [`Resizable`]
+    /// trait. See
+    /// [`DynBuildingBlock::is_resizable()`](struct.DynBuildingBlock.html#method.is_resizable).
+    pub fn is_resizable(&self) -> bool {
+        self.building_block.is_resizable()
+    }
+
+    /// Make this [`BuildingBlock`](../trait.BuildingBlock.html) into an
+    /// [`Concurrent`](../traits/trait.Concurrent.html)
+    /// [`BuildingBlock`](../trait.BuildingBlock.html).
+    ///
+    /// If the object does not support the trait, an error is returned.
+    pub fn into_concurrent(self) -> Result<DynConcurrent<Self>, Self> {
+        if self.building_block.has_concurrent_trait() {
+            Ok(DynConcurrent::new(self))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<'a, K: 'a, V: 'a> BuildingBlock<K, V>
+    for DynGetBuildingBlock<'a, K, V>
+{
+    fn capacity(&self) -> usize {
+        self.building_block.capacity()
+    }
+    fn size(&self) -> usize {
+        self.building_block.size()
+    }
+    fn contains(&self, key: &K) -> bool {
+        self.building_block.contains(key)
+    }
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.building_block.contains_if(key, predicate)
+    }
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        self.building_block.take(key)
+    }
+    fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
+        self.building_block.take_multiple(keys)
+    }
+    fn pop(&mut self, n: usize) -> Vec<(K, V)> {
+        self.building_block.pop(n)
+    }
+    fn push(&mut self, values: Vec<(K, V)>) -> Vec<(K, V)> {
+        self.building_block.push(values)
+    }
+
+    type FlushIterator = Box<dyn Iterator<Item = (K, V)> + 'a>;
+    fn flush(&mut self) -> Self::FlushIterator {
+        self.building_block.flush()
+    }
+}
+
+impl<'a, K: 'a, V: 'a> Resizable<K, V> for DynGetBuildingBlock<'a, K, V> {
+    /// Change the capacity of the wrapped container.
+    ///
+    /// ## Panics
+    ///
+    /// This method panics if the wrapped container does not implement the
+    /// [`Resizable`] trait. See
+    /// [`is_resizable()`](struct.DynGetBuildingBlock.html#method.is_resizable).
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.building_block.resize(new_capacity)
+    }
+
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.building_block.try_resize(new_capacity)
+    }
+}
+
+// The wrapped container was checked to implement `Get` and `GetMut` in
+// `DynBuildingBlock::into_get()`.
+impl<'a, K: 'a, V: 'a> Get<K, V> for DynGetBuildingBlock<'a, K, V> {
+    type Target = DynGetCell<'a, V>;
+
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.building_block.as_get().unwrap().dyn_get(key)
+    }
+}
+
+impl<'a, K: 'a, V: 'a> GetMut<K, V> for DynGetBuildingBlock<'a, K, V> {
+    type Target = DynGetMutCell<'a, V>;
+
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        self.building_block.as_get_mut().unwrap().dyn_get_mut(key)
+    }
+}
@@ -3,3 +3,9 @@ pub use building_block::DynBuildingBlock;
 
 mod concurrent;
 pub use concurrent::DynConcurrent;
+
+mod get;
+pub use get::{DynGet, DynGetCell, DynGetMut, DynGetMutCell};
+
+mod get_building_block;
+pub use get_building_block::DynGetBuildingBlock;
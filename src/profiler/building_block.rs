@@ -87,6 +87,27 @@ where
         out
     }
 
+    /// Check if container contains a matching key which value satisfies
+    /// `predicate`.
+    ///
+    /// This is profiled the same way as
+    /// [`contains()`](trait.BuildingBlock.html#tymethod.contains).
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        let (time, out) = time_it!(self.cache.contains_if(key, predicate));
+        Clone::clone(&self.stats).as_mut().contains.add(1, time, 0);
+        match out {
+            true => Clone::clone(&self.stats).as_mut().hit.add(1, time, 0),
+            false => {
+                Clone::clone(&self.stats).as_mut().miss.add(1, time, 0)
+            }
+        };
+        out
+    }
+
     /// Take the matching key/value pair out of the container.
     ///
     /// This is calls the same method from the wrapped container.
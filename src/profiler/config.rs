@@ -77,13 +77,34 @@ impl ConfigInstance for ProfilerConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
+        let is_resizable = self.is_resizable();
         let is_concurrent = self.is_concurrent();
-        DynBuildingBlock::new(
-            Profiler::new(
-                GenericConfig::from_toml(&self.container).unwrap().build(),
+        let container =
+            GenericConfig::from_toml(&self.container).unwrap().build();
+        match container.into_get() {
+            Ok(container) => DynBuildingBlock::new_resizable_get(
+                Profiler::new(container),
+                is_concurrent,
+                is_resizable,
             ),
-            is_concurrent,
-        )
+            Err(container) => DynBuildingBlock::new_resizable(
+                Profiler::new(container),
+                is_concurrent,
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .is_resizable()
     }
 
     fn is_concurrent(&self) -> bool {
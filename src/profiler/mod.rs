@@ -9,3 +9,6 @@ mod concurrent;
 #[cfg(feature = "config")]
 pub(crate) mod config;
 mod get;
+mod range;
+mod resizable;
+mod try_building_block;
@@ -0,0 +1,43 @@
+use super::Profiler;
+use crate::{RangeGet, RangeTake};
+use std::ops::RangeBounds;
+
+/// At the moment no profiling is performed on these methods. They call the
+/// matching method of the container wrapped in this [`Profiler`] container.
+impl<K, V, C> RangeGet<K, V> for Profiler<C>
+where
+    C: RangeGet<K, V>,
+{
+    fn contains_range<R: RangeBounds<K>>(&self, range: R) -> bool {
+        self.cache.contains_range(range)
+    }
+
+    fn keys_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<K> {
+        self.cache.keys_in_range(range)
+    }
+}
+
+/// At the moment no profiling is performed on this method. It calls the
+/// matching method of the container wrapped in this [`Profiler`] container.
+impl<K, V, C> RangeTake<K, V> for Profiler<C>
+where
+    C: RangeTake<K, V>,
+{
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)> {
+        self.cache.take_range(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+    use crate::tests::test_range;
+    use crate::{Array, BTree};
+
+    #[test]
+    fn range() {
+        test_range(Profiler::new(Array::new(0)));
+        test_range(Profiler::new(Array::new(100)));
+        test_range(Profiler::new(BTree::new(100)));
+    }
+}
@@ -0,0 +1,27 @@
+use super::Profiler;
+use crate::Resizable;
+
+impl<K, V, C> Resizable<K, V> for Profiler<C>
+where
+    C: Resizable<K, V>,
+{
+    /// Change the capacity of the container wrapped in this [`Profiler`].
+    ///
+    /// At the moment no profiling is performed on this method.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.cache.resize(new_capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+    use crate::tests::test_resizable;
+    use crate::Array;
+
+    #[test]
+    fn resizable() {
+        test_resizable(Profiler::new(Array::new(0)));
+        test_resizable(Profiler::new(Array::new(100)));
+    }
+}
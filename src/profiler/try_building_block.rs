@@ -0,0 +1,60 @@
+use super::Profiler;
+use crate::{Error, TryBuildingBlock};
+
+/// At the moment no profiling is performed on these methods. They call the
+/// matching method of the container wrapped in this [`Profiler`] container.
+impl<K, V, C> TryBuildingBlock<K, V> for Profiler<C>
+where
+    C: TryBuildingBlock<K, V>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        self.cache.try_capacity()
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        self.cache.try_size()
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        self.cache.try_contains(key)
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        self.cache.try_take(key)
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.cache.try_take_multiple(keys)
+    }
+
+    fn try_pop(&mut self, n: usize) -> Result<Vec<(K, V)>, Error> {
+        self.cache.try_pop(n)
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        self.cache.try_push(elements)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        self.cache.try_flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(Profiler::new(Array::new(0)), true);
+        test_try_building_block(Profiler::new(Array::new(100)), true);
+    }
+}
@@ -31,6 +31,16 @@ where
         container.contains(key)
     }
 
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        let _lock = self.lock.lock_for(()).unwrap();
+        let container = self.container.as_ref();
+        container.contains_if(key, predicate)
+    }
+
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         let _ = self.lock.lock_mut_for(()).unwrap();
         let mut container = self.container.as_mut();
@@ -76,12 +76,33 @@ impl ConfigInstance for SequentialConfig {
     fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
         self,
     ) -> DynBuildingBlock<'a, K, V> {
-        DynBuildingBlock::new(
-            Sequential::new(
-                GenericConfig::from_toml(&self.container).unwrap().build(),
+        let is_resizable = self.is_resizable();
+        let container =
+            GenericConfig::from_toml(&self.container).unwrap().build();
+        match container.into_get() {
+            Ok(container) => DynBuildingBlock::new_resizable_get(
+                Sequential::new(container),
+                true,
+                is_resizable,
             ),
-            true,
-        )
+            Err(container) => DynBuildingBlock::new_resizable(
+                Sequential::new(container),
+                true,
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.container)
+            .unwrap()
+            .is_resizable()
     }
 
     fn is_concurrent(&self) -> bool {
@@ -0,0 +1,57 @@
+use super::{Sequential, SequentialCell};
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, GetEntry};
+
+impl<K, V, C> GetEntry<K, V> for Sequential<C>
+where
+    C: GetEntry<K, V> + BuildingBlock<K, V>,
+{
+    type Target = SequentialCell<C::Target>;
+
+    /// The container is locked exclusively until the returned entry is
+    /// dropped or, for vacant entries, inserted. Therefore, no other
+    /// thread can insert the same key in between.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        self.lock.lock_mut().unwrap();
+        let found = match self.container.as_mut().entry(key) {
+            Entry::Occupied(e) => {
+                let (key, value) = e.into_parts();
+                Ok((key, value.unwrap()))
+            }
+            Entry::Vacant(e) => Err(e.into_key()),
+        };
+
+        match found {
+            Ok((key, value)) => Entry::Occupied(OccupiedEntry::new(
+                key,
+                LifeTimeGuard::new(SequentialCell::new(value, &self.lock)),
+            )),
+            Err(key) => {
+                let lock = SequentialCell::new((), &self.lock);
+                let container = &mut self.container;
+                Entry::Vacant(VacantEntry::new(
+                    key,
+                    Box::new(move |k, v| {
+                        let out = container.as_mut().push(vec![(k, v)]);
+                        drop(lock);
+                        out
+                    }),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequential;
+    use crate::tests::test_entry;
+    use crate::Array;
+
+    #[test]
+    fn entry() {
+        test_entry(Sequential::new(Array::new(0)));
+        test_entry(Sequential::new(Array::new(100)));
+    }
+}
@@ -0,0 +1,35 @@
+use super::Sequential;
+use crate::Iter;
+
+impl<K, V, C> Iter<K, V> for Sequential<C>
+where
+    C: Iter<K, V>,
+{
+    type Iterator<'a>
+        = std::vec::IntoIter<(K, V)>
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of the wrapped container.
+    ///
+    /// The elements are collected while holding the lock such that the
+    /// iteration is a snapshot of the container content.
+    fn iter(&self) -> Self::Iterator<'_> {
+        let _lock = self.lock.lock_for(()).unwrap();
+        let container = self.container.as_ref();
+        container.iter().collect::<Vec<(K, V)>>().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequential;
+    use crate::tests::test_iter;
+    use crate::Array;
+
+    #[test]
+    fn iter() {
+        test_iter(Sequential::new(Array::new(0)));
+        test_iter(Sequential::new(Array::new(100)));
+    }
+}
@@ -3,7 +3,12 @@ mod sequential;
 pub use sequential::Sequential;
 mod building_block;
 mod concurrent;
+mod entry;
 mod get;
+mod iter;
+mod range;
+mod resizable;
+mod try_building_block;
 pub use get::SequentialCell;
 pub(crate) mod builder;
 #[cfg(feature = "config")]
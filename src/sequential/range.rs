@@ -0,0 +1,42 @@
+use super::Sequential;
+use crate::{RangeGet, RangeTake};
+use std::ops::RangeBounds;
+
+impl<K, V, C> RangeGet<K, V> for Sequential<C>
+where
+    C: RangeGet<K, V>,
+{
+    fn contains_range<R: RangeBounds<K>>(&self, range: R) -> bool {
+        let _lock = self.lock.lock_for(()).unwrap();
+        self.container.as_ref().contains_range(range)
+    }
+
+    fn keys_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<K> {
+        let _lock = self.lock.lock_for(()).unwrap();
+        self.container.as_ref().keys_in_range(range)
+    }
+}
+
+impl<K, V, C> RangeTake<K, V> for Sequential<C>
+where
+    C: RangeTake<K, V>,
+{
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        self.container.as_mut().take_range(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequential;
+    use crate::tests::test_range;
+    use crate::{Array, BTree};
+
+    #[test]
+    fn range() {
+        test_range(Sequential::new(Array::new(0)));
+        test_range(Sequential::new(Array::new(100)));
+        test_range(Sequential::new(BTree::new(100)));
+    }
+}
@@ -0,0 +1,26 @@
+use super::Sequential;
+use crate::{BuildingBlock, Resizable};
+
+impl<K, V, C> Resizable<K, V> for Sequential<C>
+where
+    C: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        let mut container = self.container.as_mut();
+        container.resize(new_capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequential;
+    use crate::tests::test_resizable;
+    use crate::Array;
+
+    #[test]
+    fn resizable() {
+        test_resizable(Sequential::new(Array::new(0)));
+        test_resizable(Sequential::new(Array::new(100)));
+    }
+}
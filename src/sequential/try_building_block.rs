@@ -0,0 +1,66 @@
+use super::Sequential;
+use crate::{Error, TryBuildingBlock};
+
+impl<K, V, C> TryBuildingBlock<K, V> for Sequential<C>
+where
+    C: TryBuildingBlock<K, V>,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        let _lock = self.lock.lock_for(()).unwrap();
+        self.container.as_ref().try_capacity()
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        let _lock = self.lock.lock_for(()).unwrap();
+        self.container.as_ref().try_size()
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        let _lock = self.lock.lock_for(()).unwrap();
+        self.container.as_ref().try_contains(key)
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        self.container.as_mut().try_take(key)
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        self.container.as_mut().try_take_multiple(keys)
+    }
+
+    fn try_pop(&mut self, n: usize) -> Result<Vec<(K, V)>, Error> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        self.container.as_mut().try_pop(n)
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        self.container.as_mut().try_push(elements)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        let _lock = self.lock.lock_mut_for(()).unwrap();
+        self.container.as_mut().try_flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequential;
+    use crate::tests::test_try_building_block;
+    use crate::Array;
+
+    #[test]
+    fn try_building_block() {
+        test_try_building_block(Sequential::new(Array::new(0)), true);
+        test_try_building_block(Sequential::new(Array::new(100)), true);
+    }
+}
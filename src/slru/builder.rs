@@ -0,0 +1,114 @@
+use crate::builder::Build;
+use crate::{BuildingBlock, Resizable, Slru};
+use std::marker::PhantomData;
+
+/// `Slru` container builder.
+///
+/// This builder can be consumed later to spawn an
+/// [`Slru`](../../struct.Slru.html) container from the builders of its
+/// probationary and protected segments.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{ArrayBuilder, Build, SlruBuild, SlruBuilder};
+///
+/// let mut container =
+///     SlruBuilder::new(ArrayBuilder::new(2), ArrayBuilder::new(2)).build();
+/// container.push(vec![(1, 2)]);
+///
+/// // You can also chain calls:
+/// let mut container = ArrayBuilder::new(2)
+///     .slru(ArrayBuilder::new(8))
+///     .with_protected_ratio(0.5)
+///     .build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct SlruBuilder<K, V, P, PB, R, RB> {
+    pub(super) pbuilder: PB,
+    pub(super) rbuilder: RB,
+    pub(super) protected_ratio: Option<f32>,
+    unused: PhantomData<(K, V, P, R)>,
+}
+
+impl<K, V, P, PB, R, RB> Clone for SlruBuilder<K, V, P, PB, R, RB>
+where
+    PB: Clone,
+    RB: Clone,
+{
+    fn clone(&self) -> Self {
+        SlruBuilder {
+            pbuilder: self.pbuilder.clone(),
+            rbuilder: self.rbuilder.clone(),
+            protected_ratio: self.protected_ratio,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<K, V, P, PB, R, RB> SlruBuilder<K, V, P, PB, R, RB> {
+    pub fn new(pbuilder: PB, rbuilder: RB) -> Self {
+        SlruBuilder {
+            pbuilder,
+            rbuilder,
+            protected_ratio: None,
+            unused: PhantomData,
+        }
+    }
+
+    /// Set the share of the total capacity given to the protected segment
+    /// of the [`Slru`](../../struct.Slru.html) container to build.
+    ///
+    /// See
+    /// [`Slru::with_protected_ratio()`](../../struct.Slru.html#method.with_protected_ratio).
+    pub fn with_protected_ratio(mut self, ratio: f32) -> Self {
+        self.protected_ratio = Some(ratio);
+        self
+    }
+}
+
+impl<K, V, P, PB, R, RB> Build<Slru<K, V, P, R>>
+    for SlruBuilder<K, V, P, PB, R, RB>
+where
+    P: BuildingBlock<K, V> + Resizable<K, V>,
+    R: BuildingBlock<K, V> + Resizable<K, V>,
+    PB: Build<P>,
+    RB: Build<R>,
+{
+    fn build(self) -> Slru<K, V, P, R> {
+        let slru = Slru::new(self.pbuilder.build(), self.rbuilder.build());
+        match self.protected_ratio {
+            None => slru,
+            Some(ratio) => slru.with_protected_ratio(ratio),
+        }
+    }
+}
+
+/// Segment a container into a probationary and a protected segment.
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{Build, Builder, SlruBuild};
+///
+/// let mut container = Builder::array(10000)
+///     .slru(Builder::array(10000))
+///     .build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub trait SlruBuild<P> {
+    /// Use the built container as the probationary segment of a
+    /// [`Slru`](../../struct.Slru.html) container and `rbuilder` as
+    /// the builder of its protected segment.
+    fn slru<K, V, R, RB: Build<R>>(
+        self,
+        rbuilder: RB,
+    ) -> SlruBuilder<K, V, P, Self, R, RB>
+    where
+        Self: Sized,
+    {
+        SlruBuilder::new(self, rbuilder)
+    }
+}
+
+impl<P, B: Build<P>> SlruBuild<P> for B {}
@@ -0,0 +1,221 @@
+use super::Slru;
+use crate::BuildingBlock;
+
+impl<K, V, P, R> BuildingBlock<K, V> for Slru<K, V, P, R>
+where
+    K: Eq,
+    P: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Get the maximum "size" that elements in the container can fit.
+    ///
+    /// This is the sum of the capacities of the two segments.
+    fn capacity(&self) -> usize {
+        self.probation.capacity() + self.protected.capacity()
+    }
+
+    /// Get the size currently occupied by elements in this [`BuildingBlock`].
+    ///
+    /// This is the sum of the sizes held in the two segments, plus one per
+    /// element that fit in neither segment after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call.
+    fn size(&self) -> usize {
+        self.probation.size() + self.protected.size() + self.overflow.len()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.probation.contains(key)
+            || self.protected.contains(key)
+            || self.overflow.iter().any(|(k, _)| k == key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.probation.contains_if(key, predicate)
+            || self.protected.contains_if(key, predicate)
+            || self.overflow.iter().any(|(k, v)| k == key && predicate(v))
+    }
+
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        match self.probation.take(key) {
+            Some(x) => Some(x),
+            None => self.protected.take(key).or_else(|| {
+                let i =
+                    self.overflow.iter().position(|(k, _)| k == key)?;
+                Some(self.overflow.swap_remove(i))
+            }),
+        }
+    }
+
+    /// Free up to `size` space from the container.
+    ///
+    /// Elements that fit in neither segment after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are popped
+    /// first. Then, elements are popped from the probationary segment. If
+    /// less than `size` space was freed, the remaining size is popped from
+    /// the protected segment.
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        let n = size.min(self.overflow.len());
+        let mut v: Vec<(K, V)> = self.overflow.drain(..n).collect();
+        let size = size - n;
+        if size == 0 {
+            return v;
+        }
+
+        let old_size = self.probation.size();
+        v.append(&mut self.probation.pop(size));
+        let evicted_size = old_size - self.probation.size();
+
+        if evicted_size < size {
+            v.append(&mut self.protected.pop(size - evicted_size));
+        }
+        v
+    }
+
+    /// Insert key/value pairs in the probationary segment.
+    ///
+    /// Elements of the protected segment with a key matching one of the
+    /// inserted keys are taken out and returned first. Then, the elements
+    /// are pushed in the probationary segment and the elements it pops out
+    /// are returned.
+    ///
+    /// Elements that fit in neither segment after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are also
+    /// returned.
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        let mut out = std::mem::take(&mut self.overflow);
+        for (k, _) in elements.iter() {
+            out.extend(self.protected.take(k));
+        }
+        out.append(&mut self.probation.push(elements));
+        out
+    }
+
+    type FlushIterator = std::iter::Chain<
+        std::vec::IntoIter<(K, V)>,
+        std::iter::Chain<P::FlushIterator, R::FlushIterator>,
+    >;
+
+    /// Empty the probationary segment then the protected segment.
+    ///
+    /// Elements that fit in neither segment after a
+    /// [`get()`](trait.Get.html#tymethod.get) or
+    /// [`get_mut()`](trait.GetMut.html#tymethod.get_mut) call are
+    /// returned first.
+    fn flush(&mut self) -> Self::FlushIterator {
+        std::mem::take(&mut self.overflow)
+            .into_iter()
+            .chain(self.probation.flush().chain(self.protected.flush()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slru;
+    use crate::tests::test_building_block;
+    use crate::{Array, BuildingBlock, Get};
+
+    #[test]
+    fn building_block() {
+        test_building_block(Slru::new(Array::new(0), Array::new(0)), true);
+        test_building_block(
+            Slru::new(Array::new(0), Array::new(10)),
+            true,
+        );
+        test_building_block(
+            Slru::new(Array::new(10), Array::new(0)),
+            true,
+        );
+        test_building_block(
+            Slru::new(Array::new(10), Array::new(100)),
+            true,
+        );
+    }
+
+    #[test]
+    fn scan_resistance() {
+        let mut c = Slru::new(Array::new(2), Array::new(2));
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+        assert_eq!(c.protected().size(), 2);
+
+        // New elements only evict elements from the probationary segment.
+        for i in 2u16..10u16 {
+            c.push(vec![(i, i as u32)]);
+        }
+        assert!(c.contains(&0u16));
+        assert!(c.contains(&1u16));
+        assert_eq!(c.probation().size(), 2);
+
+        // Promoting a third element demotes a protected element.
+        assert_eq!(*c.get(&9u16).unwrap(), 9u32);
+        assert_eq!(c.protected().size(), 2);
+        assert_eq!(c.probation().size(), 2);
+        assert_eq!(c.size(), 4);
+    }
+
+    #[test]
+    fn promote_overflow() {
+        let mut c = Slru::new(
+            Array::new(4).with_element_size(|(_, v): &(u16, usize)| *v),
+            Array::new(4).with_element_size(|(_, v): &(u16, usize)| *v),
+        );
+        assert!(c.push(vec![(10, 2)]).is_empty());
+        assert_eq!(*c.get(&10).unwrap(), 2);
+        assert!(c.push(vec![(11, 2)]).is_empty());
+        assert_eq!(*c.get(&11).unwrap(), 2);
+        assert!(c.push(vec![(1, 1), (2, 3)]).is_empty());
+
+        // The demoted element does not fit in the probationary segment
+        // and is returned by the next push.
+        assert_eq!(*c.get(&1).unwrap(), 1);
+        let out = c.push(vec![]);
+        assert_eq!(out.len(), 1);
+        assert!(!c.contains(&out[0].0));
+    }
+
+    #[test]
+    fn overflow_is_in_container() {
+        let overflowing = || {
+            let mut c = Slru::new(
+                Array::new(4)
+                    .with_element_size(|(_, v): &(u16, usize)| *v),
+                Array::new(4)
+                    .with_element_size(|(_, v): &(u16, usize)| *v),
+            );
+            c.push(vec![(10, 2)]);
+            assert_eq!(*c.get(&10).unwrap(), 2);
+            c.push(vec![(11, 2)]);
+            assert_eq!(*c.get(&11).unwrap(), 2);
+            c.push(vec![(1, 1), (2, 3)]);
+            assert_eq!(*c.get(&1).unwrap(), 1);
+            assert_eq!(c.overflow.len(), 1);
+            c
+        };
+
+        // The demoted element that does not fit in the probationary
+        // segment is still in the container.
+        let mut c = overflowing();
+        let (key, value) = c.overflow[0];
+        let size = c.probation().size() + c.protected().size();
+        assert_eq!(c.size(), size + 1);
+        assert!(c.contains(&key));
+        assert!(c.contains_if(&key, &|v| *v == value));
+        assert_eq!(c.take(&key), Some((key, value)));
+        assert!(!c.contains(&key));
+        assert_eq!(c.size(), size);
+
+        // Overflowing elements are popped first.
+        let mut c = overflowing();
+        let (key, value) = c.overflow[0];
+        assert_eq!(c.pop(1), vec![(key, value)]);
+        assert!(c.overflow.is_empty());
+    }
+}
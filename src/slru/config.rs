@@ -0,0 +1,272 @@
+use crate::builder::SlruBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
+    IntoConfig,
+};
+use crate::objsafe::DynBuildingBlock;
+use crate::{BuildingBlock, Resizable, Slru};
+use serde::{Deserialize, Serialize};
+
+/// Configuration format for [`Slru`](../struct.Slru.html)
+/// containers.
+///
+/// This configuration format is composed of an `id` field where the
+/// `id` value must be "SlruConfig"; and of two
+/// [`toml tables`](https://toml.io/en/v1.0.0#table) `probation` and
+/// `protected` representing respectively the segment where new elements are
+/// inserted and the segment where elements accessed again are promoted.
+///
+/// The optional `protected_ratio` field sets the share of the total
+/// capacity of the two segments given to the protected segment. It must be
+/// between 0 and 1 and come before the `probation` and `protected` tables.
+/// It is only valid if both segments are resizable. Without it, each
+/// segment keeps its own configured capacity.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='SlruConfig'
+/// protected_ratio=0.8
+/// [probation]
+/// id='ArrayConfig'
+/// capacity=10
+/// [protected]
+/// id='ArrayConfig'
+/// capacity=10
+/// ");
+/// let container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct SlruConfig {
+    #[allow(dead_code)]
+    id: String,
+    protected_ratio: Option<f32>,
+    probation: toml::Value,
+    protected: toml::Value,
+}
+
+impl<K, V, P, PB, R, RB> IntoConfig<SlruConfig>
+    for SlruBuilder<K, V, P, PB, R, RB>
+where
+    PB: IntoConfig<P>,
+    RB: IntoConfig<R>,
+    P: ConfigInstance,
+    R: ConfigInstance,
+{
+    fn as_config(&self) -> SlruConfig {
+        let probation_config: P = self.pbuilder.as_config();
+        let protected_config: R = self.rbuilder.as_config();
+        let probation_config_str = probation_config.to_toml_string();
+        let protected_config_str = protected_config.to_toml_string();
+        let probation =
+            toml::de::from_str(probation_config_str.as_ref()).unwrap();
+        let protected =
+            toml::de::from_str(protected_config_str.as_ref()).unwrap();
+
+        SlruConfig {
+            id: String::from(SlruConfig::id()),
+            protected_ratio: self.protected_ratio,
+            probation,
+            protected,
+        }
+    }
+}
+
+impl SlruConfig {
+    fn build_with<K, V, P, R>(
+        probation: P,
+        protected: R,
+        protected_ratio: Option<f32>,
+    ) -> Slru<K, V, P, R>
+    where
+        P: BuildingBlock<K, V> + Resizable<K, V>,
+        R: BuildingBlock<K, V> + Resizable<K, V>,
+    {
+        let slru = Slru::new(probation, protected);
+        match protected_ratio {
+            None => slru,
+            Some(ratio) => slru.with_protected_ratio(ratio),
+        }
+    }
+}
+
+impl ConfigInstance for SlruConfig {
+    fn id() -> &'static str {
+        "SlruConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        let cfg: SlruConfig = match toml::from_str(&toml) {
+            Err(e) => return Err(ConfigError::TomlFormatError(e)),
+            Ok(cfg) => cfg,
+        };
+        if let Some(ratio) = cfg.protected_ratio {
+            if !(0.0..=1.0).contains(&ratio) {
+                return Err(ConfigError::ConfigFormatError(format!(
+                    "Invalid SlruConfig protected_ratio: {}. Must be between 0 and 1.",
+                    ratio
+                )));
+            }
+        }
+        match (
+            GenericConfig::from_toml(&cfg.probation),
+            GenericConfig::from_toml(&cfg.protected),
+        ) {
+            (Ok(_), Ok(_)) => {}
+            (Ok(_), Err(e)) => return Err(e),
+            (Err(e), _) => return Err(e),
+        }
+        if cfg.protected_ratio.is_some() && !cfg.is_resizable() {
+            return Err(ConfigError::ConfigFormatError(String::from(
+                "Invalid SlruConfig: protected_ratio requires resizable probation and protected containers.",
+            )));
+        }
+        Ok(cfg)
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        let is_resizable = self.is_resizable();
+        let probation =
+            GenericConfig::from_toml(&self.probation).unwrap().build();
+        let protected =
+            GenericConfig::from_toml(&self.protected).unwrap().build();
+        match (probation.into_get(), protected.into_get()) {
+            (Ok(probation), Ok(protected)) => {
+                DynBuildingBlock::new_resizable_get(
+                    Self::build_with(
+                        probation,
+                        protected,
+                        self.protected_ratio,
+                    ),
+                    false,
+                    is_resizable,
+                )
+            }
+            (probation, protected) => DynBuildingBlock::new_resizable(
+                Self::build_with(
+                    probation.map_or_else(|c| c, |c| c.into_inner()),
+                    protected.map_or_else(|c| c, |c| c.into_inner()),
+                    self.protected_ratio,
+                ),
+                false,
+                is_resizable,
+            ),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        GenericConfig::from_toml(&self.probation)
+            .unwrap()
+            .has_get_trait()
+            && GenericConfig::from_toml(&self.protected)
+                .unwrap()
+                .has_get_trait()
+    }
+
+    fn is_resizable(&self) -> bool {
+        GenericConfig::from_toml(&self.probation)
+            .unwrap()
+            .is_resizable()
+            && GenericConfig::from_toml(&self.protected)
+                .unwrap()
+                .is_resizable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SlruConfig;
+    use crate::builder::{ArrayBuilder, SlruBuilder};
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_slru_config() {
+        let array_capacity = 10;
+        let config_str = format!(
+            "id='SlruConfig'
+protected_ratio=0.5
+[probation]
+id='ArrayConfig'
+capacity={}
+[protected]
+id='ArrayConfig'
+capacity={}
+",
+            array_capacity, array_capacity
+        );
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = SlruConfig::from_toml(&value).unwrap();
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), array_capacity * 2);
+    }
+
+    #[test]
+    fn test_invalid_slru_config() {
+        let config_str = "id='SlruConfig'
+protected_ratio=2.0
+[probation]
+id='ArrayConfig'
+capacity=10
+[protected]
+id='ArrayConfig'
+capacity=10
+"
+        .to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            SlruConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_non_resizable_slru_config() {
+        let segments = "[probation]
+id='HierarchyConfig'
+[[probation.levels]]
+id='ArrayConfig'
+capacity=10
+[protected]
+id='ArrayConfig'
+capacity=10
+";
+        let value: toml::Value = toml::from_str(
+            format!("id='SlruConfig'\n{}", segments).as_str(),
+        )
+        .unwrap();
+        assert!(SlruConfig::from_toml(&value).is_ok());
+
+        // Segments cannot be resized to the protected ratio.
+        let value: toml::Value = toml::from_str(
+            format!("id='SlruConfig'\nprotected_ratio=0.5\n{}", segments)
+                .as_str(),
+        )
+        .unwrap();
+        assert!(matches!(
+            SlruConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder = SlruBuilder::<u64, u64, _, _, _, _>::new(
+            ArrayBuilder::<(u64, u64)>::new(2),
+            ArrayBuilder::<(u64, u64)>::new(2),
+        );
+        test_config_builder(builder.clone());
+        test_config_builder(builder.with_protected_ratio(0.5));
+    }
+}
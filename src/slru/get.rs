@@ -0,0 +1,111 @@
+use super::Slru;
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, Get, GetMut};
+use std::ops::{Deref, DerefMut};
+
+/// Cell holding a value inside one of the segments of an
+/// [`Slru`](struct.Slru.html) container.
+///
+/// Values are returned from the protected segment unless they could not
+/// be promoted, for instance when the protected segment has no capacity.
+pub enum SlruCell<P, R> {
+    Probation(P),
+    Protected(R),
+}
+
+impl<V, P, R> Deref for SlruCell<P, R>
+where
+    P: Deref<Target = V>,
+    R: Deref<Target = V>,
+{
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        match self {
+            SlruCell::Probation(v) => v.deref(),
+            SlruCell::Protected(v) => v.deref(),
+        }
+    }
+}
+
+impl<V, P, R> DerefMut for SlruCell<P, R>
+where
+    P: DerefMut<Target = V>,
+    R: DerefMut<Target = V>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            SlruCell::Probation(v) => v.deref_mut(),
+            SlruCell::Protected(v) => v.deref_mut(),
+        }
+    }
+}
+
+impl<K, V, P, R> Get<K, V> for Slru<K, V, P, R>
+where
+    P: Get<K, V> + BuildingBlock<K, V>,
+    R: Get<K, V> + BuildingBlock<K, V>,
+{
+    type Target = SlruCell<P::Target, R::Target>;
+
+    /// Get a read-only smart pointer to a value inside the container.
+    ///
+    /// If the element is found in the probationary segment, it is promoted
+    /// to the protected segment first.
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        if self.probation.contains(key) {
+            self.promote(key);
+        }
+        if self.protected.contains(key) {
+            return self.protected.get(key).map(|v| {
+                LifeTimeGuard::new(SlruCell::Protected(v.unwrap()))
+            });
+        }
+        self.probation
+            .get(key)
+            .map(|v| LifeTimeGuard::new(SlruCell::Probation(v.unwrap())))
+    }
+}
+
+impl<K, V, P, R> GetMut<K, V> for Slru<K, V, P, R>
+where
+    P: GetMut<K, V> + BuildingBlock<K, V>,
+    R: GetMut<K, V> + BuildingBlock<K, V>,
+{
+    type Target = SlruCell<P::Target, R::Target>;
+
+    /// Get a smart pointer to a mutable value inside the container.
+    ///
+    /// If the element is found in the probationary segment, it is promoted
+    /// to the protected segment first.
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        if self.probation.contains(key) {
+            self.promote(key);
+        }
+        if self.protected.contains(key) {
+            return self.protected.get_mut(key).map(|v| {
+                LifeTimeGuard::new(SlruCell::Protected(v.unwrap()))
+            });
+        }
+        self.probation
+            .get_mut(key)
+            .map(|v| LifeTimeGuard::new(SlruCell::Probation(v.unwrap())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slru;
+    use crate::tests::{test_get, test_get_mut};
+    use crate::Array;
+
+    #[test]
+    fn get() {
+        test_get(Slru::new(Array::new(10), Array::new(0)));
+        test_get(Slru::new(Array::new(10), Array::new(100)));
+        test_get_mut(Slru::new(Array::new(10), Array::new(0)));
+        test_get_mut(Slru::new(Array::new(10), Array::new(100)));
+    }
+}
@@ -0,0 +1,9 @@
+#[allow(clippy::module_inception)]
+mod slru;
+pub use slru::Slru;
+pub(crate) mod builder;
+mod building_block;
+#[cfg(feature = "config")]
+pub(crate) mod config;
+mod get;
+mod resizable;
@@ -0,0 +1,123 @@
+use super::Slru;
+use crate::utils::size::split_capacity;
+use crate::{BuildingBlock, Error, Resizable};
+
+impl<K, V, P, R> Resizable<K, V> for Slru<K, V, P, R>
+where
+    K: Eq,
+    P: BuildingBlock<K, V> + Resizable<K, V>,
+    R: BuildingBlock<K, V> + Resizable<K, V>,
+{
+    /// Change the [`Slru`] container capacity.
+    ///
+    /// The new capacity is split between the probationary and the protected
+    /// segments proportionally to their current capacities. If the
+    /// probationary segment content does not fit its share, the unused
+    /// room of the protected segment is lent to it. The protected
+    /// segment is resized first and its victims are demoted to the
+    /// probationary segment once it is resized. Elements that do not fit
+    /// in the probationary segment are returned.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        let split = self.split(new_capacity);
+        let demoted = self.protected.resize(split[1]);
+        let mut out = self.probation.resize(split[0]);
+        out.append(&mut self.probation.push(demoted));
+        out
+    }
+
+    /// Change the [`Slru`] container capacity if both segments can be
+    /// resized. Otherwise, the container is left unchanged and an error is
+    /// returned.
+    ///
+    /// The segments are resized with their
+    /// [`try_resize()`](trait.Resizable.html#method.try_resize) method.
+    /// If the protected segment is resized but the probationary segment
+    /// cannot be, the protected segment is restored to its former
+    /// capacity and its victims are pushed back in it.
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let split = self.split(new_capacity);
+        let protected_capacity = self.protected.capacity();
+        let demoted = self.protected.try_resize(split[1])?;
+        let mut out = match self.probation.try_resize(split[0]) {
+            Ok(out) => out,
+            Err(e) => {
+                self.protected.resize(protected_capacity);
+                self.overflow.append(&mut self.protected.push(demoted));
+                return Err(e);
+            }
+        };
+        out.append(&mut self.probation.push(demoted));
+        Ok(out)
+    }
+}
+
+impl<K, V, P, R> Slru<K, V, P, R>
+where
+    P: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Split `new_capacity` between the probationary and the protected
+    /// segments proportionally to their current capacities. If the
+    /// probationary segment content does not fit its share, the unused
+    /// room of the protected segment is lent to it.
+    fn split(&self, new_capacity: usize) -> Vec<usize> {
+        let mut split = split_capacity(
+            &[self.probation.capacity(), self.protected.capacity()],
+            new_capacity,
+        );
+        let probation_size = self.probation.size();
+        if probation_size > split[0] {
+            let lend = std::cmp::min(
+                probation_size - split[0],
+                split[1].saturating_sub(self.protected.size()),
+            );
+            split[0] += lend;
+            split[1] -= lend;
+        }
+        split
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slru;
+    use crate::tests::test_resizable;
+    use crate::{
+        Array, BuildingBlock, DynBuildingBlock, Error, Resizable,
+        Sequential,
+    };
+
+    #[test]
+    fn resizable() {
+        test_resizable(Slru::new(Array::new(0), Array::new(0)));
+        test_resizable(Slru::new(Array::new(0), Array::new(10)));
+        test_resizable(Slru::new(Array::new(10), Array::new(0)));
+        test_resizable(Slru::new(Array::new(10), Array::new(100)));
+    }
+
+    #[test]
+    fn protected_ratio() {
+        let c = Slru::new(Array::<(u16, u32)>::new(10), Array::new(0))
+            .with_protected_ratio(0.8);
+        assert_eq!(c.probation().capacity(), 2);
+        assert_eq!(c.protected().capacity(), 8);
+        assert_eq!(c.capacity(), 10);
+    }
+
+    #[test]
+    fn try_resize_not_resizable() {
+        let probation =
+            DynBuildingBlock::from(Sequential::new(
+                Array::<(u16, u32)>::new(4),
+            ));
+        let mut c = Slru::new(probation, Array::new(4));
+        c.push(vec![(0u16, 0u32)]);
+        assert!(matches!(c.try_resize(4), Err(Error::NotResizable)));
+        assert_eq!(c.probation().capacity(), 4);
+        assert_eq!(c.protected().capacity(), 4);
+        assert!(c.contains(&0u16));
+    }
+}
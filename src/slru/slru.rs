@@ -0,0 +1,157 @@
+use crate::{BuildingBlock, Resizable};
+
+/// Segmented Least Recently Used (SLRU) `BuildingBlock`.
+///
+/// This building block is composed of two containers: a probationary
+/// segment and a protected segment. Newly inserted elements enter the
+/// probationary segment. When an element of the probationary segment is
+/// accessed with the [`Get`](trait.Get.html) or
+/// [`GetMut`](trait.GetMut.html) traits, i.e it is referenced a second
+/// time, it is promoted to the protected segment. Elements that do not
+/// fit in the protected segment anymore are demoted back to the
+/// probationary segment. Evictions happen in the probationary segment
+/// first. As a result, elements that are referenced only once, such as
+/// the elements of a one-time scan, never evict elements from the
+/// protected segment.
+///
+/// The order of evictions inside each segment is decided by the segment
+/// containers. For instance, segments made of
+/// [`Array`](struct.Array.html) containers decorated with the
+/// [`Lru`](utils/decorator/struct.Lru.html) policy implement the classic
+/// SLRU policy.
+///
+/// The share of the total capacity given to the protected segment can be
+/// set with
+/// [`with_protected_ratio()`](struct.Slru.html#method.with_protected_ratio)
+/// if both segments are [`Resizable`](trait.Resizable.html).
+///
+/// [`Slru`] can also be built from a
+/// [builder pattern](builder/trait.SlruBuild.html) and a
+/// [configuration](config/struct.SlruConfig.html).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Get, Slru};
+///
+/// let mut c = Slru::new(Array::new(2), Array::new(2));
+/// c.push(vec![("first", 1), ("second", 2)]);
+///
+/// // Accessing "first" promotes it to the protected segment.
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+/// assert!(c.protected().contains(&"first"));
+///
+/// // A scan of new elements only evicts elements of the probationary
+/// // segment.
+/// c.push(vec![("third", 3), ("fourth", 4), ("fifth", 5)]);
+/// assert!(c.contains(&"first"));
+/// ```
+pub struct Slru<K, V, P, R> {
+    pub(super) probation: P,
+    pub(super) protected: R,
+    // Elements that fit in neither segment after a `get()` or `get_mut()`
+    // call.
+    pub(super) overflow: Vec<(K, V)>,
+}
+
+impl<K, V, P, R> Slru<K, V, P, R> {
+    /// Create a new [`Slru`] container from a `probation` segment and a
+    /// `protected` segment.
+    pub fn new(probation: P, protected: R) -> Self {
+        Slru {
+            probation,
+            protected,
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Get shared access to the probationary segment.
+    pub fn probation(&self) -> &P {
+        &self.probation
+    }
+
+    /// Get exclusive access to the probationary segment.
+    pub fn probation_mut(&mut self) -> &mut P {
+        &mut self.probation
+    }
+
+    /// Get shared access to the protected segment.
+    pub fn protected(&self) -> &R {
+        &self.protected
+    }
+
+    /// Get exclusive access to the protected segment.
+    pub fn protected_mut(&mut self) -> &mut R {
+        &mut self.protected
+    }
+
+    /// Resize the segments such that the protected segment gets `ratio` of
+    /// the container total capacity and the probationary segment gets the
+    /// rest.
+    ///
+    /// The `ratio` must be between 0 and 1 and the container must be
+    /// empty. Both segments are resized with their
+    /// [`resize()`](trait.Resizable.html#tymethod.resize) method which
+    /// may panic if a segment cannot be resized, e.g a
+    /// [`DynBuildingBlock`](struct.DynBuildingBlock.html) that is not
+    /// resizable.
+    pub fn with_protected_ratio(mut self, ratio: f32) -> Self
+    where
+        P: BuildingBlock<K, V> + Resizable<K, V>,
+        R: BuildingBlock<K, V> + Resizable<K, V>,
+    {
+        if !(0.0..=1.0).contains(&ratio) {
+            panic!("Slru protected ratio must be between 0 and 1.");
+        }
+        if self.probation.size() + self.protected.size() > 0 {
+            panic!("It is not allowed to set a non empty Slru container protected ratio.")
+        }
+        let capacity =
+            self.probation.capacity() + self.protected.capacity();
+        let protected =
+            ((capacity as f64) * (ratio as f64)).round() as usize;
+        self.protected.resize(protected);
+        self.probation.resize(capacity - protected);
+        self
+    }
+}
+
+impl<K, V, P, R> Slru<K, V, P, R>
+where
+    P: BuildingBlock<K, V>,
+    R: BuildingBlock<K, V>,
+{
+    /// Move `key` from the probationary segment to the protected segment.
+    ///
+    /// Elements popped out of the protected segment to make room for the
+    /// promoted element are pushed back to the probationary segment. If
+    /// the probationary segment pops elements in turn, these elements are
+    /// kept aside and returned by the next call to
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push).
+    pub(super) fn promote(&mut self, key: &K) {
+        let x = match self.probation.take(key) {
+            None => return,
+            Some(x) => x,
+        };
+
+        let demoted = self.protected.push(vec![x]);
+        if demoted.is_empty() {
+            return;
+        }
+        let mut popped = self.probation.push(demoted);
+        self.overflow.append(&mut popped);
+    }
+}
+
+impl<'a, K, V, P, R> From<Slru<K, V, P, R>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Eq,
+    V: 'a,
+    P: 'a + BuildingBlock<K, V> + Resizable<K, V>,
+    R: 'a + BuildingBlock<K, V> + Resizable<K, V>,
+{
+    fn from(slru: Slru<K, V, P, R>) -> Self {
+        crate::DynBuildingBlock::new_resizable(slru, false, true)
+    }
+}
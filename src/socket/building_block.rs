@@ -37,6 +37,30 @@ where
         }
     }
 
+    /// The remote container cannot evaluate `predicate`. Instead, the
+    /// matching key/value pair is taken out of the remote container,
+    /// `predicate` is evaluated locally and the pair is pushed back. The
+    /// remote container may thus see the pair as a newly inserted element.
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        let (k, v) = match process_request(
+            &self.stream,
+            Request::<K, V>::Take(key.clone()),
+        ) {
+            Response::Take(Some(kv)) => kv,
+            Response::Take(None) => return false,
+            _ => mismatch_panic(),
+        };
+        let out = predicate(&v);
+        match process_request(&self.stream, Request::Push(vec![(k, v)])) {
+            Response::Push(_) => out,
+            _ => mismatch_panic(),
+        }
+    }
+
     fn pop(&mut self, n: usize) -> Vec<(K, V)> {
         match process_request(&self.stream, Request::<K, V>::Pop(n)) {
             Response::Pop(ret) => ret,
@@ -84,13 +108,13 @@ where
 }
 
 #[cfg(test)]
-mod tests {
+pub(super) mod tests {
     use crate::tests::{test_building_block, TestKey, TestValue};
     use crate::utils::socket::{ServerThreadBuilder, ServerThreadHandle};
     use crate::{Array, Sequential, SocketClient};
     use std::time::Duration;
 
-    fn make_server_client(
+    pub(in crate::socket) fn make_server_client(
         capacity: usize,
         address: &str,
     ) -> (
@@ -1,3 +1,4 @@
+use super::error::SocketError;
 use super::message::{Message, Request, Response};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
@@ -5,27 +6,52 @@ use std::{
     net::{TcpStream, ToSocketAddrs},
 };
 
-/// Make send a request to the connected [`SocketServer`] and return
-/// the associated response.
-pub(super) fn process_request<K, V>(
+/// Send a request to the connected [`SocketServer`] and return
+/// the associated response or the error that prevented to obtain it.
+pub(super) fn try_process_request<K, V>(
     stream: &TcpStream,
     request: Request<K, V>,
-) -> Response<K, V>
+) -> Result<Response<K, V>, SocketError>
 where
     K: DeserializeOwned + Serialize,
     V: DeserializeOwned + Serialize,
 {
-    let mut stream =
-        stream.try_clone().expect("IO Error cloning TcpStream");
+    let mut stream = match stream.try_clone() {
+        Ok(s) => s,
+        Err(e) => return Err(SocketError::Stream(e)),
+    };
     if let Err(e) = request.send(&mut stream) {
-        panic!("SocketClient failed to send request to SocketServer with error: {}", e);
+        return Err(SocketError::Send(e));
     };
 
     match Response::<K, V>::receive(&mut stream) {
+        Ok(response) => Ok(response),
+        Err(e) => Err(SocketError::Receive(e)),
+    }
+}
+
+/// Make send a request to the connected [`SocketServer`] and return
+/// the associated response.
+pub(super) fn process_request<K, V>(
+    stream: &TcpStream,
+    request: Request<K, V>,
+) -> Response<K, V>
+where
+    K: DeserializeOwned + Serialize,
+    V: DeserializeOwned + Serialize,
+{
+    match try_process_request(stream, request) {
         Ok(response) => response,
-        Err(e) => {
+        Err(SocketError::Stream(_)) => {
+            panic!("IO Error cloning TcpStream")
+        }
+        Err(SocketError::Send(e)) => {
+            panic!("SocketClient failed to send request to SocketServer with error: {}", e);
+        }
+        Err(SocketError::Receive(e)) => {
             panic!("SocketClient failed to receive request from SocketServer with error: {}", e);
         }
+        Err(SocketError::Response) => unreachable!(),
     }
 }
 
@@ -222,14 +222,14 @@ mod tests {
     use super::{SocketClientConfig, SocketServerConfig};
     use crate::builder::{Build, SocketClientBuilder};
     use crate::config::tests::test_config_builder;
-    use crate::config::{ConfigError, ConfigInstance};
+    use crate::config::{ConfigBuilder, ConfigError, ConfigInstance};
     use crate::objsafe::DynBuildingBlock;
     use crate::socket::{
         ServerThreadBuilder, ServerThreadHandle, SocketClient,
     };
     use crate::tests::{TestKey, TestValue};
     use crate::Array;
-    use crate::BuildingBlock;
+    use crate::{BuildingBlock, Get};
 
     #[test]
     fn test_valid_client_config() {
@@ -255,6 +255,66 @@ address='{}'",
         server.stop_and_join().unwrap();
     }
 
+    #[test]
+    fn test_wrapped_client_config_is_not_resizable() {
+        let container = Array::<(TestKey, TestValue)>::new(10);
+        let address = "localhost:6296";
+        let server = ServerThreadBuilder::new(address, container)
+            .spawn()
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        let config_str = format!(
+            "
+id='ProfilerConfig'
+[container]
+id='SocketClientConfig'
+address='{}'",
+            address
+        );
+        let config = ConfigBuilder::from_string(&config_str).unwrap();
+        assert!(!config.is_resizable());
+        assert!(!config.has_get_trait());
+        let container: DynBuildingBlock<TestKey, TestValue> =
+            config.build();
+        assert!(!container.is_resizable());
+        assert!(container.into_get().is_err());
+        server.stop_and_join().unwrap();
+    }
+
+    #[test]
+    fn test_exclusive_client_config_has_get_trait() {
+        let container = Array::<(TestKey, TestValue)>::new(10);
+        let address = "localhost:6297";
+        let server = ServerThreadBuilder::new(address, container)
+            .spawn()
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        // Only the front container of an Exclusive container needs to
+        // be accessed in place.
+        let config_str = format!(
+            "
+id='ExclusiveConfig'
+[front]
+id='ArrayConfig'
+capacity=10
+[back]
+id='SocketClientConfig'
+address='{}'",
+            address
+        );
+        let config = ConfigBuilder::from_string(&config_str).unwrap();
+        assert!(config.has_get_trait());
+        let container: DynBuildingBlock<TestKey, TestValue> =
+            config.build();
+        let mut container = container.into_get().ok().unwrap();
+        assert!(container.push(vec![(1, 2)]).is_empty());
+        assert_eq!(*container.get(&1).unwrap(), 2);
+        drop(container);
+        server.stop_and_join().unwrap();
+    }
+
     #[test]
     fn test_invalid_client_config() {
         let address = "localhost:6292";
@@ -77,3 +77,47 @@ impl From<bincode::Error> for SocketServerError {
         }
     }
 }
+
+/// Error returned by a [`SocketClient`](../../struct.SocketClient.html)
+/// failing to process a request on its remote container.
+#[derive(Debug)]
+pub enum SocketError {
+    /// Failure to clone the client [`std::net::TcpStream`].
+    Stream(std::io::Error),
+    /// Failure to send a request to the remote container.
+    Send(bincode::Error),
+    /// Failure to receive a response from the remote container.
+    Receive(bincode::Error),
+    /// The response from the remote container is an error or does not
+    /// match the request.
+    Response,
+}
+
+impl std::fmt::Display for SocketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SocketError::Stream(e) => {
+                write!(f, "failed to clone the client stream: {}", e)
+            }
+            SocketError::Send(e) => {
+                write!(f, "failed to send the request: {}", e)
+            }
+            SocketError::Receive(e) => {
+                write!(f, "failed to receive the response: {}", e)
+            }
+            SocketError::Response => {
+                write!(f, "invalid response from the remote container")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SocketError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SocketError::Stream(e) => Some(e),
+            SocketError::Send(e) | SocketError::Receive(e) => Some(e),
+            SocketError::Response => None,
+        }
+    }
+}
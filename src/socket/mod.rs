@@ -1,6 +1,7 @@
 mod client;
 pub use client::SocketClient;
 mod building_block;
+mod try_building_block;
 
 mod server;
 mod server_thread;
@@ -11,4 +12,5 @@ pub mod builder;
 pub mod config;
 
 mod error;
+pub use error::SocketError;
 mod message;
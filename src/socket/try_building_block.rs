@@ -0,0 +1,113 @@
+use super::client::try_process_request;
+use super::error::SocketError;
+use super::message::{Request, Response};
+use super::SocketClient;
+use crate::{Error, TryBuildingBlock};
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V> TryBuildingBlock<K, V> for SocketClient<K, V>
+where
+    K: DeserializeOwned + Serialize + Clone,
+    V: DeserializeOwned + Serialize,
+{
+    fn try_capacity(&self) -> Result<usize, Error> {
+        match try_process_request(&self.stream, Request::<K, V>::Capacity)?
+        {
+            Response::Capacity(s) => Ok(s),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_size(&self) -> Result<usize, Error> {
+        match try_process_request(&self.stream, Request::<K, V>::Size)? {
+            Response::Size(s) => Ok(s),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        match try_process_request(
+            &self.stream,
+            Request::<K, V>::Contains(key.clone()),
+        )? {
+            Response::Contains(tf) => Ok(tf),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        match try_process_request(
+            &self.stream,
+            Request::<K, V>::Take(key.clone()),
+        )? {
+            Response::Take(ret) => Ok(ret),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        match try_process_request(
+            &self.stream,
+            Request::<K, V>::TakeMultiple(keys.to_vec()),
+        )? {
+            Response::TakeMultiple(ret) => Ok(ret),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_pop(&mut self, n: usize) -> Result<Vec<(K, V)>, Error> {
+        match try_process_request(&self.stream, Request::<K, V>::Pop(n))? {
+            Response::Pop(ret) => Ok(ret),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_push(
+        &mut self,
+        elements: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        match try_process_request(
+            &self.stream,
+            Request::<K, V>::Push(elements),
+        )? {
+            Response::Push(ret) => Ok(ret),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        match try_process_request(&self.stream, Request::<K, V>::Flush)? {
+            Response::Flush(ret) => Ok(ret),
+            _ => Err(Error::Socket(SocketError::Response)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::socket::building_block::tests::make_server_client;
+    use crate::tests::test_try_building_block;
+    use crate::{Error, TryBuildingBlock};
+
+    #[test]
+    fn test_server_client_try_building_block() {
+        let (client, server) = make_server_client(10, "localhost:6385");
+        test_try_building_block(client, true);
+        server.stop_and_join().unwrap();
+    }
+
+    #[test]
+    fn test_disconnected_client_try_building_block() {
+        let (mut client, server) =
+            make_server_client(10, "localhost:6386");
+        server.stop_and_join().unwrap();
+        assert!(matches!(
+            client.try_push(vec![(0, 0)]),
+            Err(Error::Socket(_))
+        ));
+        assert!(matches!(client.try_size(), Err(Error::Socket(_))));
+    }
+}
@@ -49,6 +49,25 @@ where
         })
     }
 
+    /// Check if container contains a matching key which value satisfies
+    /// `predicate`.
+    ///
+    /// This functions reads all internal streams one by one and stops as soon
+    /// as a matching key is found.
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.stream.iter().any(|s| {
+            if let Some(s) = s {
+                s.iter().any(|kv| &kv.0 == key && predicate(&kv.1))
+            } else {
+                false
+            }
+        })
+    }
+
     /// Take the matching key/value pair out of the container.
     ///
     /// This functions reads all internal streams one by one and stops as soon
@@ -94,22 +113,24 @@ where
     /// one by one to find matching keys. Everytime a matching key is found,
     /// it is removed from the input `keys`. The function stops when all the
     /// `keys` have been found and removed or when all the internal streams have
-    /// been read.
+    /// been read. Matching elements are then removed from the streams.
     fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
-        let mut ret = Vec::with_capacity(keys.len());
+        let mut indexes = Vec::with_capacity(keys.len());
         keys.sort();
-        for stream in self.stream.iter_mut().filter_map(|s| s.as_ref()) {
-            for (k, v) in stream.iter().map(|x| x.unwrap()) {
-                if let Ok(i) = keys.binary_search(&k) {
-                    ret.push((k, v));
-                    keys.remove(i);
-                    if keys.is_empty() {
-                        break;
+        'streams: for (i, s) in self.stream.iter().enumerate() {
+            if let Some(s) = s {
+                for (j, kv) in s.iter().enumerate() {
+                    if let Ok(k) = keys.binary_search(&kv.0) {
+                        indexes.push((i, j));
+                        keys.remove(k);
+                        if keys.is_empty() {
+                            break 'streams;
+                        }
                     }
                 }
             }
         }
-        ret
+        self.try_remove_indexes(indexes).unwrap()
     }
 
     /// Free up to `size` space from the container.
@@ -72,19 +72,29 @@ impl ConfigInstance for StreamConfig {
     ) -> DynBuildingBlock<'a, K, V> {
         #[cfg(feature = "tempfile")]
         {
-            DynBuildingBlock::new(
+            DynBuildingBlock::new_resizable_get(
                 Stream::new(TempFileStreamFactory {}, self.capacity),
                 false,
+                true,
             )
         }
         #[cfg(not(feature = "tempfile"))]
         {
-            DynBuildingBlock::new(
+            DynBuildingBlock::new_resizable_get(
                 Stream::new(VecStreamFactory {}, self.capacity),
                 false,
+                true,
             )
         }
     }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
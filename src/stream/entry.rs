@@ -0,0 +1,46 @@
+use super::{ByteStream, StreamMutCell};
+use crate::stream::StreamFactory;
+use crate::utils::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::utils::get::LifeTimeGuard;
+use crate::{BuildingBlock, GetEntry, GetMut};
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, F> GetEntry<K, V> for ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize + Ord,
+    V: DeserializeOwned + Serialize + Ord,
+    F: StreamFactory,
+{
+    type Target = StreamMutCell<K, V, F::Stream>;
+
+    /// Look up the key once. Occupied entries are written back to the
+    /// stream when they are dropped if they were modified. Vacant entries
+    /// are inserted with the [`ByteStream`]
+    /// [`push()`](trait.BuildingBlock.html#method.push) method.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target> {
+        match self.get_mut(&key).map(LifeTimeGuard::unwrap) {
+            Some(value) => Entry::Occupied(OccupiedEntry::new(
+                key,
+                LifeTimeGuard::new(value),
+            )),
+            None => Entry::Vacant(VacantEntry::new(
+                key,
+                Box::new(move |k, v| self.push(vec![(k, v)])),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteStream;
+    use crate::stream::VecStreamFactory;
+    use crate::tests::test_entry;
+
+    #[test]
+    fn entry() {
+        for i in [0usize, 10usize, 100usize] {
+            test_entry(ByteStream::new(VecStreamFactory {}, i));
+        }
+    }
+}
@@ -2,6 +2,8 @@
 // In-memory representation of a chunk.
 //----------------------------------------------------------------------------//
 
+/// Error encountered by containers storing their elements in a
+/// [`Stream`](trait.Stream.html).
 #[derive(Debug)]
 pub enum IOError {
     /// Error returned by call to `seek()` from `std::io::Seek` trait.
@@ -27,3 +29,38 @@ pub enum IOError {
 /// Result type of [`byoc::utils::io`](index.html)
 /// See [`IOError`](enum.IOError.html).
 pub type IOResult<T> = Result<T, IOError>;
+
+impl std::fmt::Display for IOError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IOError::Seek(e) => write!(f, "seek failed: {}", e),
+            IOError::Read(e) => write!(f, "read failed: {}", e),
+            IOError::Write(e) => write!(f, "write failed: {}", e),
+            #[cfg(feature = "compression")]
+            IOError::Encode(e) => write!(f, "encoding failed: {}", e),
+            #[cfg(feature = "compression")]
+            IOError::Decode(e) => write!(f, "decoding failed: {}", e),
+            IOError::Serialize(e) => {
+                write!(f, "serialization failed: {}", e)
+            }
+            IOError::Deserialize(e) => {
+                write!(f, "deserialization failed: {}", e)
+            }
+            IOError::InvalidSize => write!(f, "invalid size"),
+        }
+    }
+}
+
+impl std::error::Error for IOError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IOError::Seek(e) | IOError::Read(e) | IOError::Write(e) => {
+                Some(e)
+            }
+            #[cfg(feature = "compression")]
+            IOError::Encode(e) | IOError::Decode(e) => Some(e),
+            IOError::Serialize(e) | IOError::Deserialize(e) => Some(e),
+            IOError::InvalidSize => None,
+        }
+    }
+}
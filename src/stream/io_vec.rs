@@ -309,6 +309,48 @@ where
     }
 }
 
+/// Iterator over the items of an [`IOVec`] yielding an error instead of
+/// panicking when an item cannot be read.
+pub struct IOVecTryIter<O, T>
+where
+    O: DeserializeOwned,
+    T: Read + Seek,
+{
+    stream: BufReader<T>,
+    chunk_size: usize,
+    _o: PhantomData<O>,
+}
+
+impl<O, T> IOVecTryIter<O, T>
+where
+    O: DeserializeOwned + Serialize,
+    T: Read + Seek,
+{
+    pub fn new(stream: BufReader<T>, chunk_size: usize) -> Self {
+        IOVecTryIter {
+            stream,
+            chunk_size,
+            _o: PhantomData,
+        }
+    }
+}
+
+impl<O, T> Iterator for IOVecTryIter<O, T>
+where
+    O: DeserializeOwned,
+    T: Read + Seek,
+{
+    type Item = IOResult<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match IOChunk::from_stream(self.chunk_size, &mut self.stream) {
+            Err(e) => Some(Err(e)),
+            Ok(None) => None,
+            Ok(Some(mut c)) => Some(c.deserialize::<O>()),
+        }
+    }
+}
+
 pub struct IOVecIterMut<O, T>
 where
     O: DeserializeOwned + Serialize,
@@ -603,11 +645,10 @@ where
         }
 
         // Resize stream to one less chunk.
-        //On failure: we could rewrite things how they were and return an
-        // error instead.
-        self.stream
-            .resize(end - self.chunk_size as u64)
-            .expect("Failure to resize.");
+        //On failure: we could rewrite things how they were.
+        if let Err(e) = self.stream.resize(end - self.chunk_size as u64) {
+            return Err(IOError::Write(e));
+        }
 
         Ok(item)
     }
@@ -620,8 +661,21 @@ where
         IOVecIter::new(BufReader::new(stream), self.chunk_size)
     }
 
+    /// Build an iterator over items of this `IOVec` that yields an error
+    /// when an item cannot be read instead of panicking.
+    pub fn try_iter(&self) -> IOResult<IOVecTryIter<O, T>> {
+        let mut stream = self.stream.clone();
+        if let Err(e) = stream.seek(SeekFrom::Start(0)) {
+            return Err(IOError::Seek(e));
+        }
+        Ok(IOVecTryIter::new(BufReader::new(stream), self.chunk_size))
+    }
+
     #[allow(clippy::should_implement_trait)]
-    pub fn into_iter(self) -> IOIter<O, T> {
+    pub fn into_iter(mut self) -> IOIter<O, T> {
+        // The stream position may be shared with other iterators over
+        // this vector.
+        self.stream.seek(SeekFrom::Start(0)).unwrap();
         IOIter::new(BufReader::new(self.stream), self.chunk_size)
     }
 
@@ -0,0 +1,51 @@
+use super::ByteStream;
+use crate::stream::{IOStruct, IOVecIter, StreamFactory};
+use crate::Iter;
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, F> Iter<K, V> for ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize,
+    V: DeserializeOwned + Serialize,
+    F: StreamFactory,
+{
+    #[allow(clippy::type_complexity)]
+    type Iterator<'a>
+        = std::iter::Map<
+        std::iter::Flatten<
+            std::vec::IntoIter<IOVecIter<(K, V), F::Stream>>,
+        >,
+        fn(IOStruct<(K, V)>) -> (K, V),
+    >
+    where
+        Self: 'a;
+
+    /// Iterate over the elements of the container.
+    ///
+    /// Elements are read one by one from the container streams.
+    /// The iterator panics if an element cannot be read.
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.stream
+            .iter()
+            .flatten()
+            .map(|s| s.iter())
+            .collect::<Vec<IOVecIter<(K, V), F::Stream>>>()
+            .into_iter()
+            .flatten()
+            .map(|kv| kv.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteStream;
+    use crate::stream::VecStreamFactory;
+    use crate::tests::test_iter;
+
+    #[test]
+    fn iter() {
+        for i in [0usize, 10usize, 100usize] {
+            test_iter(ByteStream::new(VecStreamFactory {}, i));
+        }
+    }
+}
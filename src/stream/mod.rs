@@ -5,11 +5,17 @@ mod get;
 pub use get::{StreamCell, StreamMutCell};
 pub(crate) mod builder;
 mod building_block;
+mod entry;
 mod file_stream;
 mod io_vec;
-pub(crate) use io_vec::{IOIter, IOStructMut, IOVec};
+mod iter;
+mod range;
+mod resizable;
+mod try_building_block;
+pub(crate) use io_vec::{IOIter, IOStruct, IOStructMut, IOVec, IOVecIter};
 mod error;
-pub(crate) use error::{IOError, IOResult};
+pub use error::IOError;
+pub(crate) use error::IOResult;
 pub use file_stream::FileStream;
 #[cfg(feature = "tempfile")]
 pub use file_stream::TempFileStreamFactory;
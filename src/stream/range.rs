@@ -0,0 +1,78 @@
+use super::ByteStream;
+use crate::stream::StreamFactory;
+use crate::{Iter, RangeGet, RangeTake};
+use serde::{de::DeserializeOwned, Serialize};
+use std::ops::RangeBounds;
+
+impl<K, V, F> RangeGet<K, V> for ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize + Ord,
+    V: DeserializeOwned + Serialize,
+    F: StreamFactory,
+{
+    /// Check whether the container contains at least one key within
+    /// `range`.
+    ///
+    /// This method reads the container elements from its streams until a
+    /// key within `range` is found.
+    fn contains_range<R: RangeBounds<K>>(&self, range: R) -> bool {
+        self.iter().any(|(k, _)| range.contains(&k))
+    }
+
+    /// Get the keys of the container within `range` sorted in ascending
+    /// order.
+    ///
+    /// This method reads all the container elements from its streams and
+    /// sorts the matching keys.
+    fn keys_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<K> {
+        let mut keys: Vec<K> = self
+            .iter()
+            .filter_map(
+                |(k, _)| if range.contains(&k) { Some(k) } else { None },
+            )
+            .collect();
+        keys.sort();
+        keys
+    }
+}
+
+impl<K, V, F> RangeTake<K, V> for ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize + Ord,
+    V: DeserializeOwned + Serialize + Ord,
+    F: StreamFactory,
+{
+    /// Take all the key/value pairs with a key within `range` out of the
+    /// container.
+    ///
+    /// This method reads all the container elements from its streams and
+    /// removes the elements with a matching key.
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)> {
+        let mut indexes = Vec::new();
+        for (i, s) in self.stream.iter().enumerate() {
+            if let Some(s) = s {
+                for (j, kv) in s.iter().enumerate() {
+                    if range.contains(&kv.0) {
+                        indexes.push((i, j));
+                    }
+                }
+            }
+        }
+        self.try_remove_indexes(indexes)
+            .expect("Failed to remove elements from a stream.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteStream;
+    use crate::stream::VecStreamFactory;
+    use crate::tests::test_range;
+
+    #[test]
+    fn range() {
+        for i in [0usize, 10usize, 100usize] {
+            test_range(ByteStream::new(VecStreamFactory {}, i));
+        }
+    }
+}
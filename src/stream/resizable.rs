@@ -0,0 +1,39 @@
+use super::ByteStream;
+use crate::stream::StreamFactory;
+use crate::{BuildingBlock, Resizable};
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, F> Resizable<K, V> for ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize + Ord,
+    V: DeserializeOwned + Serialize + Ord,
+    F: StreamFactory,
+{
+    /// Change the [`ByteStream`] capacity.
+    ///
+    /// If the elements of the container do not fit in the new capacity,
+    /// the elements with the greatest values are popped out until they do.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.capacity = new_capacity;
+        let size = self.size();
+        if size > new_capacity {
+            self.pop(size - new_capacity)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteStream;
+    use crate::stream::VecStreamFactory;
+    use crate::tests::test_resizable;
+
+    #[test]
+    fn resizable() {
+        for i in [0usize, 10usize, 100usize] {
+            test_resizable(ByteStream::new(VecStreamFactory {}, i));
+        }
+    }
+}
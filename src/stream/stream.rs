@@ -123,6 +123,6 @@ where
     F: 'a + StreamFactory,
 {
     fn from(stream: ByteStream<(K, V), F>) -> Self {
-        crate::DynBuildingBlock::new(stream, true)
+        crate::DynBuildingBlock::new_resizable_get(stream, true, true)
     }
 }
@@ -0,0 +1,181 @@
+use super::ByteStream;
+use crate::stream::{IOError, IOVec, StreamFactory};
+use crate::utils::kmin::KMin;
+use crate::{Error, TryBuildingBlock};
+use serde::{de::DeserializeOwned, Serialize};
+
+impl<K, V, F> ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize + Ord,
+    V: DeserializeOwned + Serialize + Ord,
+    F: StreamFactory,
+{
+    /// Remove the elements at positions `(stream_index, element_index)`
+    /// from the container streams and return them.
+    pub(super) fn try_remove_indexes(
+        &mut self,
+        mut indexes: Vec<(usize, usize)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        indexes.sort_unstable();
+
+        let mut ret = Vec::with_capacity(indexes.len());
+        // Removes keys with swap remove from the end.
+        // Position of other matching elements is not impacted
+        // by the swap.
+        for (i, j) in indexes.into_iter().rev() {
+            if let Some(kv) =
+                self.stream[i].as_mut().unwrap().swap_remove(j)?
+            {
+                ret.push(kv);
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl<K, V, F> TryBuildingBlock<K, V> for ByteStream<(K, V), F>
+where
+    K: DeserializeOwned + Serialize + Ord,
+    V: DeserializeOwned + Serialize + Ord,
+    F: StreamFactory,
+{
+    fn try_size(&self) -> Result<usize, Error> {
+        let mut size = 0usize;
+        for s in self.stream.iter().flatten() {
+            size += s.size()?;
+        }
+        Ok(size)
+    }
+
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        for s in self.stream.iter().flatten() {
+            for kv in s.try_iter()? {
+                if &kv?.0 == key {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        for (i, s) in self.stream.iter().enumerate() {
+            if let Some(s) = s {
+                for (j, kv) in s.try_iter()?.enumerate() {
+                    if &kv?.0 == key {
+                        return Ok(self
+                            .try_remove_indexes(vec![(i, j)])?
+                            .pop());
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        keys.sort();
+
+        let mut indexes = Vec::with_capacity(keys.len());
+        let mut matched_keys = Vec::with_capacity(keys.len());
+        for (i, s) in self.stream.iter().enumerate() {
+            if let Some(s) = s {
+                for (j, kv) in s.try_iter()?.enumerate() {
+                    if let Ok(k) = keys.binary_search(&kv?.0) {
+                        indexes.push((i, j));
+                        matched_keys.push(k);
+                    }
+                }
+            }
+        }
+
+        let ret = self.try_remove_indexes(indexes)?;
+        matched_keys.sort_unstable();
+        matched_keys.dedup();
+        for k in matched_keys.into_iter().rev() {
+            keys.remove(k);
+        }
+        Ok(ret)
+    }
+
+    fn try_pop(&mut self, n: usize) -> Result<Vec<(K, V)>, Error> {
+        let mut set = KMin::new(n);
+
+        // Stream values and save only the top n ones with their index.
+        for (i, s) in self.stream.iter().enumerate() {
+            if let Some(s) = s.as_ref() {
+                let size = s.chunk_size;
+                for (j, kv) in s.try_iter()?.enumerate() {
+                    set.push((kv?.1, i, j), size);
+                }
+            }
+        }
+
+        self.try_remove_indexes(
+            set.into_iter().map(|((_, i, j), _)| (i, j)).collect(),
+        )
+    }
+
+    fn try_push(
+        &mut self,
+        values: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        let mut out = Vec::<(K, V)>::with_capacity(values.len());
+        let mut total_size = self.try_size()?;
+
+        for value in values.into_iter() {
+            let size = bincode::serialized_size(&value)
+                .map_err(IOError::Serialize)?
+                as usize;
+            let (i, chunk_size) = Self::chunk_size(size);
+            if chunk_size + total_size > self.capacity {
+                out.push(value);
+                continue;
+            }
+
+            if self.stream[i].is_none() {
+                let store = self.factory.create();
+                self.stream[i] = Some(IOVec::new(store, chunk_size));
+            }
+
+            self.stream[i].as_mut().unwrap().append(&mut vec![value])?;
+            total_size += chunk_size;
+        }
+        Ok(out)
+    }
+
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        // Read everything before emptying the container such that nothing
+        // is lost if a read fails.
+        let mut ret = Vec::new();
+        for s in self.stream.iter().flatten() {
+            for kv in s.try_iter()? {
+                ret.push(kv?);
+            }
+        }
+        for s in self.stream.iter_mut() {
+            *s = None;
+        }
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteStream;
+    use crate::stream::VecStreamFactory;
+    use crate::tests::test_try_building_block;
+
+    #[test]
+    fn try_building_block() {
+        for i in [0usize, 10usize, 100usize] {
+            test_try_building_block(
+                ByteStream::new(VecStreamFactory {}, i),
+                true,
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::tests::{TestElements, TestKey, TestValue};
+use crate::utils::entry::Entry;
+use crate::{BuildingBlock, GetEntry};
+
+pub fn test_entry<C>(mut c: C)
+where
+    C: BuildingBlock<TestKey, TestValue> + GetEntry<TestKey, TestValue>,
+{
+    let keys: Vec<TestKey> =
+        (0..c.capacity()).map(|i| i as TestKey).collect();
+
+    // Keys are not in the container: entries are vacant.
+    let mut popped = TestElements::new();
+    for key in keys.iter() {
+        match c.entry(*key) {
+            Entry::Occupied(_) => panic!("Unexpected occupied entry."),
+            Entry::Vacant(e) => {
+                assert_eq!(e.key(), key);
+                popped.append(&mut e.insert(*key as TestValue));
+            }
+        }
+    }
+
+    // Every key is either in the container or popped out of it.
+    for key in keys.iter() {
+        let is_popped = popped.iter().any(|(k, _)| k == key);
+        assert!(c.contains(key) != is_popped);
+    }
+
+    // Keys in the container are updated in place.
+    let keys: Vec<TestKey> =
+        keys.into_iter().filter(|k| c.contains(k)).collect();
+    let size = c.size();
+    for key in keys.iter() {
+        let entry = c.entry(*key).and_modify(|v| *v += 1);
+        assert!(matches!(entry, Entry::Occupied(_)));
+        assert!(entry.or_insert(0).is_empty());
+    }
+    assert_eq!(c.size(), size);
+
+    for key in keys.iter() {
+        assert_eq!(c.take(key), Some((*key, *key as TestValue + 1)));
+    }
+}
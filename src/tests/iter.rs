@@ -0,0 +1,29 @@
+use crate::tests::{insert, rand, TestElements, TestKey, TestValue};
+use crate::{BuildingBlock, Iter};
+
+pub fn test_iter<C>(mut c: C)
+where
+    C: BuildingBlock<TestKey, TestValue> + Iter<TestKey, TestValue>,
+{
+    // Empty container has nothing to iterate.
+    assert_eq!(c.iter().count(), 0);
+
+    let elements: TestElements = (0..c.capacity())
+        .map(|i| (i as TestKey, rand(0, 100) as TestValue))
+        .collect();
+    let (mut elements, _) = insert(&mut c, elements);
+    elements.sort();
+    let size = c.size();
+
+    // Iteration yields exactly the elements of the container.
+    let mut iterated: TestElements = c.iter().collect();
+    iterated.sort();
+    assert_eq!(iterated, elements);
+
+    // Iteration did not remove anything from the container.
+    assert_eq!(c.size(), size);
+    let mut flushed: TestElements = c.flush().collect();
+    flushed.sort();
+    assert_eq!(flushed, elements);
+    assert_eq!(c.iter().count(), 0);
+}
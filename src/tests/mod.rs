@@ -5,3 +5,13 @@ pub use building_block::{
 pub use building_block::{test_building_block, test_get, test_get_mut};
 mod concurrent;
 pub use concurrent::test_concurrent;
+mod resizable;
+pub use resizable::test_resizable;
+mod try_building_block;
+pub use try_building_block::test_try_building_block;
+mod iter;
+pub use iter::test_iter;
+mod range;
+pub use range::test_range;
+mod entry;
+pub use entry::test_entry;
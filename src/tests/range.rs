@@ -0,0 +1,58 @@
+use crate::tests::{insert, TestElements, TestKey, TestValue};
+use crate::{BuildingBlock, RangeGet, RangeTake};
+use std::ops::{Bound, RangeBounds};
+
+fn test_range_bounds<C, R>(
+    c: &mut C,
+    elements: &mut TestElements,
+    range: R,
+) where
+    C: BuildingBlock<TestKey, TestValue>
+        + RangeGet<TestKey, TestValue>
+        + RangeTake<TestKey, TestValue>,
+    R: RangeBounds<TestKey> + Clone,
+{
+    let mut expected: TestElements = elements
+        .iter()
+        .filter(|(k, _)| range.contains(k))
+        .copied()
+        .collect();
+    expected.sort();
+    let keys: Vec<TestKey> = expected.iter().map(|(k, _)| *k).collect();
+
+    assert_eq!(c.contains_range(range.clone()), !keys.is_empty());
+    assert_eq!(c.keys_in_range(range.clone()), keys);
+
+    let mut taken = c.take_range(range.clone());
+    taken.sort();
+    assert_eq!(taken, expected);
+    assert!(!c.contains_range(range));
+    elements.retain(|e| !expected.contains(e));
+    for (k, _) in elements.iter() {
+        assert!(c.contains(k));
+    }
+}
+
+pub fn test_range<C>(mut c: C)
+where
+    C: BuildingBlock<TestKey, TestValue>
+        + RangeGet<TestKey, TestValue>
+        + RangeTake<TestKey, TestValue>,
+{
+    let n = c.capacity() as TestKey;
+    let elements: TestElements =
+        (0..n).map(|i| (i, i as TestValue)).collect();
+    let (mut elements, _) = insert(&mut c, elements);
+
+    test_range_bounds(&mut c, &mut elements, n..);
+    test_range_bounds(&mut c, &mut elements, n / 4..n / 2);
+    test_range_bounds(&mut c, &mut elements, n / 2..=n / 2 + 2);
+    test_range_bounds(&mut c, &mut elements, ..n / 8);
+    test_range_bounds(
+        &mut c,
+        &mut elements,
+        (Bound::Excluded(n / 2), Bound::Unbounded),
+    );
+    test_range_bounds(&mut c, &mut elements, ..);
+    assert_eq!(c.size(), 0);
+}
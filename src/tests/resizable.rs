@@ -0,0 +1,50 @@
+use crate::tests::{insert, TestElements, TestKey, TestValue};
+use crate::{BuildingBlock, Resizable};
+
+fn test_resize<C>(c: &mut C, keys: &[TestKey], new_capacity: usize)
+where
+    C: BuildingBlock<TestKey, TestValue> + Resizable<TestKey, TestValue>,
+{
+    let size = c.size();
+    let victims = c.resize(new_capacity);
+
+    // The container takes the new capacity and fits in it.
+    assert_eq!(c.capacity(), new_capacity);
+    assert!(c.size() <= new_capacity);
+
+    // Growing or keeping a container large enough does not evict anything.
+    if new_capacity >= size {
+        assert!(victims.is_empty());
+    }
+
+    // Every key is either in the container or in the victims.
+    for key in keys {
+        let is_victim = victims.iter().any(|(k, _)| k == key);
+        assert!(c.contains(key) != is_victim);
+    }
+}
+
+pub fn test_resizable<C>(mut c: C)
+where
+    C: BuildingBlock<TestKey, TestValue> + Resizable<TestKey, TestValue>,
+{
+    let capacity = c.capacity();
+    let elements: TestElements = (0..capacity)
+        .map(|i| (i as TestKey, i as TestValue))
+        .collect();
+    let (elements, _) = insert(&mut c, elements);
+    let mut keys: Vec<TestKey> =
+        elements.iter().map(|(k, _)| *k).collect();
+
+    // Shrink the container.
+    test_resize(&mut c, &keys, capacity / 2);
+    keys.retain(|k| c.contains(k));
+
+    // Grow the container back.
+    test_resize(&mut c, &keys, capacity * 2);
+    assert_eq!(keys.iter().filter(|k| c.contains(k)).count(), keys.len());
+
+    // Empty the container.
+    test_resize(&mut c, &keys, 0);
+    assert_eq!(c.size(), 0);
+}
@@ -0,0 +1,57 @@
+use crate::tests::{test_building_block, TestElement, TestKey, TestValue};
+use crate::{BuildingBlock, TryBuildingBlock};
+
+/// A `BuildingBlock` implemented with the `TryBuildingBlock` methods of
+/// the container it wraps. Errors are unwrapped.
+struct TryWrapper<C> {
+    container: C,
+}
+
+impl<C> BuildingBlock<TestKey, TestValue> for TryWrapper<C>
+where
+    C: TryBuildingBlock<TestKey, TestValue>,
+{
+    fn capacity(&self) -> usize {
+        self.container.try_capacity().unwrap()
+    }
+    fn size(&self) -> usize {
+        self.container.try_size().unwrap()
+    }
+    fn contains(&self, key: &TestKey) -> bool {
+        self.container.try_contains(key).unwrap()
+    }
+    fn contains_if(
+        &self,
+        key: &TestKey,
+        predicate: &dyn Fn(&TestValue) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, predicate)
+    }
+    fn take(&mut self, key: &TestKey) -> Option<TestElement> {
+        self.container.try_take(key).unwrap()
+    }
+    fn take_multiple(
+        &mut self,
+        keys: &mut Vec<TestKey>,
+    ) -> Vec<TestElement> {
+        self.container.try_take_multiple(keys).unwrap()
+    }
+    fn pop(&mut self, size: usize) -> Vec<TestElement> {
+        self.container.try_pop(size).unwrap()
+    }
+    fn push(&mut self, values: Vec<TestElement>) -> Vec<TestElement> {
+        self.container.try_push(values).unwrap()
+    }
+
+    type FlushIterator = std::vec::IntoIter<TestElement>;
+    fn flush(&mut self) -> Self::FlushIterator {
+        self.container.try_flush().unwrap().into_iter()
+    }
+}
+
+pub fn test_try_building_block<C>(c: C, check_capacity: bool)
+where
+    C: TryBuildingBlock<TestKey, TestValue>,
+{
+    test_building_block(TryWrapper { container: c }, check_capacity);
+}
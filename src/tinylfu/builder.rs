@@ -0,0 +1,87 @@
+use super::TinyLfuAdmission;
+use crate::builder::Build;
+use std::marker::PhantomData;
+
+/// `TinyLfuAdmission` container builder.
+///
+/// This builder can be consumed later to wrap some containers into a
+/// [`TinyLfuAdmission`](../../struct.TinyLfuAdmission.html) container.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{Build, TinyLfuAdmissionBuild};
+/// use byoc::builder::{ArrayBuilder, TinyLfuAdmissionBuilder};
+///
+/// let array_builder = ArrayBuilder::new(2);
+/// let mut container = TinyLfuAdmissionBuilder::new(array_builder).build();
+/// container.push(vec![(1, 2)]);
+///
+/// // You can also chain calls:
+/// let mut container = ArrayBuilder::new(2)
+///     .with_tiny_lfu_admission()
+///     .build();
+/// container.push(vec![(1, 2)]);
+/// ```
+pub struct TinyLfuAdmissionBuilder<C, B> {
+    pub(super) builder: B,
+    unused: PhantomData<C>,
+}
+
+impl<C, B> TinyLfuAdmissionBuilder<C, B> {
+    pub fn new(builder: B) -> Self {
+        TinyLfuAdmissionBuilder {
+            builder,
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<C, B> Clone for TinyLfuAdmissionBuilder<C, B>
+where
+    B: Clone,
+{
+    fn clone(&self) -> Self {
+        TinyLfuAdmissionBuilder {
+            builder: self.builder.clone(),
+            unused: PhantomData,
+        }
+    }
+}
+
+impl<C, B> Build<TinyLfuAdmission<C>> for TinyLfuAdmissionBuilder<C, B>
+where
+    B: Build<C>,
+{
+    fn build(self) -> TinyLfuAdmission<C> {
+        TinyLfuAdmission::new(self.builder.build())
+    }
+}
+
+/// Filter the elements admitted in the container with their access
+/// frequency.
+///
+/// ```
+/// use byoc::BuildingBlock;
+/// use byoc::builder::{Build,Builder,TinyLfuAdmissionBuild};
+///
+/// let mut container = Builder::array(10000)
+///     .with_tiny_lfu_admission()
+///     .build();
+/// container.push(vec![(1,2)]);
+/// ```
+pub trait TinyLfuAdmissionBuild<C> {
+    /// Wrap a container builder into a
+    /// [TinyLFU admission](../../struct.TinyLfuAdmission.html) building
+    /// block rejecting new elements accessed less often than the elements
+    /// they would evict.
+    fn with_tiny_lfu_admission(self) -> TinyLfuAdmissionBuilder<C, Self>
+    where
+        Self: Sized,
+    {
+        TinyLfuAdmissionBuilder::new(self)
+    }
+}
+
+impl<C, B: Build<C>> TinyLfuAdmissionBuild<C> for B {}
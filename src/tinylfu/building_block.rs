@@ -0,0 +1,146 @@
+use super::{ReversiblePop, TinyLfuAdmission};
+use crate::BuildingBlock;
+use std::hash::Hash;
+
+impl<K, V, C> BuildingBlock<K, V> for TinyLfuAdmission<C>
+where
+    K: Hash + Clone,
+    C: BuildingBlock<K, V> + ReversiblePop,
+{
+    fn capacity(&self) -> usize {
+        self.container.capacity()
+    }
+
+    fn size(&self) -> usize {
+        self.container.size()
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.container.contains(key)
+    }
+
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.container.contains_if(key, predicate)
+    }
+
+    type FlushIterator = C::FlushIterator;
+    fn flush(&mut self) -> Self::FlushIterator {
+        self.container.flush()
+    }
+
+    fn take(&mut self, key: &K) -> Option<(K, V)> {
+        self.container.take(key)
+    }
+
+    fn take_multiple(&mut self, keys: &mut Vec<K>) -> Vec<(K, V)> {
+        self.container.take_multiple(keys)
+    }
+
+    fn pop(&mut self, size: usize) -> Vec<(K, V)> {
+        self.container.pop(size)
+    }
+
+    /// Insert key/value pairs in the container.
+    ///
+    /// Elements are inserted one after the other. Elements whose key is
+    /// already in the container are pushed in the wrapped container.
+    /// Otherwise, if the container is full, a victim is popped out of the
+    /// wrapped container and the element is pushed in its place. The
+    /// element is kept only if its key is estimated to be accessed more
+    /// often than the keys of the victim and of the elements evicted by
+    /// the wrapped container to make room for it. Otherwise, the element
+    /// is taken back out and the victims are pushed back in the wrapped
+    /// container. The victims and the rejected elements are returned.
+    fn push(&mut self, elements: Vec<(K, V)>) -> Vec<(K, V)> {
+        let mut out = Vec::new();
+        for (key, value) in elements {
+            out.append(&mut self.admit(key, value));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TinyLfuAdmission;
+    use crate::tests::test_building_block;
+    use crate::utils::decorator::Lru;
+    use crate::utils::timestamp::Counter;
+    use crate::{Array, BTree, BuildingBlock, Decorator, Exclusive, Get};
+
+    #[test]
+    fn building_block() {
+        test_building_block(TinyLfuAdmission::new(Array::new(0)), true);
+        test_building_block(TinyLfuAdmission::new(Array::new(10)), true);
+        test_building_block(TinyLfuAdmission::new(Array::new(100)), true);
+    }
+
+    #[test]
+    fn one_hit_wonders() {
+        let mut c = TinyLfuAdmission::new(Array::new(2));
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        assert_eq!(*c.get(&0u16).unwrap(), 0u32);
+        assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+
+        // Keys seen once are rejected until frequencies are aged.
+        for i in 2u16..10u16 {
+            assert_eq!(c.push(vec![(i, i as u32)]), vec![(i, i as u32)]);
+        }
+        assert!(c.contains(&0u16));
+        assert!(c.contains(&1u16));
+    }
+
+    #[test]
+    fn sized_elements() {
+        let mut c = TinyLfuAdmission::new(
+            BTree::new(4).with_element_size(|(k, _)| *k as usize),
+        );
+        c.push(vec![(1u16, 10u32), (2u16, 20u32)]);
+        assert_eq!(*c.get(&2u16).unwrap(), 20u32);
+
+        // 3 does not fit along with 2 which is accessed more often.
+        assert_eq!(c.push(vec![(3u16, 0u32)]), vec![(3u16, 0u32)]);
+        assert!(c.contains(&1u16));
+        assert!(c.contains(&2u16));
+        assert_eq!(c.size(), 3);
+    }
+
+    #[test]
+    fn rejected_victim_keeps_decoration() {
+        let mut c = Decorator::new(
+            TinyLfuAdmission::new(Array::new(2)),
+            Lru::<Counter>::new(),
+        );
+        c.push(vec![(0u16, 0u32), (1u16, 1u32)]);
+        for _ in 0..4 {
+            assert_eq!(*c.get(&1u16).unwrap(), 1u32);
+        }
+
+        // 0 is the least recently used element and the victim.
+        assert_eq!(c.push(vec![(2u16, 2u32)]), vec![(2u16, 2u32)]);
+        // 0 is still the victim after being pushed back.
+        assert_eq!(c.push(vec![(2u16, 2u32)]), vec![(0u16, 0u32)]);
+        assert!(c.contains(&1u16));
+        assert!(c.contains(&2u16));
+    }
+
+    #[test]
+    fn window() {
+        // The front container admits every element and the elements
+        // evicted from it are filtered before entering the back
+        // container.
+        let mut c = Exclusive::new(
+            Array::new(1),
+            TinyLfuAdmission::new(Array::new(1)),
+        );
+        assert!(c.push(vec![(0u16, 0u32)]).is_empty());
+        assert!(c.push(vec![(1u16, 1u32)]).is_empty());
+        assert_eq!(c.push(vec![(2u16, 2u32)]), vec![(1u16, 1u32)]);
+        assert!(c.front().contains(&2u16));
+        assert!(c.back().contains(&0u16));
+    }
+}
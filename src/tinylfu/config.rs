@@ -0,0 +1,203 @@
+use crate::array::config::ArrayConfig;
+use crate::btree::config::BTreeConfig;
+use crate::builder::TinyLfuAdmissionBuilder;
+use crate::config::{
+    ConfigError, ConfigInstance, GenericConfig, GenericKey, GenericValue,
+    IntoConfig,
+};
+use crate::hashmap::config::HashMapConfig;
+use crate::objsafe::DynBuildingBlock;
+use crate::TinyLfuAdmission;
+use serde::{Deserialize, Serialize};
+
+/// Configuration format for
+/// [`TinyLfuAdmission`](../struct.TinyLfuAdmission.html) containers.
+///
+/// This configuration format is composed of a unique `id` field where the
+/// `id` value must be "TinyLfuAdmissionConfig", and the configuration in
+/// toml format of the container to wrap. The wrapped container must
+/// implement [`ReversiblePop`](../utils/tinylfu/trait.ReversiblePop.html),
+/// i.e its `id` must be one of "ArrayConfig", "BTreeConfig" or
+/// "HashMapConfig".
+///
+/// Below is an example of the configuration of a
+/// [`TinyLfuAdmission`](../struct.TinyLfuAdmission.html) wrapping an
+/// [`Array`](../struct.Array.html) container.
+/// ```
+/// use byoc::{BuildingBlock, DynBuildingBlock};
+/// use byoc::config::{ConfigInstance, ConfigBuilder};
+///
+/// let config_str = format!("
+/// id='TinyLfuAdmissionConfig'
+/// [container]
+/// id='ArrayConfig'
+/// capacity=10
+/// ");
+///
+/// let container: DynBuildingBlock<u64, u64> =
+///                ConfigBuilder::from_string(config_str.as_str())
+///                .unwrap()
+///                .build();
+/// ```
+#[derive(Deserialize, Serialize, Clone)]
+pub struct TinyLfuAdmissionConfig {
+    #[allow(dead_code)]
+    id: String,
+    container: toml::Value,
+}
+
+impl<C, B> IntoConfig<TinyLfuAdmissionConfig>
+    for TinyLfuAdmissionBuilder<C, B>
+where
+    C: ConfigInstance,
+    B: IntoConfig<C>,
+{
+    fn as_config(&self) -> TinyLfuAdmissionConfig {
+        let container_toml_str = self.builder.as_config().to_toml_string();
+        let container: toml::value::Value =
+            toml::de::from_str(container_toml_str.as_ref()).unwrap();
+        TinyLfuAdmissionConfig {
+            id: String::from(TinyLfuAdmissionConfig::id()),
+            container,
+        }
+    }
+}
+
+impl ConfigInstance for TinyLfuAdmissionConfig {
+    fn id() -> &'static str {
+        "TinyLfuAdmissionConfig"
+    }
+
+    fn from_toml(value: &toml::Value) -> Result<Self, ConfigError> {
+        let toml = toml::to_string(&value).unwrap();
+        let cfg: TinyLfuAdmissionConfig = match toml::from_str(&toml) {
+            Err(e) => return Err(ConfigError::TomlFormatError(e)),
+            Ok(cfg) => cfg,
+        };
+        GenericConfig::from_toml(&cfg.container)?;
+        match cfg.container_id() {
+            "ArrayConfig" | "BTreeConfig" | "HashMapConfig" => Ok(cfg),
+            id => Err(ConfigError::ConfigFormatError(format!(
+                "Invalid TinyLfuAdmissionConfig container {}: must be \
+                 one of ArrayConfig, BTreeConfig or HashMapConfig.",
+                id
+            ))),
+        }
+    }
+
+    fn build<'a, K: 'a + GenericKey, V: 'a + GenericValue>(
+        self,
+    ) -> DynBuildingBlock<'a, K, V> {
+        match self.container_id() {
+            "ArrayConfig" => DynBuildingBlock::new_resizable_get(
+                TinyLfuAdmission::new(
+                    ArrayConfig::from_toml(&self.container)
+                        .unwrap()
+                        .array(),
+                ),
+                false,
+                true,
+            ),
+            "BTreeConfig" => DynBuildingBlock::new_resizable_get(
+                TinyLfuAdmission::new(
+                    BTreeConfig::from_toml(&self.container)
+                        .unwrap()
+                        .btree(),
+                ),
+                false,
+                true,
+            ),
+            "HashMapConfig" => DynBuildingBlock::new_resizable_get(
+                TinyLfuAdmission::new(
+                    HashMapConfig::from_toml(&self.container)
+                        .unwrap()
+                        .hashmap(),
+                ),
+                false,
+                true,
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    fn has_get_trait(&self) -> bool {
+        true
+    }
+
+    fn is_resizable(&self) -> bool {
+        true
+    }
+}
+
+impl TinyLfuAdmissionConfig {
+    fn container_id(&self) -> &str {
+        self.container.get("id").and_then(|id| id.as_str()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TinyLfuAdmissionConfig;
+    use crate::builder::{ArrayBuilder, TinyLfuAdmissionBuilder};
+    use crate::config::tests::test_config_builder;
+    use crate::config::{ConfigError, ConfigInstance};
+    use crate::objsafe::DynBuildingBlock;
+    use crate::BuildingBlock;
+
+    #[test]
+    fn test_valid_tiny_lfu_admission_config() {
+        let array_capacity = 10;
+        let config_str = format!(
+            "id='TinyLfuAdmissionConfig'
+[container]
+id='ArrayConfig'
+capacity={}
+",
+            array_capacity
+        );
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        let config = TinyLfuAdmissionConfig::from_toml(&value).unwrap();
+        let container: DynBuildingBlock<u64, u64> = config.build();
+        assert_eq!(container.capacity(), array_capacity);
+    }
+
+    #[test]
+    fn test_invalid_tiny_lfu_admission_config() {
+        let config_str = "id='TinyLfuAdmissionConfig'
+[container]
+id='ArrayConfig'
+capacity='ten'
+"
+        .to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            TinyLfuAdmissionConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_tiny_lfu_admission_container() {
+        let config_str = "id='TinyLfuAdmissionConfig'
+[container]
+id='ClockConfig'
+capacity=10
+"
+        .to_string();
+        let value: toml::Value =
+            toml::from_str(config_str.as_str()).unwrap();
+        assert!(matches!(
+            TinyLfuAdmissionConfig::from_toml(&value),
+            Err(ConfigError::ConfigFormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_as_config() {
+        let builder =
+            TinyLfuAdmissionBuilder::new(ArrayBuilder::<()>::new(2));
+        test_config_builder(builder);
+    }
+}
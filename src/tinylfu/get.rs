@@ -0,0 +1,60 @@
+use super::TinyLfuAdmission;
+use crate::utils::get::LifeTimeGuard;
+use crate::{Get, GetMut};
+use std::hash::Hash;
+
+impl<K, V, C> Get<K, V> for TinyLfuAdmission<C>
+where
+    K: Hash,
+    C: Get<K, V>,
+{
+    type Target = C::Target;
+
+    /// Accesses to elements of the container are recorded in the
+    /// frequency sketch.
+    fn get(&mut self, key: &K) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let out = self.container.get(key);
+        if out.is_some() {
+            self.sketch.increment(key);
+        }
+        out
+    }
+}
+
+impl<K, V, C> GetMut<K, V> for TinyLfuAdmission<C>
+where
+    K: Hash,
+    C: GetMut<K, V>,
+{
+    type Target = C::Target;
+
+    /// Accesses to elements of the container are recorded in the
+    /// frequency sketch.
+    fn get_mut(
+        &mut self,
+        key: &K,
+    ) -> Option<LifeTimeGuard<'_, Self::Target>> {
+        let out = self.container.get_mut(key);
+        if out.is_some() {
+            self.sketch.increment(key);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TinyLfuAdmission;
+    use crate::tests::{test_get, test_get_mut};
+    use crate::Array;
+
+    #[test]
+    fn get() {
+        test_get(TinyLfuAdmission::new(Array::new(0)));
+        test_get(TinyLfuAdmission::new(Array::new(10)));
+        test_get(TinyLfuAdmission::new(Array::new(100)));
+        test_get_mut(TinyLfuAdmission::new(Array::new(0)));
+        test_get_mut(TinyLfuAdmission::new(Array::new(10)));
+        test_get_mut(TinyLfuAdmission::new(Array::new(100)));
+    }
+}
@@ -0,0 +1,10 @@
+#[allow(clippy::module_inception)]
+mod tinylfu;
+pub use tinylfu::{ReversiblePop, TinyLfuAdmission};
+pub(crate) mod builder;
+mod building_block;
+#[cfg(feature = "config")]
+pub(crate) mod config;
+mod get;
+mod resizable;
+mod sketch;
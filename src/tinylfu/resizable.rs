@@ -0,0 +1,30 @@
+use super::TinyLfuAdmission;
+use crate::Resizable;
+
+impl<K, V, C> Resizable<K, V> for TinyLfuAdmission<C>
+where
+    C: Resizable<K, V>,
+{
+    /// Change the wrapped container capacity.
+    ///
+    /// The frequency sketch is sized after the new capacity on the next
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push). The recorded
+    /// frequencies are kept.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        self.container.resize(new_capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TinyLfuAdmission;
+    use crate::tests::test_resizable;
+    use crate::Array;
+
+    #[test]
+    fn resizable() {
+        test_resizable(TinyLfuAdmission::new(Array::new(0)));
+        test_resizable(TinyLfuAdmission::new(Array::new(10)));
+        test_resizable(TinyLfuAdmission::new(Array::new(100)));
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Number of rows of the count-min sketch.
+const DEPTH: usize = 4;
+/// Minimum number of counters in a row of the count-min sketch.
+const MIN_WIDTH: usize = 64;
+/// Maximum value of a count-min sketch counter.
+const MAX_COUNT: u8 = 15;
+/// Number of bits of the doorkeeper per sketch column.
+const DOORKEEPER_BITS: usize = 8;
+/// Number of bits set in the doorkeeper per key.
+const DOORKEEPER_PROBES: usize = 2;
+/// Number of recorded keys per unit of capacity before counters are aged.
+const SAMPLE_FACTOR: usize = 10;
+
+/// Approximate key access frequencies of a
+/// [`TinyLfuAdmission`](../struct.TinyLfuAdmission.html) container.
+///
+/// Frequencies are recorded in a count-min sketch with small saturating
+/// counters. The first occurrence of a key only sets bits in a doorkeeper
+/// bloom filter such that keys seen once do not use sketch counters.
+/// After a number of recorded keys proportional to the container capacity,
+/// all counters are halved and the doorkeeper is cleared so that the
+/// sketch follows changes in the access pattern.
+pub(super) struct FrequencySketch {
+    // Capacity the sketch is sized for.
+    capacity: usize,
+    // Number of counters in a row. This is a power of two.
+    width: usize,
+    counters: Vec<u8>,
+    doorkeeper: Vec<u64>,
+    // Number of keys recorded since the last aging.
+    additions: usize,
+}
+
+impl FrequencySketch {
+    pub(super) fn new() -> Self {
+        FrequencySketch {
+            capacity: 0,
+            width: 0,
+            counters: Vec::new(),
+            doorkeeper: Vec::new(),
+            additions: 0,
+        }
+    }
+
+    /// Size the sketch for a container of `capacity` elements.
+    ///
+    /// Recorded frequencies are kept when the sketch is resized: when the
+    /// sketch grows, the counters and doorkeeper bits of a key are copied
+    /// to its new columns, and when it shrinks, the columns folded
+    /// together keep their greatest counter and the union of their bits.
+    /// Hence, estimated frequencies can only increase.
+    pub(super) fn fit(&mut self, capacity: usize) {
+        if self.capacity == capacity && self.width > 0 {
+            return;
+        }
+        self.capacity = capacity;
+        let width = capacity.max(MIN_WIDTH).next_power_of_two();
+        if width == self.width {
+            return;
+        }
+
+        let mut counters = vec![0; DEPTH * width];
+        let bits = width * DOORKEEPER_BITS;
+        let mut doorkeeper = vec![0; bits.div_ceil(64)];
+        // Columns are masked hashes and widths are powers of two, such
+        // that the column of a key in the largest sketch masked with the
+        // width of the smallest sketch is its column in the smallest one.
+        if self.width > 0 {
+            for row in 0..DEPTH {
+                for column in 0..width.max(self.width) {
+                    let i = row * width + (column & (width - 1));
+                    let j = row * self.width + (column & (self.width - 1));
+                    counters[i] = counters[i].max(self.counters[j]);
+                }
+            }
+            let old_bits = self.width * DOORKEEPER_BITS;
+            for b in 0..bits.max(old_bits) {
+                let old = b & (old_bits - 1);
+                if self.doorkeeper[old / 64] & (1u64 << (old % 64)) != 0 {
+                    let new = b & (bits - 1);
+                    doorkeeper[new / 64] |= 1u64 << (new % 64);
+                }
+            }
+        }
+        self.width = width;
+        self.counters = counters;
+        self.doorkeeper = doorkeeper;
+    }
+
+    fn hash<K: Hash>(key: &K) -> (usize, usize) {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let h = hasher.finish();
+        // The second hash is odd such that probes do not collapse.
+        ((h & 0xffffffff) as usize, ((h >> 32) | 1) as usize)
+    }
+
+    fn counter_indexes(&self, h: (usize, usize)) -> [usize; DEPTH] {
+        let mut indexes = [0; DEPTH];
+        for (row, index) in indexes.iter_mut().enumerate() {
+            let column = h.0.wrapping_add(row.wrapping_mul(h.1));
+            *index = row * self.width + (column & (self.width - 1));
+        }
+        indexes
+    }
+
+    fn doorkeeper_bits(
+        &self,
+        h: (usize, usize),
+    ) -> [usize; DOORKEEPER_PROBES] {
+        let bits = self.width * DOORKEEPER_BITS;
+        let mut indexes = [0; DOORKEEPER_PROBES];
+        for (i, index) in indexes.iter_mut().enumerate() {
+            *index = h.1.wrapping_add(i.wrapping_mul(h.0)) % bits;
+        }
+        indexes
+    }
+
+    fn doorkeeper_contains(&self, h: (usize, usize)) -> bool {
+        self.doorkeeper_bits(h)
+            .iter()
+            .all(|&b| self.doorkeeper[b / 64] & (1u64 << (b % 64)) != 0)
+    }
+
+    /// Record one occurrence of `key`.
+    pub(super) fn increment<K: Hash>(&mut self, key: &K) {
+        if self.width == 0 {
+            return;
+        }
+
+        let h = Self::hash(key);
+        if self.doorkeeper_contains(h) {
+            for i in self.counter_indexes(h) {
+                if self.counters[i] < MAX_COUNT {
+                    self.counters[i] += 1;
+                }
+            }
+        } else {
+            for b in self.doorkeeper_bits(h) {
+                self.doorkeeper[b / 64] |= 1u64 << (b % 64);
+            }
+        }
+
+        self.additions += 1;
+        if self.additions >= SAMPLE_FACTOR * self.capacity.max(1) {
+            self.age();
+        }
+    }
+
+    /// Get the estimated number of occurrences of `key`.
+    pub(super) fn frequency<K: Hash>(&self, key: &K) -> u8 {
+        if self.width == 0 {
+            return 0;
+        }
+
+        let h = Self::hash(key);
+        if !self.doorkeeper_contains(h) {
+            return 0;
+        }
+        let count = self
+            .counter_indexes(h)
+            .iter()
+            .map(|&i| self.counters[i])
+            .min()
+            .unwrap();
+        count + 1
+    }
+
+    /// Halve every counter and clear the doorkeeper.
+    fn age(&mut self) {
+        for c in self.counters.iter_mut() {
+            *c /= 2;
+        }
+        for bits in self.doorkeeper.iter_mut() {
+            *bits = 0;
+        }
+        self.additions /= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FrequencySketch, MAX_COUNT, SAMPLE_FACTOR};
+
+    #[test]
+    fn frequency() {
+        let mut sketch = FrequencySketch::new();
+        sketch.fit(16);
+        assert_eq!(sketch.frequency(&0u16), 0);
+        sketch.increment(&0u16);
+        assert_eq!(sketch.frequency(&0u16), 1);
+        sketch.increment(&0u16);
+        sketch.increment(&0u16);
+        assert!(sketch.frequency(&0u16) >= 3);
+        for _ in 0..2 * MAX_COUNT {
+            sketch.increment(&1u16);
+        }
+        assert_eq!(sketch.frequency(&1u16), MAX_COUNT + 1);
+    }
+
+    #[test]
+    fn fit_keeps_frequencies() {
+        let mut sketch = FrequencySketch::new();
+        sketch.fit(16);
+        for _ in 0..3 {
+            sketch.increment(&0u16);
+        }
+        sketch.increment(&1u16);
+
+        // Growing the sketch.
+        sketch.fit(1000);
+        assert!(sketch.frequency(&0u16) >= 3);
+        assert!(sketch.frequency(&1u16) >= 1);
+        sketch.increment(&0u16);
+        assert!(sketch.frequency(&0u16) >= 4);
+
+        // Shrinking the sketch.
+        sketch.fit(16);
+        assert!(sketch.frequency(&0u16) >= 4);
+        assert!(sketch.frequency(&1u16) >= 1);
+    }
+
+    #[test]
+    fn aging() {
+        let mut sketch = FrequencySketch::new();
+        sketch.fit(1);
+        for _ in 0..SAMPLE_FACTOR - 1 {
+            sketch.increment(&0u16);
+        }
+        assert_eq!(sketch.frequency(&0u16), SAMPLE_FACTOR as u8 - 1);
+
+        // The next record halves counters and clears the doorkeeper.
+        sketch.increment(&0u16);
+        assert_eq!(sketch.frequency(&0u16), 0);
+        sketch.increment(&0u16);
+        assert_eq!(sketch.frequency(&0u16), SAMPLE_FACTOR as u8 / 2);
+    }
+}
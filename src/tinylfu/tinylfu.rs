@@ -0,0 +1,186 @@
+use super::sketch::FrequencySketch;
+use crate::{Array, BTree, BuildingBlock, HashMapContainer};
+use std::hash::Hash;
+
+/// Marker trait of containers that can be wrapped in a
+/// [`TinyLfuAdmission`](../../struct.TinyLfuAdmission.html) container.
+///
+/// [`TinyLfuAdmission`](../../struct.TinyLfuAdmission.html) pops the
+/// victims of the wrapped container to compare their frequency with the
+/// frequency of new elements, and pushes them back if the new elements
+/// are rejected. Implementers of this trait guarantee that pushing back the
+/// elements they popped leaves them in the same state as before the pop,
+/// such that rejecting an element does not change which elements are
+/// evicted next.
+///
+/// Containers keeping an eviction state outside of their values, such as
+/// [`ArcCache`](../../struct.ArcCache.html) ghost lists or
+/// [`Decorator`](../../struct.Decorator.html) policies, do not implement
+/// this trait: a popped and pushed back victim would be seen as a new
+/// element.
+pub trait ReversiblePop {}
+
+impl<T> ReversiblePop for Array<T> {}
+impl<K, V> ReversiblePop for BTree<K, V>
+where
+    K: Copy + Ord,
+    V: Ord,
+{
+}
+impl<K, V> ReversiblePop for HashMapContainer<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Ord,
+{
+}
+
+/// `BuildingBlock` wrapper admitting new elements based on their
+/// access frequency.
+///
+/// This [`BuildingBlock`](trait.BuildingBlock.html) implements the TinyLFU
+/// admission policy described by G. Einziger, R. Friedman and B. Manes in
+/// "TinyLFU: A Highly Efficient Cache Admission Policy". The key of every
+/// element pushed in the container and of every element accessed with the
+/// [`Get`](trait.Get.html) and [`GetMut`](trait.GetMut.html) traits is
+/// recorded in a compact frequency sketch made of a count-min sketch and a
+/// doorkeeper bloom filter.
+///
+/// When a new element pushed in the wrapped container evicts elements,
+/// i.e the container is full or the new element does not fit in the
+/// remaining space, the estimated access frequency of the victims is
+/// compared with the frequency of the new element. The new element
+/// replaces the victims only if it is accessed more often. Otherwise, the
+/// victims are pushed back in the container and the new element is
+/// rejected, i.e it is returned as if it was evicted. This prevents keys
+/// accessed only once from polluting the container.
+///
+/// Since a rejected victim is pushed back, the wrapped container must
+/// implement the [`ReversiblePop`](utils/tinylfu/trait.ReversiblePop.html)
+/// marker trait, i.e pushing back the victims it pops must restore its
+/// eviction order. This is the case of containers ordering their elements
+/// by value: [`Array`](struct.Array.html), [`BTree`](struct.BTree.html)
+/// and [`HashMapContainer`](struct.HashMapContainer.html). A
+/// [`Decorator`](struct.Decorator.html) policy should thus wrap the
+/// [`TinyLfuAdmission`] container rather than be wrapped by it: the
+/// victim is then pushed back with its decoration.
+///
+/// Frequencies are aged periodically such that the sketch follows changes
+/// in the access pattern. Other methods are forwarded to the wrapped
+/// container and do not update the sketch.
+///
+/// W-TinyLFU is obtained by filtering the main container of an
+/// [`Exclusive`](struct.Exclusive.html) cache with a small front
+/// container acting as an admission window.
+///
+/// [`TinyLfuAdmission`] can also be built from a
+/// [builder pattern](builder/trait.TinyLfuAdmissionBuild.html) and a
+/// [configuration](config/struct.TinyLfuAdmissionConfig.html).
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Get, TinyLfuAdmission};
+///
+/// let mut c = TinyLfuAdmission::new(Array::new(1));
+/// c.push(vec![("first", 1)]);
+/// assert_eq!(*c.get(&"first").unwrap(), 1);
+///
+/// // "second" was seen less often than "first" and is rejected.
+/// assert_eq!(c.push(vec![("second", 2)]), vec![("second", 2)]);
+/// assert!(c.contains(&"first"));
+///
+/// // "second" is now seen as often as "first", but not more.
+/// assert_eq!(c.push(vec![("second", 2)]), vec![("second", 2)]);
+///
+/// // "second" is eventually admitted.
+/// assert_eq!(c.push(vec![("second", 2)]), vec![("first", 1)]);
+/// ```
+pub struct TinyLfuAdmission<C> {
+    pub(super) container: C,
+    pub(super) sketch: FrequencySketch,
+}
+
+impl<C> TinyLfuAdmission<C> {
+    /// Wrap `container` into a [`TinyLfuAdmission`] container.
+    ///
+    /// The frequency sketch is sized after the container capacity.
+    pub fn new(container: C) -> Self {
+        TinyLfuAdmission {
+            container,
+            sketch: FrequencySketch::new(),
+        }
+    }
+
+    /// Get shared access to the wrapped container.
+    pub fn container(&self) -> &C {
+        &self.container
+    }
+
+    /// Get exclusive access to the wrapped container.
+    pub fn container_mut(&mut self) -> &mut C {
+        &mut self.container
+    }
+
+    /// Insert one element in the wrapped container if it is admitted.
+    ///
+    /// If the container is full, a victim is popped out of it before
+    /// pushing the element. The element is admitted if it is estimated to
+    /// be accessed more often than the popped victim and the victims
+    /// evicted while pushing it. Otherwise, the element is taken out of
+    /// the container and the victims are pushed back.
+    ///
+    /// Returns the elements evicted from the container or the element
+    /// itself if it is rejected.
+    pub(super) fn admit<K, V>(&mut self, key: K, value: V) -> Vec<(K, V)>
+    where
+        K: Hash + Clone,
+        C: BuildingBlock<K, V> + ReversiblePop,
+    {
+        self.sketch.fit(self.container.capacity());
+        self.sketch.increment(&key);
+
+        if self.container.contains(&key) {
+            return self.container.push(vec![(key, value)]);
+        }
+
+        let mut victims =
+            if self.container.size() >= self.container.capacity() {
+                self.container.pop(1)
+            } else {
+                Vec::new()
+            };
+        let mut evicted = self.container.push(vec![(key.clone(), value)]);
+
+        // The element does not fit in the container.
+        if !self.container.contains(&key) {
+            evicted.append(&mut self.container.push(victims));
+            return evicted;
+        }
+
+        victims.append(&mut evicted);
+        let frequency = self.sketch.frequency(&key);
+        if victims
+            .iter()
+            .all(|(k, _)| self.sketch.frequency(k) < frequency)
+        {
+            victims
+        } else {
+            let element = self.container.take(&key);
+            let mut out = self.container.push(victims);
+            out.extend(element);
+            out
+        }
+    }
+}
+
+impl<'a, K, V, C> From<TinyLfuAdmission<C>>
+    for crate::DynBuildingBlock<'a, K, V>
+where
+    K: 'a + Hash + Clone,
+    V: 'a,
+    C: 'a + BuildingBlock<K, V> + ReversiblePop + crate::Resizable<K, V>,
+{
+    fn from(container: TinyLfuAdmission<C>) -> Self {
+        crate::DynBuildingBlock::new_resizable(container, false, true)
+    }
+}
@@ -53,6 +53,17 @@ pub trait BuildingBlock<K, V> {
     /// Check if container contains a matching key.
     fn contains(&self, key: &K) -> bool;
 
+    /// Check if container contains a matching key which value satisfies
+    /// `predicate`.
+    ///
+    /// This lets containers wrapping other containers look at the values
+    /// they stored, e.g a [`Decorator`](struct.Decorator.html) container
+    /// treats expired values as absent. Containers forwarding calls to
+    /// other containers forward this method, and containers holding their
+    /// values evaluate `predicate` on the value stored with `key`.
+    fn contains_if(&self, key: &K, predicate: &dyn Fn(&V) -> bool)
+        -> bool;
+
     /// Take the matching key/value pair out of the container.
     ///
     /// After this method is called, if the key was present in the container,
@@ -134,6 +145,13 @@ where
     fn contains(&self, key: &K) -> bool {
         (**self).contains(key)
     }
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        (**self).contains_if(key, predicate)
+    }
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         (**self).take(key)
     }
@@ -0,0 +1,202 @@
+use crate::utils::get::LifeTimeGuard;
+use std::ops::{Deref, DerefMut};
+
+/// In-place insertion or update of a value inside a `BuildingBlock`.
+///
+/// Updating a value with [`take()`](trait.BuildingBlock.html#tymethod.take)
+/// then [`push()`](trait.BuildingBlock.html#tymethod.push) may trigger
+/// evictions and update the container eviction state twice. This trait
+/// mirrors [`std::collections::HashMap::entry()`]: the returned
+/// [`Entry`](utils/entry/enum.Entry.html) is looked up once and lets
+/// the caller modify the value in place if it is found, or insert a new
+/// value otherwise.
+///
+/// Inserting a value in a container may pop other values out of it.
+/// Therefore, insertion methods return the elements that did not fit in
+/// the container, like the container
+/// [`push()`](trait.BuildingBlock.html#tymethod.push) method.
+///
+/// ## Example:
+///
+/// ```
+/// use byoc::{Array, Get, GetEntry};
+///
+/// let mut counters = Array::new(2);
+/// for key in ["first", "second", "first"] {
+///     counters.entry(key).and_modify(|v| *v += 1).or_insert(1);
+/// }
+///
+/// assert_eq!(*counters.get(&"first").unwrap(), 2);
+/// assert_eq!(*counters.get(&"second").unwrap(), 1);
+/// ```
+pub trait GetEntry<K, V> {
+    type Target: Deref<Target = V> + DerefMut;
+
+    /// Get the entry of a key in the container for in-place
+    /// insertion or update.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self::Target>;
+}
+
+impl<K, V, B: GetEntry<K, V>> GetEntry<K, V> for &mut B {
+    type Target = B::Target;
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, B::Target> {
+        (**self).entry(key)
+    }
+}
+
+/// A view into a single entry of a container, which may either be
+/// occupied or vacant.
+///
+/// This enum is constructed from the
+/// [`entry()`](../../trait.GetEntry.html#tymethod.entry) method of the
+/// [`GetEntry`](../../trait.GetEntry.html) trait.
+pub enum Entry<'a, K, V, T> {
+    /// The key is in the container.
+    Occupied(OccupiedEntry<'a, K, T>),
+    /// The key is not in the container.
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V, T> Entry<'a, K, V, T>
+where
+    T: Deref<Target = V> + DerefMut,
+{
+    /// Get a reference to this entry key.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(e) => e.key(),
+            Entry::Vacant(e) => e.key(),
+        }
+    }
+
+    /// Modify in place the value of an occupied entry.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut e) => {
+                f(e.get_mut());
+                Entry::Occupied(e)
+            }
+            Entry::Vacant(e) => Entry::Vacant(e),
+        }
+    }
+
+    /// Insert `default` value if the entry is vacant.
+    ///
+    /// The elements that did not fit in the container are returned.
+    pub fn or_insert(self, default: V) -> Vec<(K, V)> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `default` function if the entry is vacant.
+    ///
+    /// The elements that did not fit in the container are returned.
+    pub fn or_insert_with<F: FnOnce() -> V>(
+        self,
+        default: F,
+    ) -> Vec<(K, V)> {
+        self.or_insert_with_key(|_| default())
+    }
+
+    /// Insert the result of `default` function called with the entry key
+    /// if the entry is vacant.
+    ///
+    /// The elements that did not fit in the container are returned.
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(
+        self,
+        default: F,
+    ) -> Vec<(K, V)> {
+        match self {
+            Entry::Occupied(_) => Vec::new(),
+            Entry::Vacant(e) => {
+                let value = default(e.key());
+                e.insert(value)
+            }
+        }
+    }
+
+    /// Consume the entry and get back its key.
+    pub fn into_key(self) -> K {
+        match self {
+            Entry::Occupied(e) => e.into_parts().0,
+            Entry::Vacant(e) => e.into_key(),
+        }
+    }
+}
+
+/// A view into an occupied entry of a container.
+///
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, K, T> {
+    key: K,
+    value: LifeTimeGuard<'a, T>,
+}
+
+impl<'a, K, V, T> OccupiedEntry<'a, K, T>
+where
+    T: Deref<Target = V> + DerefMut,
+{
+    pub(crate) fn new(key: K, value: LifeTimeGuard<'a, T>) -> Self {
+        OccupiedEntry { key, value }
+    }
+
+    pub(crate) fn into_parts(self) -> (K, LifeTimeGuard<'a, T>) {
+        (self.key, self.value)
+    }
+
+    /// Get a reference to this entry key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Get a reference to this entry value.
+    pub fn get(&self) -> &V {
+        self.value.deref()
+    }
+
+    /// Get a mutable reference to this entry value.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.value.deref_mut()
+    }
+
+    /// Convert this entry into the smart pointer to its value inside
+    /// the container.
+    pub fn into_mut(self) -> LifeTimeGuard<'a, T> {
+        self.value
+    }
+}
+
+/// Function inserting a key/value pair of a [`VacantEntry`] in the
+/// container it comes from.
+type InsertFn<'a, K, V> = Box<dyn FnOnce(K, V) -> Vec<(K, V)> + 'a>;
+
+/// A view into a vacant entry of a container.
+///
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    insert: InsertFn<'a, K, V>,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub(crate) fn new(key: K, insert: InsertFn<'a, K, V>) -> Self {
+        VacantEntry { key, insert }
+    }
+
+    /// Get a reference to this entry key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Consume the entry and get back its key.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Insert this entry key with `value` in the container.
+    ///
+    /// The elements that did not fit in the container are returned. They
+    /// may include the inserted key/value pair.
+    pub fn insert(self, value: V) -> Vec<(K, V)> {
+        (self.insert)(self.key, value)
+    }
+}
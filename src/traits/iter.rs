@@ -0,0 +1,45 @@
+/// Non-destructive iteration over the elements of a `BuildingBlock`.
+///
+/// The only way to enumerate the elements of a
+/// [`BuildingBlock`](trait.BuildingBlock.html) is to
+/// [`flush()`](trait.BuildingBlock.html#tymethod.flush) it, which leaves
+/// the container empty. This trait allows to inspect, export or debug the
+/// content of a container while leaving it untouched.
+///
+/// Some containers do not hold their elements in memory, e.g.
+/// [`Stream`](struct.Stream.html) or
+/// [`Compressed`](struct.Compressed.html) containers. Therefore, the
+/// iterator yields owned clones of the container key/value pairs rather
+/// than references.
+///
+/// Iterating over a container does not update its internal state. For
+/// instance, the elements of a [`Decorator`](struct.Decorator.html)
+/// container are not marked as accessed.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Iter};
+///
+/// let mut c = Array::new(2);
+/// c.push(vec![("first", 1), ("second", 2)]);
+///
+/// let mut elements: Vec<(&str, i32)> = c.iter().collect();
+/// elements.sort();
+/// assert_eq!(elements, vec![("first", 1), ("second", 2)]);
+///
+/// // The container is left untouched.
+/// assert_eq!(c.size(), 2);
+/// ```
+pub trait Iter<K, V> {
+    /// The type of the iterator returned by
+    /// [`iter()`](trait.Iter.html#tymethod.iter).
+    type Iterator<'a>: Iterator<Item = (K, V)>
+    where
+        Self: 'a;
+
+    /// Iterate over clones of the key/value pairs of the container.
+    ///
+    /// The order of iteration is not specified.
+    fn iter(&self) -> Self::Iterator<'_>;
+}
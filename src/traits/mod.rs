@@ -4,3 +4,13 @@ mod get;
 pub use get::{Get, GetMut};
 mod concurrent;
 pub use concurrent::Concurrent;
+mod resizable;
+pub use resizable::Resizable;
+mod try_building_block;
+pub use try_building_block::TryBuildingBlock;
+mod iter;
+pub use iter::Iter;
+mod range;
+pub use range::{RangeGet, RangeTake};
+mod entry;
+pub use entry::{Entry, GetEntry, OccupiedEntry, VacantEntry};
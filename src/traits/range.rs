@@ -0,0 +1,63 @@
+use std::ops::RangeBounds;
+
+/// Look up the keys of a `BuildingBlock` within a range.
+///
+/// This trait provides ordered queries over the keys of a container,
+/// e.g. "all the keys in `[a, b)`". Containers keeping their keys ordered
+/// such as [`BTree`](struct.BTree.html) implement these queries
+/// efficiently while other containers, such as
+/// [`Array`](struct.Array.html), implement them with a linear scan of
+/// their elements.
+///
+/// Ranges are expressed with any type implementing
+/// [`RangeBounds`](std::ops::RangeBounds), e.g. `a..b`, `a..=b`, `..b` or
+/// `..`.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{BTree, BuildingBlock, RangeGet};
+///
+/// let mut c = BTree::new(4);
+/// c.push(vec![(1, 1), (3, 3), (5, 5), (7, 7)]);
+///
+/// assert!(c.contains_range(2..4));
+/// assert!(!c.contains_range(8..));
+/// assert_eq!(c.keys_in_range(3..=7), vec![3, 5, 7]);
+/// ```
+pub trait RangeGet<K, V> {
+    /// Check whether the container contains at least one key within
+    /// `range`.
+    fn contains_range<R: RangeBounds<K>>(&self, range: R) -> bool;
+
+    /// Get the keys of the container within `range` sorted in ascending
+    /// order.
+    fn keys_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<K>;
+}
+
+/// Take the elements of a `BuildingBlock` within a range of keys.
+///
+/// This is the range counterpart of the
+/// [`take_multiple()`](trait.BuildingBlock.html#method.take_multiple)
+/// method. See [`RangeGet`](trait.RangeGet.html) for more details.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{BTree, BuildingBlock, RangeTake};
+///
+/// let mut c = BTree::new(4);
+/// c.push(vec![(1, 1), (3, 3), (5, 5), (7, 7)]);
+///
+/// let mut taken = c.take_range(2..6);
+/// taken.sort();
+/// assert_eq!(taken, vec![(3, 3), (5, 5)]);
+/// assert_eq!(c.size(), 2);
+/// ```
+pub trait RangeTake<K, V> {
+    /// Take all the key/value pairs with a key within `range` out of the
+    /// container.
+    ///
+    /// The order of the returned elements is not specified.
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)>;
+}
@@ -0,0 +1,71 @@
+use crate::Error;
+
+/// A `BuildingBlock` which capacity can be changed at runtime.
+///
+/// This trait allows to shrink or grow a
+/// [`BuildingBlock`](trait.BuildingBlock.html) container without tearing it
+/// down and rebuilding it. For instance, it can be used to rebalance a
+/// memory budget between several containers.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, BuildingBlock, Resizable};
+///
+/// let mut c = Array::new(3);
+/// c.push(vec![("first", 1), ("second", 2), ("third", 3)]);
+///
+/// // Shrinking the container evicts the elements that do not fit anymore.
+/// let victims = c.resize(2);
+/// assert_eq!(victims.len(), 1);
+/// assert_eq!(victims[0].0, "third");
+/// assert_eq!(c.capacity(), 2);
+///
+/// // Growing the container makes room for new elements.
+/// assert!(c.resize(4).is_empty());
+/// assert!(c.push(vec![("fourth", 4), ("fifth", 5)]).is_empty());
+/// ```
+pub trait Resizable<K, V> {
+    /// Change the container maximum capacity.
+    ///
+    /// If the container is shrunk and the new capacity does not fit all
+    /// elements then some victim elements are evicted out and returned.
+    /// The choice of victims is the same as the one made by the container
+    /// [`pop()`](trait.BuildingBlock.html#tymethod.pop) method.
+    ///
+    /// After this method returns, the container
+    /// [`capacity()`](trait.BuildingBlock.html#tymethod.capacity) should be
+    /// `new_capacity` and its
+    /// [`size()`](trait.BuildingBlock.html#tymethod.size) should be less or
+    /// equal to `new_capacity`.
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)>;
+
+    /// Change the container maximum capacity if it can be changed.
+    ///
+    /// This is [`resize()`](trait.Resizable.html#tymethod.resize) for
+    /// containers which capacity can only be changed under runtime
+    /// conditions, e.g a [`DynBuildingBlock`](struct.DynBuildingBlock.html)
+    /// wrapping a container that is not [`Resizable`]. Instead of panicking,
+    /// these containers return [`Error::NotResizable`](enum.Error.html)
+    /// and are left unchanged. The default implementation calls
+    /// [`resize()`](trait.Resizable.html#tymethod.resize).
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        Ok(self.resize(new_capacity))
+    }
+}
+
+impl<K, V, C: Resizable<K, V>> Resizable<K, V> for &mut C {
+    fn resize(&mut self, new_capacity: usize) -> Vec<(K, V)> {
+        (**self).resize(new_capacity)
+    }
+
+    fn try_resize(
+        &mut self,
+        new_capacity: usize,
+    ) -> Result<Vec<(K, V)>, Error> {
+        (**self).try_resize(new_capacity)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{BuildingBlock, Error};
+
+/// Fallible variant of the [`BuildingBlock`](trait.BuildingBlock.html)
+/// interface.
+///
+/// Some containers store their elements on a medium that may fail, such
+/// as a [file](struct.Stream.html) or a
+/// [remote container](struct.SocketClient.html). The
+/// [`BuildingBlock`](trait.BuildingBlock.html) methods of these containers
+/// cannot report such failures and will panic or silently ignore them.
+/// This trait provides methods mirroring the
+/// [`BuildingBlock`](trait.BuildingBlock.html) methods that instead return
+/// an [`Error`](enum.Error.html) when the operation fails.
+///
+/// The default implementation of each method calls its
+/// [`BuildingBlock`](trait.BuildingBlock.html) counterpart and never fails.
+/// Containers that cannot fail only need to implement this trait with its
+/// default methods. Containers that can fail implement these methods
+/// natively while containers wrapping other containers forward the calls
+/// to the `try_` methods of the containers they wrap.
+///
+/// ## Examples
+///
+/// ```
+/// use byoc::{Array, Exclusive, TryBuildingBlock};
+///
+/// let mut c = Exclusive::new(Array::new(1), Array::new(1));
+/// assert!(c.try_push(vec![(1, 1), (2, 2)]).unwrap().is_empty());
+/// assert!(c.try_contains(&1).unwrap());
+/// assert_eq!(c.try_take(&2).unwrap().unwrap(), (2, 2));
+/// assert_eq!(c.try_flush().unwrap(), vec![(1, 1)]);
+/// ```
+pub trait TryBuildingBlock<K, V>: BuildingBlock<K, V> {
+    /// Fallible variant of
+    /// [`capacity()`](trait.BuildingBlock.html#tymethod.capacity).
+    fn try_capacity(&self) -> Result<usize, Error> {
+        Ok(self.capacity())
+    }
+
+    /// Fallible variant of
+    /// [`size()`](trait.BuildingBlock.html#tymethod.size).
+    fn try_size(&self) -> Result<usize, Error> {
+        Ok(self.size())
+    }
+
+    /// Fallible variant of
+    /// [`contains()`](trait.BuildingBlock.html#tymethod.contains).
+    fn try_contains(&self, key: &K) -> Result<bool, Error> {
+        Ok(self.contains(key))
+    }
+
+    /// Fallible variant of
+    /// [`take()`](trait.BuildingBlock.html#tymethod.take).
+    fn try_take(&mut self, key: &K) -> Result<Option<(K, V)>, Error> {
+        Ok(self.take(key))
+    }
+
+    /// Fallible variant of
+    /// [`take_multiple()`](trait.BuildingBlock.html#method.take_multiple).
+    fn try_take_multiple(
+        &mut self,
+        keys: &mut Vec<K>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        Ok(self.take_multiple(keys))
+    }
+
+    /// Fallible variant of
+    /// [`pop()`](trait.BuildingBlock.html#tymethod.pop).
+    fn try_pop(&mut self, size: usize) -> Result<Vec<(K, V)>, Error> {
+        Ok(self.pop(size))
+    }
+
+    /// Fallible variant of
+    /// [`push()`](trait.BuildingBlock.html#tymethod.push).
+    fn try_push(
+        &mut self,
+        values: Vec<(K, V)>,
+    ) -> Result<Vec<(K, V)>, Error> {
+        Ok(self.push(values))
+    }
+
+    /// Fallible variant of
+    /// [`flush()`](trait.BuildingBlock.html#tymethod.flush).
+    ///
+    /// Unlike [`flush()`](trait.BuildingBlock.html#tymethod.flush), the
+    /// flushed elements are read into memory before being returned such
+    /// that all the failures can be reported by this method.
+    fn try_flush(&mut self) -> Result<Vec<(K, V)>, Error> {
+        Ok(self.flush().collect())
+    }
+}
@@ -2,7 +2,7 @@
 #[cfg(feature = "stream")]
 pub mod stream {
     pub use crate::stream::{
-        FileStream, Stream, StreamBase, StreamFactory,
+        FileStream, IOError, Stream, StreamBase, StreamFactory,
         TempFileStreamFactory, VecStream, VecStreamFactory,
     };
 }
@@ -47,13 +47,39 @@ pub mod stream {
 /// ```
 pub mod decorator {
     pub use crate::decorator::{
-        Decoration, DecorationFactory, Fifo, Lrfu, Lru,
+        Decoration, DecorationFactory, Fifo, Gdsf, Lfu, Lrfu, Lru, Policy,
+        Random, Ttl,
     };
 }
 
+/// Sources of elements for `Loader` `BuildingBlock`.
+///
+/// This module is a companion module of
+/// [`Loader`](../../struct.Loader.html) container. It provides the
+/// [`Load`](trait.Load.html) trait implemented by the sources of elements
+/// loaded in a [`Loader`](../../struct.Loader.html) container, and a
+/// [`Backend`](struct.Backend.html) structure to use a
+/// [`BuildingBlock`](../../trait.BuildingBlock.html) as a source.
+pub mod loader {
+    pub use crate::loader::{Backend, Load, WithSink};
+}
+
 /// `Associative` struct helpers.
 pub mod associative {
-    pub use crate::associative::ExclusiveHasher;
+    pub use crate::associative::{
+        BucketExecution, BucketSelector, ExclusiveHasher, JumpHash,
+        KeyRange, Parallel, Rendezvous, Serial,
+    };
+}
+
+/// `Hierarchy` struct helpers.
+pub mod hierarchy {
+    pub use crate::hierarchy::{Boundary, LevelStats};
+}
+
+/// `TinyLfuAdmission` struct helpers.
+pub mod tinylfu {
+    pub use crate::tinylfu::ReversiblePop;
 }
 
 pub mod timestamp;
@@ -63,6 +89,12 @@ mod lifetime;
 /// Objects returned by `Get` and `GetMut` traits implementations.
 pub mod get {
     pub use super::lifetime::LifeTimeGuard;
+    pub use crate::objsafe::{DynGetCell, DynGetMutCell};
+}
+
+/// Entries returned by `GetEntry` trait implementations.
+pub mod entry {
+    pub use crate::traits::{Entry, OccupiedEntry, VacantEntry};
 }
 
 #[cfg(feature = "socket")]
@@ -122,7 +154,9 @@ pub mod get {
 /// server.stop_and_join().unwrap();
 /// ```
 pub mod socket {
-    pub use crate::socket::{ServerThreadBuilder, ServerThreadHandle};
+    pub use crate::socket::{
+        ServerThreadBuilder, ServerThreadHandle, SocketError,
+    };
 }
 
 //-----------------------------------------------------------------------------
@@ -142,3 +176,6 @@ pub(crate) mod lock;
 
 /// Size computation utils.
 pub(crate) mod size;
+
+/// Seedable pseudo random number generator.
+pub(crate) mod random;
@@ -168,6 +168,14 @@ where
         self.as_ref().contains(key)
     }
 
+    fn contains_if(
+        &self,
+        key: &K,
+        predicate: &dyn Fn(&V) -> bool,
+    ) -> bool {
+        self.as_ref().contains_if(key, predicate)
+    }
+
     fn take(&mut self, key: &K) -> Option<(K, V)> {
         self.as_mut().take(key)
     }
@@ -42,3 +42,35 @@ where
         Err((i, (size, element))) => (i, size, element),
     }
 }
+
+/// Split a `capacity` between multiple containers proportionally to their
+/// current `capacities`.
+///
+/// The sum of the returned capacities is exactly `capacity`. The rounding
+/// remainder is spread over the first containers. If the current
+/// `capacities` sum to `0`, `capacity` is evenly split between containers.
+pub fn split_capacity(
+    capacities: &[usize],
+    capacity: usize,
+) -> Vec<usize> {
+    let n = capacities.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let total: u128 = capacities.iter().map(|c| *c as u128).sum();
+    let mut split: Vec<usize> = if total == 0 {
+        vec![capacity / n; n]
+    } else {
+        capacities
+            .iter()
+            .map(|c| ((capacity as u128 * *c as u128) / total) as usize)
+            .collect()
+    };
+
+    let remainder = capacity - split.iter().sum::<usize>();
+    for c in split.iter_mut().take(remainder) {
+        *c += 1;
+    }
+    split
+}
@@ -2,7 +2,11 @@
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(test)]
+use std::cell::Cell;
 use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(test)]
+use std::time::Duration;
 use std::time::Instant;
 
 /// timestamp trait representing a fixed point in time.
@@ -96,10 +100,29 @@ pub struct Clock {
     t: Instant,
 }
 
+#[cfg(test)]
+thread_local! {
+    /// Time added to [`Clock::now()`] in the current test thread.
+    static CLOCK_OFFSET: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+impl Clock {
+    /// Move the [`Clock`] of the current test thread forward by `d`.
+    /// This lets tests expire time sensitive values without sleeping.
+    #[cfg(test)]
+    pub(crate) fn advance(d: Duration) {
+        CLOCK_OFFSET.with(|offset| offset.set(offset.get() + d));
+    }
+}
+
 impl Timestamp for Clock {
     /// Create a timestamp of current time with a nanoseconds granularity.
     fn now() -> Self {
-        Clock { t: Instant::now() }
+        #[cfg(test)]
+        let t = Instant::now() + CLOCK_OFFSET.with(Cell::get);
+        #[cfg(not(test))]
+        let t = Instant::now();
+        Clock { t }
     }
 
     fn diff(&self, other: &Self) -> f32 {
@@ -110,6 +133,7 @@ impl Timestamp for Clock {
 #[cfg(test)]
 mod tests {
     use super::{Clock, Counter, Timestamp};
+    use std::time::Duration;
 
     fn test_timestamp<T: Timestamp + std::fmt::Debug>() {
         let t0 = T::now();
@@ -129,6 +153,13 @@ mod tests {
         test_timestamp::<Clock>();
     }
 
+    #[test]
+    fn test_clock_advance() {
+        let t0 = Clock::now();
+        Clock::advance(Duration::from_secs(60));
+        assert!(Clock::now().diff(&t0) >= 60e9);
+    }
+
     #[test]
     fn test_counter() {
         test_timestamp::<Counter>();